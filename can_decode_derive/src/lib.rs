@@ -0,0 +1,168 @@
+//! Derive macros for the `can_decode` crate.
+//!
+//! These macros are re-exported by `can_decode` when its `derive` feature is
+//! enabled, and generate code that refers to `::can_decode`, so they should be
+//! used through that crate rather than by depending on this one directly.
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{Attribute, Data, DeriveInput, Fields, LitStr, parse_macro_input};
+
+/// Derives `can_decode::CanDecode` for a struct with named fields.
+///
+/// Each field is read from the signal named by its `#[signal("Name")]`
+/// attribute, or from the signal with the same name as the field if the
+/// attribute is omitted. Field types must implement `can_decode::FromSignal`.
+/// Fields of type `Option<T>` are `None` when the signal is missing from the
+/// decoded message, while missing signals for any other field are an error.
+#[proc_macro_derive(CanDecode, attributes(signal))]
+pub fn derive_can_decode(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_can_decode(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derives `can_decode::FromSignal` for an enum of unit variants.
+///
+/// Variants are matched against the signal's DBC value description (`VAL_`)
+/// label. The label defaults to the variant name and can be overridden with
+/// `#[label("...")]`. Signals without a label, or with a label no variant
+/// matches, don't convert.
+#[proc_macro_derive(SignalEnum, attributes(label))]
+pub fn derive_signal_enum(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_signal_enum(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand_can_decode(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &input.ident,
+                    "CanDecode can only be derived for structs with named fields",
+                ));
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "CanDecode can only be derived for structs",
+            ));
+        }
+    };
+
+    let mut initializers = Vec::with_capacity(fields.len());
+    for field in fields {
+        let ident = field.ident.as_ref().expect("named fields have identifiers");
+        let signal = match string_attribute(&field.attrs, "signal")? {
+            Some(signal) => signal,
+            None => {
+                let name = ident.to_string();
+                let name = name.strip_prefix("r#").unwrap_or(&name);
+                LitStr::new(name, ident.span())
+            }
+        };
+
+        let value = match option_inner_type(&field.ty) {
+            Some(inner) => quote! {
+                match decoded.signal_as::<#inner>(#signal) {
+                    ::core::result::Result::Ok(value) => ::core::option::Option::Some(value),
+                    ::core::result::Result::Err(
+                        ::can_decode::FromDecodedError::MissingSignal { .. },
+                    ) => ::core::option::Option::None,
+                    ::core::result::Result::Err(err) => return ::core::result::Result::Err(err),
+                }
+            },
+            None => quote! { decoded.signal_as(#signal)? },
+        };
+        initializers.push(quote! { #ident: #value });
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::can_decode::CanDecode for #name #ty_generics #where_clause {
+            fn from_decoded(
+                decoded: &::can_decode::DecodedMessage,
+            ) -> ::core::result::Result<Self, ::can_decode::FromDecodedError> {
+                ::core::result::Result::Ok(Self {
+                    #(#initializers,)*
+                })
+            }
+        }
+    })
+}
+
+fn expand_signal_enum(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let Data::Enum(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "SignalEnum can only be derived for enums",
+        ));
+    };
+
+    let mut arms = Vec::with_capacity(data.variants.len());
+    for variant in &data.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(syn::Error::new_spanned(
+                variant,
+                "SignalEnum variants can't have fields",
+            ));
+        }
+        let ident = &variant.ident;
+        let label = string_attribute(&variant.attrs, "label")?
+            .unwrap_or_else(|| LitStr::new(&ident.to_string(), ident.span()));
+        arms.push(quote! { #label => ::core::option::Option::Some(Self::#ident) });
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::can_decode::FromSignal for #name #ty_generics #where_clause {
+            fn from_signal(signal: &::can_decode::DecodedSignal) -> ::core::option::Option<Self> {
+                match signal.value.enum_label.as_deref()? {
+                    #(#arms,)*
+                    _ => ::core::option::Option::None,
+                }
+            }
+        }
+    })
+}
+
+/// Reads the string argument of an attribute like `#[signal("Name")]`.
+fn string_attribute(attrs: &[Attribute], name: &str) -> syn::Result<Option<LitStr>> {
+    let mut value = None;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident(name)) {
+        if value.is_some() {
+            return Err(syn::Error::new_spanned(
+                attr,
+                format!("duplicate `{name}` attribute"),
+            ));
+        }
+        value = Some(attr.parse_args::<LitStr>()?);
+    }
+    Ok(value)
+}
+
+/// Returns `T` if `ty` is written as `Option<T>`.
+fn option_inner_type(ty: &syn::Type) -> Option<&syn::Type> {
+    let syn::Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    if path.qself.is_some() || segment.ident != "Option" {
+        return None;
+    }
+    let syn::PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    match args.args.first()? {
+        syn::GenericArgument::Type(inner) if args.args.len() == 1 => Some(inner),
+        _ => None,
+    }
+}
@@ -0,0 +1,241 @@
+//! Reading Vector ASC (ASCII) log files.
+//!
+//! [`AscReader`] reads a log line by line and decodes its CAN and CAN FD frame
+//! lines with a [`Parser`]. Header lines (`date`, `base`, `Begin Triggerblock`,
+//! ...), comments, status and error frame lines, and remote frames are skipped.
+
+use crate::{DLC_TO_LEN, DecodedMessage, EXTENDED_ID_FLAG, Parser};
+use std::io::BufRead;
+
+/// A frame read from a line of an ASC log.
+#[derive(Debug, Clone, PartialEq)]
+struct AscFrame {
+    /// Seconds, relative to the start of the measurement or absolute as
+    /// declared by the log's `timestamps` header
+    timestamp: f64,
+    /// The channel the frame was logged on
+    channel: u8,
+    /// The CAN ID, with bit 31 set for extended IDs
+    msg_id: u32,
+    /// The payload
+    data: Vec<u8>,
+    /// Whether the frame is a CAN FD frame
+    is_fd: bool,
+}
+
+/// Parses an ASC frame line.
+///
+/// Classic frames look like `0.015 1 123 Rx d 8 00 11 22 33 44 55 66 77 ...`,
+/// where the direction may be missing and `r` instead of `d` marks a remote
+/// frame. CAN FD frames look like
+/// `0.100 CANFD 1 Rx 123 [name] 1 0 8 8 00 11 22 33 44 55 66 77 ...`, with the
+/// BRS and ESI flags, the DLC and the data length before the data. Extended IDs
+/// end in `x`. IDs and data bytes are in `radix`, as declared by the log's
+/// `base` header. Trailing fields after the data are ignored.
+fn parse_asc_line(line: &str, radix: u32) -> Option<AscFrame> {
+    let mut fields = line.split_whitespace();
+    let timestamp: f64 = fields.next()?.parse().ok()?;
+
+    let mut channel = fields.next()?;
+    let is_fd = channel == "CANFD";
+    if is_fd {
+        channel = fields.next()?;
+    }
+    let channel: u8 = channel.parse().ok()?;
+
+    let parse_id = |field: &str| {
+        let (digits, extended) = match field.strip_suffix(['x', 'X']) {
+            Some(digits) => (digits, true),
+            None => (field, false),
+        };
+        let id = u32::from_str_radix(digits, radix).ok()?;
+        match extended {
+            true if id <= 0x1FFF_FFFF => Some(id | EXTENDED_ID_FLAG),
+            false if id <= 0x7FF => Some(id),
+            _ => None,
+        }
+    };
+    let is_direction = |field: &str| matches!(field, "Rx" | "Tx" | "TxRq");
+
+    let (msg_id, data_len) = if is_fd {
+        let mut field = fields.next()?;
+        if is_direction(field) {
+            field = fields.next()?;
+        }
+        let msg_id = parse_id(field)?;
+
+        // An optional symbolic message name precedes the BRS and ESI flags
+        let mut rest: Vec<&str> = fields.by_ref().take(4).collect();
+        if !matches!(rest.get(..2)?, ["0" | "1", "0" | "1"]) {
+            rest.remove(0);
+            rest.extend(fields.next());
+        }
+        let dlc = usize::from_str_radix(rest.get(2)?, 16).ok()?;
+        let data_len: usize = rest.get(3)?.parse().ok()?;
+        if DLC_TO_LEN.get(dlc) != Some(&data_len) {
+            return None;
+        }
+        (msg_id, data_len)
+    } else {
+        let msg_id = parse_id(fields.next()?)?;
+        let mut field = fields.next()?;
+        if is_direction(field) {
+            field = fields.next()?;
+        }
+        if field != "d" {
+            // Remote frame
+            return None;
+        }
+        let dlc = usize::from_str_radix(fields.next()?, 16).ok()?;
+        (msg_id, (*DLC_TO_LEN.get(dlc)?).min(8))
+    };
+
+    let data = fields
+        .by_ref()
+        .take(data_len)
+        .map(|byte| u8::from_str_radix(byte, radix).ok())
+        .collect::<Option<Vec<u8>>>()?;
+    if data.len() != data_len {
+        return None;
+    }
+
+    Some(AscFrame {
+        timestamp,
+        channel,
+        msg_id,
+        data,
+        is_fd,
+    })
+}
+
+/// Decodes the frames of a Vector ASC log as it is read.
+///
+/// Iterating yields the timestamp in seconds, the channel, and the decoded
+/// message of every frame line that the parser can decode. Lines that aren't
+/// frames, remote frames, and frames that fail to decode (e.g. unknown IDs) are
+/// skipped. IDs and data are read as hexadecimal unless the log declares
+/// `base dec`. Bytes that aren't valid UTF-8, such as in comments written by
+/// tools using a legacy code page, are replaced rather than ending the read.
+/// Iteration ends at the end of the input, or with an error logged if reading
+/// fails.
+///
+/// # Example
+///
+/// ```
+/// use can_decode::{AscReader, Parser};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut parser = Parser::new();
+/// parser.add_from_str(
+///     r#"
+/// BO_ 291 Motor: 2 ECU
+///  SG_ Speed : 0|16@1+ (1,0) [0|0] "rpm" Vector__XXX
+/// BO_ 2566844926 Pack: 12 BMS
+///  SG_ Voltage : 64|16@1+ (0.5,0) [0|0] "V" Vector__XXX
+/// "#,
+/// )?;
+///
+/// let log = "\
+/// date Wed Jun 9 10:23:45.123 am 2021
+/// base hex  timestamps absolute
+/// internal events logged
+/// // version 9.0.0
+/// Begin Triggerblock Wed Jun 9 10:23:45.123 am 2021
+///    0.000000 Start of measurement
+///    0.010000 1  123             Rx   d 2 E8 03  Length = 0 BitCount = 0 ID = 291
+///    0.020000 1  123             Tx   r
+///    0.030000 CAN 1 Status:chip status error active
+///    0.040000 1  ErrorFrame
+///    0.050000 CANFD   1 Rx 18FEF1FEx Pack 1 0 9 12 00 00 00 00 00 00 00 00 64 05 00 00 0 0 1000 0 0 0 0 0
+///    0.060000 1  7FF             Rx   d 1 00
+///    0.070000 2  123             Rx   d 2 D0 07
+/// End TriggerBlock
+/// ";
+///
+/// let frames: Vec<(f64, u8, String)> = AscReader::new(&parser, log.as_bytes())
+///     .map(|(timestamp, channel, decoded)| (timestamp, channel, decoded.name))
+///     .collect();
+/// assert_eq!(
+///     frames,
+///     [
+///         (0.01, 1, "Motor".to_string()),
+///         (0.05, 1, "Pack".to_string()),
+///         (0.07, 2, "Motor".to_string()),
+///     ]
+/// );
+///
+/// let (_, _, pack) = AscReader::new(&parser, log.as_bytes()).nth(1).unwrap();
+/// assert_eq!(pack.signals["Voltage"].value.physical, 690.0);
+///
+/// // A Latin-1 comment doesn't stop the read
+/// let latin1 = b"// Pr\xfcfstand\n   0.010000 1  123  Rx   d 2 E8 03\n";
+/// assert_eq!(AscReader::new(&parser, &latin1[..]).count(), 1);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct AscReader<'a, R> {
+    parser: &'a Parser,
+    reader: R,
+    /// Radix of IDs and data bytes, from the `base` header
+    radix: u32,
+    line: Vec<u8>,
+}
+
+impl<'a, R: BufRead> AscReader<'a, R> {
+    /// Creates a reader that decodes frames with `parser`.
+    ///
+    /// # Arguments
+    ///
+    /// * `parser` - The parser to decode frames with
+    /// * `reader` - The ASC log, e.g. a `BufReader` over a file
+    pub fn new(parser: &'a Parser, reader: R) -> Self {
+        Self {
+            parser,
+            reader,
+            radix: 16,
+            line: Vec::new(),
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for AscReader<'_, R> {
+    type Item = (f64, u8, DecodedMessage);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            self.line.clear();
+            match self.reader.read_until(b'\n', &mut self.line) {
+                Ok(0) => return None,
+                Ok(_) => {}
+                Err(err) => {
+                    log::error!("Failed to read ASC log: {err}");
+                    return None;
+                }
+            }
+
+            let line = String::from_utf8_lossy(&self.line);
+
+            if let Some(header) = line.trim_start().strip_prefix("base ") {
+                match header.split_whitespace().next() {
+                    Some("hex") => self.radix = 16,
+                    Some("dec") => self.radix = 10,
+                    _ => log::warn!("Unknown ASC number base in '{}'", line.trim_end()),
+                }
+                continue;
+            }
+
+            let Some(frame) = parse_asc_line(&line, self.radix) else {
+                continue;
+            };
+            let decoded = if frame.is_fd {
+                self.parser.decode_fd_msg(frame.msg_id, &frame.data)
+            } else {
+                self.parser.decode_msg(frame.msg_id, &frame.data)
+            };
+            if let Some(decoded) = decoded {
+                return Some((frame.timestamp, frame.channel, decoded));
+            }
+        }
+    }
+}
@@ -0,0 +1,593 @@
+//! Precompiled message definitions.
+//!
+//! [`Parser::to_compiled_bytes`] serializes loaded definitions into a compact
+//! binary form, typically from a build script, and
+//! [`Parser::from_compiled_bytes`] loads them back at runtime without parsing
+//! DBC text.
+//!
+//! ## Format
+//!
+//! A 4-byte magic (`CDBC`) and a format version byte, followed by the default
+//! message entries, the message name index, and the per-bus definitions, each
+//! laid out like the top level after the version byte. Integers and floats are
+//! little-endian, strings are a `u32` byte length followed by UTF-8 bytes, and
+//! optional values are a presence byte followed by the value. Map entries are
+//! written in key order, so the same definitions always compile to the same
+//! bytes. The format is
+//! only meant to be read by the same version of this crate that wrote it.
+//! Signal transforms registered with [`Parser::set_transform`] are code, so
+//! they are not included.
+
+use crate::{
+    AttributeValue, CanDecodeError, CompositeSignal, FloatFormat, MsgEntry, Parser, SignalMeta,
+};
+use std::collections::HashMap;
+
+const MAGIC: &[u8; 4] = b"CDBC";
+const FORMAT_VERSION: u8 = 8;
+/// How deeply per-bus definitions may nest in a compiled database
+const MAX_BUS_DEPTH: u8 = 8;
+
+impl Parser {
+    /// Serializes all loaded definitions into a compact binary form.
+    ///
+    /// This is meant to be called from a build script, so that firmware with a
+    /// fixed database can embed the result with `include_bytes!` and load it
+    /// with [`from_compiled_bytes`](Parser::from_compiled_bytes), skipping DBC
+    /// parsing at startup. Decode settings such as the
+    /// [`NonFinitePolicy`](crate::NonFinitePolicy) are not included. The output
+    /// only depends on the loaded definitions, so build artifacts are
+    /// reproducible.
+    ///
+    /// # Examples
+    ///
+    /// In `build.rs` (with `can_decode` as a build dependency):
+    ///
+    /// ```no_run
+    /// use can_decode::Parser;
+    /// use std::path::{Path, PathBuf};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let parser = Parser::from_dbc_file(Path::new("vehicle.dbc"))?;
+    /// let out_dir = PathBuf::from(std::env::var("OUT_DIR")?);
+    /// std::fs::write(out_dir.join("vehicle.cdbc"), parser.to_compiled_bytes())?;
+    /// println!("cargo:rerun-if-changed=vehicle.dbc");
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Loading the same database twice gives the same bytes:
+    ///
+    /// ```
+    /// use can_decode::Parser;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let dbc = r#"
+    /// VERSION ""
+    ///
+    /// BU_: ECU
+    ///
+    /// BO_ 256 Engine: 2 ECU
+    ///  SG_ RPM : 0|16@1+ (0.25,0) [0|16383.75] "rpm" Vector__XXX
+    ///
+    /// BO_ 257 Gearbox: 2 ECU
+    ///  SG_ Gear : 0|4@1+ (1,0) [0|8] "" Vector__XXX
+    ///  SG_ Mode : 4|4@1+ (1,0) [0|3] "" Vector__XXX
+    ///
+    /// BO_ 258 Brakes: 1 ECU
+    ///  SG_ Pressure : 0|8@1+ (1,0) [0|255] "bar" Vector__XXX
+    ///
+    /// BA_DEF_ BO_ "GenMsgCycleTime" INT 0 10000;
+    /// BA_DEF_ SG_ "GenSigStartValue" INT 0 65535;
+    /// BA_ "GenMsgCycleTime" BO_ 256 10;
+    /// BA_ "GenMsgCycleTime" BO_ 257 100;
+    /// BA_ "GenSigStartValue" SG_ 257 Gear 1;
+    /// BA_ "GenSigStartValue" SG_ 257 Mode 2;
+    /// VAL_ 257 Gear 0 "P" 1 "R" 2 "N" 3 "D";
+    /// "#;
+    ///
+    /// let mut first = Parser::new();
+    /// first.add_from_str(dbc)?;
+    /// let mut second = Parser::new();
+    /// second.add_from_str(dbc)?;
+    /// assert_eq!(first.to_compiled_bytes(), second.to_compiled_bytes());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_compiled_bytes(&self) -> Vec<u8> {
+        let mut out = Writer::default();
+        out.bytes(MAGIC);
+        out.u8(FORMAT_VERSION);
+
+        out.definitions(self);
+        out.buf
+    }
+
+    /// Creates a parser from definitions serialized with
+    /// [`to_compiled_bytes`](Parser::to_compiled_bytes).
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - The compiled definitions
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CanDecodeError::InvalidCompiled`] if the bytes are not compiled
+    /// definitions, were written by an incompatible version of this crate, or
+    /// are truncated.
+    ///
+    /// # Example
+    ///
+    /// With the output of a build script embedded via
+    /// `include_bytes!(concat!(env!("OUT_DIR"), "/vehicle.cdbc"))`:
+    ///
+    /// ```
+    /// use can_decode::{CanDecodeError, Parser};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let dbc = r#"
+    /// # VERSION ""
+    /// #
+    /// # BU_: ECU
+    /// #
+    /// # BO_ 256 Engine: 2 ECU
+    /// #  SG_ RPM : 0|16@1+ (0.25,0) [0|16383.75] "rpm" Vector__XXX
+    /// # "#;
+    /// # let mut source = Parser::new();
+    /// # source.add_from_str(dbc)?;
+    /// # let vehicle_db: &[u8] = &source.to_compiled_bytes();
+    /// let parser = Parser::from_compiled_bytes(vehicle_db)?;
+    ///
+    /// let decoded = parser.decode_msg(256, &[0x40, 0x1F]).unwrap();
+    /// assert_eq!(decoded.signals["RPM"].value.physical, 2000.0);
+    ///
+    /// // Truncated input is rejected
+    /// let truncated = &vehicle_db[..vehicle_db.len() - 1];
+    /// assert!(matches!(
+    ///     Parser::from_compiled_bytes(truncated),
+    ///     Err(CanDecodeError::InvalidCompiled(_))
+    /// ));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_compiled_bytes(bytes: &[u8]) -> Result<Self, CanDecodeError> {
+        let invalid = |msg: &str| CanDecodeError::InvalidCompiled(msg.to_string());
+        let mut reader = Reader { data: bytes };
+
+        if reader.take(MAGIC.len()) != Some(MAGIC.as_slice()) {
+            return Err(invalid("not a compiled DBC database"));
+        }
+        match reader.u8() {
+            Some(FORMAT_VERSION) => {}
+            Some(version) => {
+                return Err(invalid(&format!("unsupported format version {version}")));
+            }
+            None => return Err(invalid("truncated compiled DBC database")),
+        }
+
+        reader
+            .definitions(MAX_BUS_DEPTH)
+            .filter(|_| reader.data.is_empty())
+            .ok_or_else(|| invalid("malformed compiled DBC database"))
+    }
+}
+
+/// Returns the entries of a map in key order, so that output doesn't depend on
+/// `HashMap` iteration order.
+fn sorted<K: Ord, V>(map: &HashMap<K, V>) -> Vec<(&K, &V)> {
+    let mut entries: Vec<_> = map.iter().collect();
+    entries.sort_unstable_by_key(|(key, _)| *key);
+    entries
+}
+
+/// Appends values to the compiled representation.
+#[derive(Default)]
+struct Writer {
+    buf: Vec<u8>,
+}
+
+impl Writer {
+    fn bytes(&mut self, bytes: &[u8]) {
+        self.buf.extend_from_slice(bytes);
+    }
+
+    fn u8(&mut self, value: u8) {
+        self.buf.push(value);
+    }
+
+    fn u32(&mut self, value: u32) {
+        self.bytes(&value.to_le_bytes());
+    }
+
+    fn u64(&mut self, value: u64) {
+        self.bytes(&value.to_le_bytes());
+    }
+
+    fn f64(&mut self, value: f64) {
+        self.bytes(&value.to_le_bytes());
+    }
+
+    fn len(&mut self, len: usize) {
+        self.u32(len as u32);
+    }
+
+    fn str(&mut self, value: &str) {
+        self.len(value.len());
+        self.bytes(value.as_bytes());
+    }
+
+    fn opt<T>(&mut self, value: Option<T>, write: impl FnOnce(&mut Self, T)) {
+        match value {
+            Some(value) => {
+                self.u8(1);
+                write(self, value);
+            }
+            None => self.u8(0),
+        }
+    }
+
+    fn numeric(&mut self, value: &can_dbc::NumericValue) {
+        match *value {
+            can_dbc::NumericValue::Uint(v) => {
+                self.u8(0);
+                self.u64(v);
+            }
+            can_dbc::NumericValue::Int(v) => {
+                self.u8(1);
+                self.u64(v as u64);
+            }
+            can_dbc::NumericValue::Double(v) => {
+                self.u8(2);
+                self.f64(v);
+            }
+        }
+    }
+
+    fn attributes(&mut self, attributes: &HashMap<String, AttributeValue>) {
+        self.len(attributes.len());
+        for (name, value) in sorted(attributes) {
+            self.str(name);
+            match value {
+                AttributeValue::Int(v) => {
+                    self.u8(0);
+                    self.u64(*v as u64);
+                }
+                AttributeValue::Float(v) => {
+                    self.u8(1);
+                    self.f64(*v);
+                }
+                AttributeValue::String(text) => {
+                    self.u8(2);
+                    self.str(text);
+                }
+                AttributeValue::Enum(label) => {
+                    self.u8(3);
+                    self.str(label);
+                }
+            }
+        }
+    }
+
+    fn definitions(&mut self, parser: &Parser) {
+        self.entries(&parser.msg_entries);
+        self.len(parser.msg_ids_by_name.len());
+        for (name, msg_id) in sorted(&parser.msg_ids_by_name) {
+            self.str(name);
+            self.u32(*msg_id);
+        }
+        self.len(parser.buses.len());
+        for (bus, bus_parser) in sorted(&parser.buses) {
+            self.str(bus);
+            self.definitions(bus_parser);
+        }
+    }
+
+    fn entries(&mut self, entries: &HashMap<u32, MsgEntry>) {
+        self.len(entries.len());
+        for (_, entry) in sorted(entries) {
+            self.entry(entry);
+        }
+    }
+
+    fn entry(&mut self, entry: &MsgEntry) {
+        let msg_def = &entry.msg_def;
+        self.u32(msg_def.id.raw());
+        self.str(&msg_def.name);
+        self.u64(msg_def.size);
+        self.opt(
+            match &msg_def.transmitter {
+                can_dbc::Transmitter::NodeName(name) => Some(name),
+                can_dbc::Transmitter::VectorXXX => None,
+            },
+            |w, name| w.str(name),
+        );
+
+        self.len(msg_def.signals.len());
+        for signal in &msg_def.signals {
+            self.signal(signal);
+        }
+
+        self.opt(entry.msg_desc.as_deref(), Self::str);
+        self.attributes(&entry.attributes);
+
+        self.len(entry.signal_meta.len());
+        for (name, meta) in sorted(&entry.signal_meta) {
+            self.str(name);
+            self.len(meta.enum_map.len());
+            for (value, label) in sorted(&meta.enum_map) {
+                self.bytes(&value.to_le_bytes());
+                self.str(label);
+            }
+            self.opt(meta.float_format, |w, format| {
+                w.u8(match format {
+                    FloatFormat::F32 => 0,
+                    FloatFormat::F64 => 1,
+                })
+            });
+            self.opt(meta.sig_comment.as_deref(), Self::str);
+            self.u8(meta.bit_reverse.into());
+            self.u8(meta.disabled.into());
+            self.opt(meta.sna_value, Self::u64);
+            self.attributes(&meta.attributes);
+        }
+
+        self.len(entry.composites.len());
+        for composite in &entry.composites {
+            self.str(&composite.name);
+            self.len(composite.parts.len());
+            for (part, shift) in &composite.parts {
+                self.str(part);
+                self.u32(*shift);
+            }
+        }
+    }
+
+    fn signal(&mut self, signal: &can_dbc::Signal) {
+        self.str(&signal.name);
+        match signal.multiplexer_indicator {
+            can_dbc::MultiplexIndicator::Plain => self.u8(0),
+            can_dbc::MultiplexIndicator::Multiplexor => self.u8(1),
+            can_dbc::MultiplexIndicator::MultiplexedSignal(value) => {
+                self.u8(2);
+                self.u64(value);
+            }
+            can_dbc::MultiplexIndicator::MultiplexorAndMultiplexedSignal(value) => {
+                self.u8(3);
+                self.u64(value);
+            }
+        }
+        self.u64(signal.start_bit);
+        self.u64(signal.size);
+        self.u8(match signal.byte_order {
+            can_dbc::ByteOrder::LittleEndian => 0,
+            can_dbc::ByteOrder::BigEndian => 1,
+        });
+        self.u8(match signal.value_type {
+            can_dbc::ValueType::Unsigned => 0,
+            can_dbc::ValueType::Signed => 1,
+        });
+        self.f64(signal.factor);
+        self.f64(signal.offset);
+        self.numeric(&signal.min);
+        self.numeric(&signal.max);
+        self.str(&signal.unit);
+        self.len(signal.receivers.len());
+        for receiver in &signal.receivers {
+            self.str(receiver);
+        }
+    }
+}
+
+/// Reads values back from the compiled representation.
+///
+/// Every method returns `None` if the input is truncated or malformed.
+struct Reader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Option<&'a [u8]> {
+        if self.data.len() < len {
+            return None;
+        }
+        let (head, tail) = self.data.split_at(len);
+        self.data = tail;
+        Some(head)
+    }
+
+    fn array<const N: usize>(&mut self) -> Option<[u8; N]> {
+        self.take(N)?.try_into().ok()
+    }
+
+    fn u8(&mut self) -> Option<u8> {
+        Some(self.take(1)?[0])
+    }
+
+    fn u32(&mut self) -> Option<u32> {
+        Some(u32::from_le_bytes(self.array()?))
+    }
+
+    fn u64(&mut self) -> Option<u64> {
+        Some(u64::from_le_bytes(self.array()?))
+    }
+
+    fn f64(&mut self) -> Option<f64> {
+        Some(f64::from_le_bytes(self.array()?))
+    }
+
+    fn len(&mut self) -> Option<usize> {
+        Some(self.u32()? as usize)
+    }
+
+    fn string(&mut self) -> Option<String> {
+        let len = self.len()?;
+        String::from_utf8(self.take(len)?.to_vec()).ok()
+    }
+
+    fn opt<T>(&mut self, read: impl FnOnce(&mut Self) -> Option<T>) -> Option<Option<T>> {
+        match self.u8()? {
+            0 => Some(None),
+            1 => read(self).map(Some),
+            _ => None,
+        }
+    }
+
+    fn numeric(&mut self) -> Option<can_dbc::NumericValue> {
+        match self.u8()? {
+            0 => Some(can_dbc::NumericValue::Uint(self.u64()?)),
+            1 => Some(can_dbc::NumericValue::Int(self.u64()? as i64)),
+            2 => Some(can_dbc::NumericValue::Double(self.f64()?)),
+            _ => None,
+        }
+    }
+
+    fn attributes(&mut self) -> Option<HashMap<String, AttributeValue>> {
+        (0..self.len()?)
+            .map(|_| {
+                let name = self.string()?;
+                let value = match self.u8()? {
+                    0 => AttributeValue::Int(self.u64()? as i64),
+                    1 => AttributeValue::Float(self.f64()?),
+                    2 => AttributeValue::String(self.string()?),
+                    3 => AttributeValue::Enum(self.string()?),
+                    _ => return None,
+                };
+                Some((name, value))
+            })
+            .collect()
+    }
+
+    /// Reads a parser's definitions, with at most `depth` levels of nested buses
+    /// so that malformed input can't exhaust the stack.
+    fn definitions(&mut self, depth: u8) -> Option<Parser> {
+        let mut parser = Parser::new();
+        parser.msg_entries = self.entries()?;
+        #[cfg(feature = "j1939")]
+        for msg_id in parser.msg_entries.keys().copied().collect::<Vec<_>>() {
+            parser.index_pgn(msg_id);
+        }
+        for _ in 0..self.len()? {
+            let name = self.string()?;
+            let msg_id = self.u32()?;
+            parser.msg_ids_by_name.insert(name, msg_id);
+        }
+        for _ in 0..self.len()? {
+            let bus = self.string()?;
+            let bus_parser = self.definitions(depth.checked_sub(1)?)?;
+            parser.buses.insert(bus, bus_parser);
+        }
+        Some(parser)
+    }
+
+    fn entries(&mut self) -> Option<HashMap<u32, MsgEntry>> {
+        let count = self.len()?;
+        let mut entries = HashMap::with_capacity(count.min(self.data.len()));
+        for _ in 0..count {
+            let entry = self.entry()?;
+            entries.insert(entry.msg_def.id.raw(), entry);
+        }
+        Some(entries)
+    }
+
+    fn entry(&mut self) -> Option<MsgEntry> {
+        let raw_id = self.u32()?;
+        let id = if raw_id & crate::EXTENDED_ID_FLAG != 0 {
+            can_dbc::MessageId::Extended(raw_id & !crate::EXTENDED_ID_FLAG)
+        } else {
+            can_dbc::MessageId::Standard(u16::try_from(raw_id).ok()?)
+        };
+        let name = self.string()?;
+        let size = self.u64()?;
+        let transmitter = match self.opt(Self::string)? {
+            Some(node) => can_dbc::Transmitter::NodeName(node),
+            None => can_dbc::Transmitter::VectorXXX,
+        };
+
+        let signals = (0..self.len()?)
+            .map(|_| self.signal())
+            .collect::<Option<Vec<_>>>()?;
+
+        let mut entry = MsgEntry::new(can_dbc::Message {
+            id,
+            name,
+            size,
+            transmitter,
+            signals,
+        });
+        entry.msg_desc = self.opt(Self::string)?;
+        entry.attributes = self.attributes()?;
+
+        for _ in 0..self.len()? {
+            let signal_name = self.string()?;
+            let enum_map = (0..self.len()?)
+                .map(|_| Some((i128::from_le_bytes(self.array()?), self.string()?)))
+                .collect::<Option<HashMap<_, _>>>()?;
+            let float_format = self.opt(|r| match r.u8()? {
+                0 => Some(FloatFormat::F32),
+                1 => Some(FloatFormat::F64),
+                _ => None,
+            })?;
+            let meta = SignalMeta {
+                enum_map,
+                float_format,
+                sig_comment: self.opt(Self::string)?,
+                bit_reverse: self.u8()? != 0,
+                transform: None,
+                disabled: self.u8()? != 0,
+                sna_value: self.opt(Self::u64)?,
+                attributes: self.attributes()?,
+            };
+            entry.signal_meta.insert(signal_name, meta);
+        }
+
+        for _ in 0..self.len()? {
+            let name = self.string()?;
+            let parts = (0..self.len()?)
+                .map(|_| Some((self.string()?, self.u32()?)))
+                .collect::<Option<Vec<_>>>()?;
+            entry.composites.push(CompositeSignal { name, parts });
+        }
+
+        Some(entry)
+    }
+
+    fn signal(&mut self) -> Option<can_dbc::Signal> {
+        let name = self.string()?;
+        let multiplexer_indicator = match self.u8()? {
+            0 => can_dbc::MultiplexIndicator::Plain,
+            1 => can_dbc::MultiplexIndicator::Multiplexor,
+            2 => can_dbc::MultiplexIndicator::MultiplexedSignal(self.u64()?),
+            3 => can_dbc::MultiplexIndicator::MultiplexorAndMultiplexedSignal(self.u64()?),
+            _ => return None,
+        };
+        let start_bit = self.u64()?;
+        let size = self.u64()?;
+        let byte_order = match self.u8()? {
+            0 => can_dbc::ByteOrder::LittleEndian,
+            1 => can_dbc::ByteOrder::BigEndian,
+            _ => return None,
+        };
+        let value_type = match self.u8()? {
+            0 => can_dbc::ValueType::Unsigned,
+            1 => can_dbc::ValueType::Signed,
+            _ => return None,
+        };
+
+        Some(can_dbc::Signal {
+            name,
+            multiplexer_indicator,
+            start_bit,
+            size,
+            byte_order,
+            value_type,
+            factor: self.f64()?,
+            offset: self.f64()?,
+            min: self.numeric()?,
+            max: self.numeric()?,
+            unit: self.string()?,
+            receivers: (0..self.len()?)
+                .map(|_| self.string())
+                .collect::<Option<Vec<_>>>()?,
+        })
+    }
+}
@@ -0,0 +1,472 @@
+//! DBC export and definition fingerprints.
+//!
+//! [`Parser::to_dbc_string`] writes the loaded definitions back out as DBC
+//! text, and [`Parser::fingerprint`] summarizes them in a single hash, so that
+//! [`Parser::assert_roundtrip`] can check that nothing is lost on the way.
+//!
+//! Only the default (bus-less) definitions are exported. Runtime settings such
+//! as signal transforms, bit reversal, disabled signals, and composite signals
+//! are not part of the DBC format, so they are neither exported nor included in
+//! the fingerprint.
+
+use crate::{FloatFormat, MsgEntry, Parser, SignalMeta, numeric_to_f64};
+use std::fmt::Write;
+
+/// Placeholder node name for messages without a transmitter and signals
+/// without receivers.
+const NO_NODE: &str = "Vector__XXX";
+
+/// Escapes a string for use between double quotes in a DBC file.
+///
+/// This is the inverse of `unescape_dbc_string`. A string ending in a
+/// backslash can't be represented, because the DBC grammar reads the final
+/// `\"` as an escaped quote.
+fn escape_dbc_string(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        if matches!(c, '"' | '\\') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Formats a `can_dbc` numeric value the way it appears in a DBC file.
+fn format_numeric(value: &can_dbc::NumericValue) -> String {
+    match value {
+        can_dbc::NumericValue::Uint(v) => v.to_string(),
+        can_dbc::NumericValue::Int(v) => v.to_string(),
+        can_dbc::NumericValue::Double(v) => v.to_string(),
+    }
+}
+
+/// A 64-bit FNV-1a hasher.
+///
+/// Used instead of `std`'s `DefaultHasher`, whose output may change between
+/// Rust releases, so fingerprints can be stored and compared later.
+struct Fnv1a(u64);
+
+impl Default for Fnv1a {
+    fn default() -> Self {
+        Self(0xCBF2_9CE4_8422_2325)
+    }
+}
+
+impl Fnv1a {
+    fn bytes(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 ^= byte as u64;
+            self.0 = self.0.wrapping_mul(0x0000_0100_0000_01B3);
+        }
+    }
+
+    fn u64(&mut self, value: u64) {
+        self.bytes(&value.to_le_bytes());
+    }
+
+    fn f64(&mut self, value: f64) {
+        self.u64(value.to_bits());
+    }
+
+    fn str(&mut self, value: &str) {
+        self.u64(value.len() as u64);
+        self.bytes(value.as_bytes());
+    }
+
+    fn opt_str(&mut self, value: Option<&str>) {
+        match value {
+            Some(value) => {
+                self.u64(1);
+                self.str(value);
+            }
+            None => self.u64(0),
+        }
+    }
+}
+
+impl Parser {
+    /// Exports the loaded definitions as DBC text.
+    ///
+    /// Messages are written in order of their CAN ID, so the output is
+    /// deterministic. Besides the message and signal definitions, the export
+    /// includes message and signal comments, value descriptions (merged with
+    /// any referenced value tables), IEEE float signal types, and the
+    /// `GenMsgCycleTime` and `GenSigStartValue` attributes. Other attributes,
+    /// node comments, and messages loaded for a specific bus are not exported.
+    ///
+    /// # Returns
+    ///
+    /// The DBC file contents, which can be loaded again with
+    /// [`add_from_str`](Parser::add_from_str).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use can_decode::Parser;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut parser = Parser::new();
+    /// parser.add_from_str(
+    ///     r#"
+    /// BO_ 256 Status: 1 ECU
+    ///  SG_ Mode : 0|8@1+ (1,0) [0|255] "" Dash
+    /// "#,
+    /// )?;
+    ///
+    /// let dbc = parser.to_dbc_string();
+    /// assert!(dbc.contains("BO_ 256 Status: 1 ECU"));
+    /// assert!(dbc.contains(r#" SG_ Mode : 0|8@1+ (1,0) [0|255] "" Dash"#));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_dbc_string(&self) -> String {
+        let mut entries: Vec<&MsgEntry> = self.msg_entries.values().collect();
+        entries.sort_by_key(|entry| entry.msg_def.id.raw());
+
+        let mut nodes: Vec<&str> = Vec::new();
+        for entry in &entries {
+            if let can_dbc::Transmitter::NodeName(node) = &entry.msg_def.transmitter {
+                nodes.push(node);
+            }
+            for signal_def in &entry.msg_def.signals {
+                nodes.extend(signal_def.receivers.iter().map(String::as_str));
+            }
+        }
+        nodes.retain(|node| *node != NO_NODE);
+        nodes.sort_unstable();
+        nodes.dedup();
+
+        // Writing to a String never fails, so the results are ignored below
+        let mut out = String::new();
+        out.push_str("VERSION \"\"\n\n");
+        let _ = writeln!(out, "BU_: {}\n", nodes.join(" "));
+
+        for entry in &entries {
+            let msg_def = &entry.msg_def;
+            let transmitter = match &msg_def.transmitter {
+                can_dbc::Transmitter::NodeName(node) => node.as_str(),
+                can_dbc::Transmitter::VectorXXX => NO_NODE,
+            };
+            let _ = writeln!(
+                out,
+                "BO_ {} {}: {} {}",
+                msg_def.id.raw(),
+                msg_def.name,
+                msg_def.size,
+                transmitter
+            );
+
+            for signal_def in &msg_def.signals {
+                let multiplexer = match signal_def.multiplexer_indicator {
+                    can_dbc::MultiplexIndicator::Plain => String::new(),
+                    can_dbc::MultiplexIndicator::Multiplexor => " M".to_string(),
+                    can_dbc::MultiplexIndicator::MultiplexedSignal(value) => format!(" m{value}"),
+                    can_dbc::MultiplexIndicator::MultiplexorAndMultiplexedSignal(value) => {
+                        format!(" m{value}M")
+                    }
+                };
+                let byte_order = match signal_def.byte_order {
+                    can_dbc::ByteOrder::BigEndian => '0',
+                    can_dbc::ByteOrder::LittleEndian => '1',
+                };
+                let sign = match signal_def.value_type {
+                    can_dbc::ValueType::Signed => '-',
+                    can_dbc::ValueType::Unsigned => '+',
+                };
+                let receivers = if signal_def.receivers.is_empty() {
+                    NO_NODE.to_string()
+                } else {
+                    signal_def.receivers.join(",")
+                };
+                let _ = writeln!(
+                    out,
+                    " SG_ {}{} : {}|{}@{}{} ({},{}) [{}|{}] \"{}\" {}",
+                    signal_def.name,
+                    multiplexer,
+                    signal_def.start_bit,
+                    signal_def.size,
+                    byte_order,
+                    sign,
+                    signal_def.factor,
+                    signal_def.offset,
+                    format_numeric(&signal_def.min),
+                    format_numeric(&signal_def.max),
+                    escape_dbc_string(&signal_def.unit),
+                    receivers
+                );
+            }
+            out.push('\n');
+        }
+
+        for entry in &entries {
+            let msg_id = entry.msg_def.id.raw();
+            if let Some(desc) = &entry.msg_desc {
+                let _ = writeln!(out, "CM_ BO_ {msg_id} \"{}\";", escape_dbc_string(desc));
+            }
+            for signal_def in &entry.msg_def.signals {
+                let comment = entry
+                    .signal_meta
+                    .get(&signal_def.name)
+                    .and_then(|meta| meta.sig_comment.as_ref());
+                if let Some(comment) = comment {
+                    let _ = writeln!(
+                        out,
+                        "CM_ SG_ {msg_id} {} \"{}\";",
+                        signal_def.name,
+                        escape_dbc_string(comment)
+                    );
+                }
+            }
+        }
+
+        let mut attributes = String::new();
+        let mut has_cycle_time = false;
+        let mut has_start_value = false;
+        for entry in &entries {
+            let msg_id = entry.msg_def.id.raw();
+            if let Some(cycle_time) = entry.cycle_time_ms() {
+                has_cycle_time = true;
+                let _ = writeln!(
+                    attributes,
+                    "BA_ \"GenMsgCycleTime\" BO_ {msg_id} {cycle_time};"
+                );
+            }
+            for signal_def in &entry.msg_def.signals {
+                let start_value = entry
+                    .signal_meta
+                    .get(&signal_def.name)
+                    .and_then(SignalMeta::start_value);
+                if let Some(start_value) = start_value {
+                    has_start_value = true;
+                    let _ = writeln!(
+                        attributes,
+                        "BA_ \"GenSigStartValue\" SG_ {msg_id} {} {start_value};",
+                        signal_def.name
+                    );
+                }
+            }
+        }
+        if has_cycle_time {
+            out.push_str("BA_DEF_ BO_ \"GenMsgCycleTime\" INT 0 65535;\n");
+        }
+        if has_start_value {
+            out.push_str("BA_DEF_ SG_ \"GenSigStartValue\" FLOAT 0 0;\n");
+        }
+        out.push_str(&attributes);
+
+        for entry in &entries {
+            let msg_id = entry.msg_def.id.raw();
+            for signal_def in &entry.msg_def.signals {
+                let Some(meta) = entry.signal_meta.get(&signal_def.name) else {
+                    continue;
+                };
+                if meta.enum_map.is_empty() {
+                    continue;
+                }
+                let mut values: Vec<_> = meta.enum_map.iter().collect();
+                values.sort_unstable_by(|a, b| b.0.cmp(a.0));
+                let _ = write!(out, "VAL_ {msg_id} {}", signal_def.name);
+                for (value, label) in values {
+                    let _ = write!(out, " {value} \"{label}\"");
+                }
+                out.push_str(" ;\n");
+            }
+        }
+
+        for entry in &entries {
+            let msg_id = entry.msg_def.id.raw();
+            for signal_def in &entry.msg_def.signals {
+                let float_format = entry
+                    .signal_meta
+                    .get(&signal_def.name)
+                    .and_then(|meta| meta.float_format);
+                let value_type = match float_format {
+                    Some(FloatFormat::F32) => 1,
+                    Some(FloatFormat::F64) => 2,
+                    None => continue,
+                };
+                let _ = writeln!(
+                    out,
+                    "SIG_VALTYPE_ {msg_id} {} : {value_type};",
+                    signal_def.name
+                );
+            }
+        }
+
+        out
+    }
+
+    /// Computes a hash of the loaded definitions.
+    ///
+    /// Two parsers have the same fingerprint when their default (bus-less)
+    /// message definitions, comments, value descriptions, float signal types,
+    /// start values, and cycle times are the same, regardless of the order
+    /// they were loaded in. Numeric limits are compared by value, so `[0|1]`
+    /// and `[0.0|1.0]` are equal, and signals without receivers are equal to
+    /// signals received by `Vector__XXX`. Runtime settings such as signal
+    /// transforms are not included.
+    ///
+    /// The hash is 64-bit FNV-1a, so it is stable across Rust releases, but it
+    /// is not suitable for detecting deliberate tampering.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use can_decode::Parser;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let dbc = r#"
+    /// BO_ 256 Status: 1 ECU
+    ///  SG_ Mode : 0|8@1+ (1,0) [0|255] "" Dash
+    /// "#;
+    ///
+    /// let mut a = Parser::new();
+    /// a.add_from_str(dbc)?;
+    /// let mut b = Parser::new();
+    /// b.add_from_str(dbc)?;
+    /// assert_eq!(a.fingerprint(), b.fingerprint());
+    ///
+    /// b.add_from_str(r#"CM_ BO_ 256 "Operating mode";"#)?;
+    /// assert_ne!(a.fingerprint(), b.fingerprint());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn fingerprint(&self) -> u64 {
+        let mut entries: Vec<&MsgEntry> = self.msg_entries.values().collect();
+        entries.sort_by_key(|entry| entry.msg_def.id.raw());
+
+        let mut hash = Fnv1a::default();
+        hash.u64(entries.len() as u64);
+        for entry in entries {
+            let msg_def = &entry.msg_def;
+            hash.u64(msg_def.id.raw() as u64);
+            hash.str(&msg_def.name);
+            hash.u64(msg_def.size);
+            hash.str(match &msg_def.transmitter {
+                can_dbc::Transmitter::NodeName(node) => node,
+                can_dbc::Transmitter::VectorXXX => NO_NODE,
+            });
+            hash.opt_str(entry.msg_desc.as_deref());
+            hash.u64(entry.cycle_time_ms().map_or(0, |cycle_time| cycle_time + 1));
+
+            hash.u64(msg_def.signals.len() as u64);
+            for signal_def in &msg_def.signals {
+                hash.str(&signal_def.name);
+                match signal_def.multiplexer_indicator {
+                    can_dbc::MultiplexIndicator::Plain => hash.u64(0),
+                    can_dbc::MultiplexIndicator::Multiplexor => hash.u64(1),
+                    can_dbc::MultiplexIndicator::MultiplexedSignal(value) => {
+                        hash.u64(2);
+                        hash.u64(value);
+                    }
+                    can_dbc::MultiplexIndicator::MultiplexorAndMultiplexedSignal(value) => {
+                        hash.u64(3);
+                        hash.u64(value);
+                    }
+                }
+                hash.u64(signal_def.start_bit);
+                hash.u64(signal_def.size);
+                hash.u64(matches!(signal_def.byte_order, can_dbc::ByteOrder::BigEndian) as u64);
+                hash.u64(matches!(signal_def.value_type, can_dbc::ValueType::Signed) as u64);
+                hash.f64(signal_def.factor);
+                hash.f64(signal_def.offset);
+                hash.f64(numeric_to_f64(&signal_def.min));
+                hash.f64(numeric_to_f64(&signal_def.max));
+                hash.str(&signal_def.unit);
+                let receivers: Vec<&String> = signal_def
+                    .receivers
+                    .iter()
+                    .filter(|receiver| *receiver != NO_NODE)
+                    .collect();
+                hash.u64(receivers.len() as u64);
+                for receiver in receivers {
+                    hash.str(receiver);
+                }
+
+                let meta = entry
+                    .signal_meta
+                    .get(&signal_def.name)
+                    .cloned()
+                    .unwrap_or_default();
+                let mut values: Vec<_> = meta.enum_map.iter().collect();
+                values.sort_unstable_by_key(|(value, _)| **value);
+                hash.u64(values.len() as u64);
+                for (value, label) in values {
+                    hash.bytes(&value.to_le_bytes());
+                    hash.str(label);
+                }
+                hash.u64(match meta.float_format {
+                    None => 0,
+                    Some(FloatFormat::F32) => 1,
+                    Some(FloatFormat::F64) => 2,
+                });
+                hash.opt_str(meta.sig_comment.as_deref());
+                match meta.start_value() {
+                    Some(start_value) => {
+                        hash.u64(1);
+                        hash.f64(start_value);
+                    }
+                    None => hash.u64(0),
+                }
+            }
+        }
+
+        hash.0
+    }
+
+    /// Checks that the loaded definitions survive a DBC export.
+    ///
+    /// Exports the definitions with [`to_dbc_string`](Parser::to_dbc_string),
+    /// loads the result into a new parser, and compares the two
+    /// [`fingerprint`](Parser::fingerprint)s. This is meant for tests of
+    /// databases that are edited programmatically and saved as DBC files.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the exported DBC text can't be parsed, or if the reparsed
+    /// definitions differ from the loaded ones.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use can_decode::Parser;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut parser = Parser::new();
+    /// parser.add_from_str(
+    ///     r#"
+    /// BO_ 256 Drive: 8 VCU
+    ///  SG_ Gear M : 0|4@1+ (1,0) [0|15] "" Dash
+    ///  SG_ Torque m1 : 15|16@0- (0.1,-50) [-3276.8|3276.7] "Nm" Inverter,Dash
+    ///  SG_ Speed m2 : 8|32@1- (1,0) [0|0] "rpm" Dash
+    /// BO_ 2147484672 Pack: 4 BMS
+    ///  SG_ Voltage : 0|16@1+ (0.01,0) [0|655.35] "V" Vector__XXX
+    ///
+    /// CM_ BO_ 256 "Drive \"mode\" request";
+    /// CM_ SG_ 256 Torque "Requested torque";
+    /// BA_DEF_ BO_ "GenMsgCycleTime" INT 0 10000;
+    /// BA_DEF_DEF_ "GenMsgCycleTime" 100;
+    /// BA_ "GenMsgCycleTime" BO_ 256 10;
+    /// VAL_ 256 Gear 2 "Reverse" 1 "Drive" 0 "Park" ;
+    /// SIG_VALTYPE_ 256 Speed : 1;
+    /// "#,
+    /// )?;
+    ///
+    /// parser.assert_roundtrip();
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn assert_roundtrip(&self) {
+        let dbc = self.to_dbc_string();
+        let mut reparsed = Parser::new();
+        if let Err(err) = reparsed.add_from_str(&dbc) {
+            panic!("exported DBC failed to parse: {err}\n{dbc}");
+        }
+        assert_eq!(
+            self.fingerprint(),
+            reparsed.fingerprint(),
+            "reparsed definitions differ from the loaded ones:\n{dbc}"
+        );
+    }
+}
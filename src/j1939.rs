@@ -0,0 +1,347 @@
+//! SAE J1939 transport protocol support.
+//!
+//! J1939 parameter groups longer than 8 bytes are split into a connection
+//! management frame (TP.CM, PGN 0xEC00) followed by numbered data frames
+//! (TP.DT, PGN 0xEB00). [`J1939Reassembler`] passively follows both broadcast
+//! (BAM) and connection mode (RTS/CTS) transfers, and yields the reassembled
+//! payload, which can then be decoded with [`Parser::decode_j1939`].
+//!
+//! Only reassembly is supported: the reassembler never sends CTS or
+//! acknowledgement frames, and it doesn't enforce the protocol's timeouts.
+
+use crate::{DecodedMessage, EXTENDED_ID_FLAG, Parser};
+
+/// PGN of transport protocol connection management frames (TP.CM).
+const PGN_TP_CM: u32 = 0xEC00;
+/// PGN of transport protocol data transfer frames (TP.DT).
+const PGN_TP_DT: u32 = 0xEB00;
+
+/// TP.CM control byte for a request to send (connection mode).
+const CM_RTS: u8 = 16;
+/// TP.CM control byte for a broadcast announce message.
+const CM_BAM: u8 = 32;
+/// TP.CM control byte for a connection abort.
+const CM_ABORT: u8 = 255;
+
+/// Destination address for broadcast (global) transfers.
+const GLOBAL_ADDRESS: u8 = 0xFF;
+
+/// Extracts the parameter group number (PGN) from a 29-bit J1939 CAN ID.
+///
+/// For PDU1 format PGNs (PDU format below 240), the PDU specific byte is a
+/// destination address and is not part of the PGN.
+///
+/// # Arguments
+///
+/// * `can_id` - The CAN ID, with or without the extended flag (bit 31)
+///
+/// # Example
+///
+/// ```
+/// use can_decode::j1939::pgn_from_id;
+///
+/// assert_eq!(pgn_from_id(0x18FEF100), 0xFEF1);
+/// assert_eq!(pgn_from_id(0x18EA00F9), 0xEA00);
+/// ```
+pub fn pgn_from_id(can_id: u32) -> u32 {
+    let pgn = (can_id >> 8) & 0x3_FFFF;
+    let pdu_format = (pgn >> 8) & 0xFF;
+    if pdu_format < 240 {
+        pgn & 0x3_FF00
+    } else {
+        pgn
+    }
+}
+
+/// A parameter group reassembled from a transport protocol transfer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct J1939Message {
+    /// The parameter group number of the transferred message
+    pub pgn: u32,
+    /// The source address of the sender
+    pub source: u8,
+    /// The destination address, or `0xFF` for broadcast (BAM) transfers
+    pub destination: u8,
+    /// The reassembled payload
+    pub data: Vec<u8>,
+}
+
+/// An in-progress transfer between a source and destination address.
+#[derive(Debug, Clone)]
+struct Session {
+    pgn: u32,
+    total_size: usize,
+    packet_count: u8,
+    next_sequence: u8,
+    data: Vec<u8>,
+}
+
+/// Reassembles multi-packet J1939 transport protocol transfers.
+///
+/// Feed every received frame to [`push`](J1939Reassembler::push). Frames that
+/// aren't part of a transport protocol transfer are ignored. Transfers are
+/// tracked per source/destination pair, so transfers from different nodes can
+/// be interleaved.
+///
+/// # Example
+///
+/// ```
+/// use can_decode::j1939::J1939Reassembler;
+///
+/// let mut reassembler = J1939Reassembler::new();
+///
+/// // BAM from source 0x00: 10 bytes of PGN 0xFEE3 in 2 packets
+/// assert!(reassembler
+///     .push(0x1CECFF00, &[32, 10, 0, 2, 0xFF, 0xE3, 0xFE, 0x00])
+///     .is_none());
+/// assert!(reassembler
+///     .push(0x1CEBFF00, &[1, 1, 2, 3, 4, 5, 6, 7])
+///     .is_none());
+/// let message = reassembler
+///     .push(0x1CEBFF00, &[2, 8, 9, 10, 0xFF, 0xFF, 0xFF, 0xFF])
+///     .unwrap();
+///
+/// assert_eq!(message.pgn, 0xFEE3);
+/// assert_eq!(message.source, 0x00);
+/// assert_eq!(message.data, [1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct J1939Reassembler {
+    sessions: std::collections::HashMap<(u8, u8), Session>,
+}
+
+impl J1939Reassembler {
+    /// Creates a reassembler with no transfers in progress.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Processes a received frame.
+    ///
+    /// # Arguments
+    ///
+    /// * `can_id` - The 29-bit CAN ID, with or without the extended flag (bit 31)
+    /// * `data` - The frame payload
+    ///
+    /// # Returns
+    ///
+    /// The reassembled message once the last data frame of a transfer has been
+    /// received, otherwise `None`.
+    pub fn push(&mut self, can_id: u32, data: &[u8]) -> Option<J1939Message> {
+        let pgn = pgn_from_id(can_id);
+        let destination = ((can_id >> 8) & 0xFF) as u8;
+        let source = (can_id & 0xFF) as u8;
+
+        match pgn {
+            PGN_TP_CM => {
+                self.handle_connection_management(source, destination, data);
+                None
+            }
+            PGN_TP_DT => self.handle_data_transfer(source, destination, data),
+            _ => None,
+        }
+    }
+
+    /// Starts or aborts a transfer based on a TP.CM frame.
+    fn handle_connection_management(&mut self, source: u8, destination: u8, data: &[u8]) {
+        let Some(&control) = data.first() else {
+            return;
+        };
+
+        match control {
+            CM_BAM | CM_RTS if data.len() >= 8 => {
+                if control == CM_BAM && destination != GLOBAL_ADDRESS {
+                    log::warn!(
+                        "Ignoring BAM from {source:#04X} to non-global address {destination:#04X}"
+                    );
+                    return;
+                }
+
+                let total_size = u16::from_le_bytes([data[1], data[2]]) as usize;
+                let packet_count = data[3];
+                let pgn = u32::from_le_bytes([data[5], data[6], data[7], 0]);
+                if total_size.div_ceil(7) != packet_count as usize {
+                    log::warn!(
+                        "Ignoring transfer of PGN {pgn:#X} from {source:#04X}: \
+                        {total_size} bytes don't fit in {packet_count} packets"
+                    );
+                    return;
+                }
+
+                if self.sessions.contains_key(&(source, destination)) {
+                    log::warn!(
+                        "New transfer from {source:#04X} to {destination:#04X} \
+                        replaces an unfinished transfer"
+                    );
+                }
+                self.sessions.insert(
+                    (source, destination),
+                    Session {
+                        pgn,
+                        total_size,
+                        packet_count,
+                        next_sequence: 1,
+                        data: Vec::with_capacity(packet_count as usize * 7),
+                    },
+                );
+            }
+            CM_ABORT => {
+                // Aborts can be sent by either side of a connection
+                self.sessions.remove(&(source, destination));
+                self.sessions.remove(&(destination, source));
+            }
+            _ => {}
+        }
+    }
+
+    /// Appends a TP.DT frame to its transfer, returning the message once complete.
+    fn handle_data_transfer(
+        &mut self,
+        source: u8,
+        destination: u8,
+        data: &[u8],
+    ) -> Option<J1939Message> {
+        let key = (source, destination);
+        let session = self.sessions.get_mut(&key)?;
+        let (&sequence, payload) = data.split_first()?;
+
+        if sequence < session.next_sequence {
+            // Retransmitted packet that was already received
+            return None;
+        }
+        if sequence != session.next_sequence {
+            log::warn!(
+                "Dropping transfer of PGN {:#X} from {source:#04X}: \
+                expected packet {} but got {sequence}",
+                session.pgn,
+                session.next_sequence
+            );
+            self.sessions.remove(&key);
+            return None;
+        }
+
+        session
+            .data
+            .extend_from_slice(&payload[..payload.len().min(7)]);
+        if session.next_sequence < session.packet_count {
+            session.next_sequence += 1;
+            return None;
+        }
+
+        let mut session = self.sessions.remove(&key)?;
+        if session.data.len() < session.total_size {
+            log::warn!(
+                "Dropping transfer of PGN {:#X} from {source:#04X}: \
+                received {} of {} bytes",
+                session.pgn,
+                session.data.len(),
+                session.total_size
+            );
+            return None;
+        }
+        session.data.truncate(session.total_size);
+
+        Some(J1939Message {
+            pgn: session.pgn,
+            source,
+            destination,
+            data: session.data,
+        })
+    }
+}
+
+impl Parser {
+    /// Decodes a J1939 parameter group by its PGN.
+    ///
+    /// The message definition is the extended ID message whose PGN (see
+    /// [`pgn_from_id`]) matches, regardless of the priority, source address and
+    /// destination address encoded in its DBC ID. If several messages share the
+    /// PGN, the one with the lowest ID is used, and a warning is logged when
+    /// they are loaded. This is typically used with payloads reassembled by
+    /// [`J1939Reassembler`].
+    ///
+    /// # Arguments
+    ///
+    /// * `pgn` - The parameter group number
+    /// * `data` - The (reassembled) payload
+    ///
+    /// # Returns
+    ///
+    /// Returns the decoded message, or `None` if no message with this PGN is
+    /// defined or decoding fails.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use can_decode::Parser;
+    /// use can_decode::j1939::J1939Reassembler;
+    /// use std::path::Path;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let parser = Parser::from_dbc_file(Path::new("j1939.dbc"))?;
+    /// let mut reassembler = J1939Reassembler::new();
+    ///
+    /// # let frames: Vec<(u32, Vec<u8>)> = Vec::new();
+    /// for (can_id, data) in frames {
+    ///     if let Some(message) = reassembler.push(can_id, &data) {
+    ///         if let Some(decoded) = parser.decode_j1939(message.pgn, &message.data) {
+    ///             println!("{}: {:?}", decoded.name, decoded.signals);
+    ///         }
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Messages that only differ in their source address share a PGN:
+    ///
+    /// ```
+    /// use can_decode::Parser;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut parser = Parser::new();
+    /// parser.add_from_str(
+    ///     r#"
+    /// BO_ 2565866751 EEC1_Engine2: 8 Engine2
+    ///  SG_ Speed2 : 24|16@1+ (0.125,0) [0|0] "rpm" Vector__XXX
+    ///
+    /// BO_ 2565866750 EEC1_Engine1: 8 Engine1
+    ///  SG_ Speed : 24|16@1+ (0.125,0) [0|0] "rpm" Vector__XXX
+    /// "#,
+    /// )?;
+    ///
+    /// let decoded = parser.decode_j1939(0xF004, &[0, 0, 0, 0x40, 0x1F, 0, 0, 0]).unwrap();
+    /// assert_eq!(decoded.name, "EEC1_Engine1");
+    /// assert_eq!(decoded.signals["Speed"].value.physical, 1000.0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn decode_j1939(&self, pgn: u32, data: &[u8]) -> Option<DecodedMessage> {
+        let msg_entry = self.msg_entries.get(self.msg_ids_by_pgn.get(&pgn)?)?;
+
+        self.check_payload(msg_entry, data).ok()?;
+        self.decode_msg_inner(msg_entry, data, None, None, |_idx, _def| true)
+    }
+
+    /// Adds a message to the PGN index used by [`decode_j1939`](Parser::decode_j1939).
+    ///
+    /// Standard ID messages have no PGN and are ignored. When several messages
+    /// share a PGN, the lowest ID is kept, so the choice doesn't depend on the
+    /// order in which definitions are loaded.
+    pub(crate) fn index_pgn(&mut self, msg_id: u32) {
+        if msg_id & EXTENDED_ID_FLAG == 0 {
+            return;
+        }
+        let pgn = pgn_from_id(msg_id);
+        let indexed_id = self.msg_ids_by_pgn.entry(pgn).or_insert(msg_id);
+        if *indexed_id != msg_id {
+            let kept_id = msg_id.min(*indexed_id);
+            log::warn!(
+                "Messages {:#X} and {msg_id:#X} share PGN {pgn:#X}. \
+                J1939 decoding uses {kept_id:#X}.",
+                *indexed_id
+            );
+            *indexed_id = kept_id;
+        }
+    }
+}
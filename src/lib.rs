@@ -8,11 +8,19 @@
 //! - Decode CAN messages into signals with physical values
 //! - Encode signal values back into raw CAN messages
 //! - Support for both standard and extended CAN IDs
+//! - Decode CAN FD frames with payloads of up to 64 bytes
 //! - Handle big-endian and little-endian byte ordering
 //! - Support for signed and unsigned signal values
 //! - Decode/encode IEEE-754 float signals (`SIG_VALTYPE_`)
 //! - Support for DBC enumerations (value descriptions) to map raw values to string labels
+//! - Decode multiplexed messages (only the active multiplexed group is included)
 //! - Apply scaling factors and offsets (and inverse for encoding)
+//! - Stream-decode Vector ASC log files
+//! - Export loaded definitions back to DBC text
+//! - Export decoded messages to ASAM MDF4 files (`mdf4` feature)
+//! - Reassemble and decode multi-packet J1939 transport protocol transfers (`j1939` feature)
+//! - Serialize decoded messages, e.g. to JSON (`serde` feature)
+//! - Decode directly into user-defined structs (`derive` feature)
 //!
 //! ## Decoding Example
 //!
@@ -65,9 +73,34 @@
 //! # Ok(())
 //! # }
 //! ```
+//!
+//! ## Embedded Targets
+//!
+//! Decoding requires the standard library: loaded definitions hold `can_dbc`
+//! message and signal types, and the parser uses `std` collections and locks,
+//! so the crate can't be built for `no_std` targets. On targets that do have
+//! `std` but shouldn't parse DBC text at startup, compile the definitions on
+//! the host with [`Parser::to_compiled_bytes`] and load them on the device with
+//! [`Parser::from_compiled_bytes`].
 
 pub use can_dbc;
 
+mod asc;
+mod compiled;
+mod export;
+mod staleness;
+mod typed;
+pub use asc::AscReader;
+pub use staleness::StalenessTracker;
+pub use typed::{CanDecode, FromDecodedError, FromSignal};
+
+#[cfg(feature = "derive")]
+pub use can_decode_derive::{CanDecode, SignalEnum};
+#[cfg(feature = "j1939")]
+pub mod j1939;
+#[cfg(feature = "mdf4")]
+pub mod mdf4;
+
 /// Creates a bitmask with the lowest N bits set to 1.
 ///
 /// This macro generates a mask value of the specified type with the lower `bits` bits set to 1
@@ -110,7 +143,48 @@ pub type SignalMap = indexmap::map::IndexMap<String, DecodedSignal>;
 ///
 /// This structure represents a fully decoded CAN message with all its signals
 /// extracted and converted to physical values.
+///
+/// With the `serde` feature, decoded messages and signals implement
+/// `Serialize` and `Deserialize`. The signal map is serialized as an object
+/// keyed by signal name, in decode order.
+///
+/// ```
+/// # #[cfg(feature = "serde")]
+/// # {
+/// use can_decode::{DecodedMessage, Parser};
+///
+/// let dbc = r#"
+/// VERSION ""
+///
+/// BU_: ECU
+///
+/// BO_ 256 Motor: 3 ECU
+///  SG_ Speed : 0|16@1+ (1,0) [0|8000] "rpm" Vector__XXX
+///  SG_ Gear : 16|8@1+ (1,0) [0|3] "" Vector__XXX
+///
+/// VAL_ 256 Gear 0 "Park" 1 "Drive" ;
+/// "#;
+/// let mut parser = Parser::new();
+/// parser.add_from_str(dbc).unwrap();
+/// let decoded = parser.decode_msg(256, &[0xE8, 0x03, 0x01]).unwrap();
+///
+/// // With serde_json, e.g. to feed a web dashboard
+/// let json = serde_json::to_string(&decoded).unwrap();
+/// let restored: DecodedMessage = serde_json::from_str(&json).unwrap();
+/// assert_eq!(restored.name, "Motor");
+/// assert_eq!(restored.signals["Speed"].value.physical, 1000.0);
+/// assert_eq!(restored.signals["Speed"].unit, "rpm");
+/// assert_eq!(restored.signals["Gear"].value.enum_label.as_deref(), Some("Drive"));
+/// assert_eq!(format!("{restored:?}"), format!("{decoded:?}"));
+///
+/// // Signals are an object keyed by name, in decode order
+/// let speed = json.find(r#""Speed":{"#).unwrap();
+/// let gear = json.find(r#""Gear":{"#).unwrap();
+/// assert!(speed < gear);
+/// # }
+/// ```
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DecodedMessage {
     /// The name of the message as defined in the DBC file
     pub name: String,
@@ -120,17 +194,628 @@ pub struct DecodedMessage {
     pub is_extended: bool,
     /// Transmitting node of the message ("Unknown" if not specified)
     pub tx_node: String,
-    /// Ordered map of signal names to their decoded values (maintains insertion order)
+    /// Ordered map of signal names to their decoded values, in the order the
+    /// signals are declared in the DBC message (followed by composite signals)
     pub signals: SignalMap,
+    /// `Some((expected_len, actual_len))`, both in bytes, if the payload length
+    /// didn't match the message size from the DBC. Signals that didn't fit in the
+    /// payload are omitted.
+    pub dlc_mismatch: Option<(usize, usize)>,
+}
+
+impl DecodedMessage {
+    /// Applies a sparse update on top of this message.
+    ///
+    /// Every signal present in `partial` overwrites the signal of the same name
+    /// (or is appended if it wasn't present yet), while all other signals keep
+    /// their previous values. This maintains a latest-value view of a message
+    /// that is sent both cyclically in full and as event messages containing
+    /// only changed signals.
+    ///
+    /// # Arguments
+    ///
+    /// * `partial` - The decoded update to apply
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use can_decode::Parser;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let dbc = r#"
+    /// VERSION ""
+    ///
+    /// BU_: ECU
+    ///
+    /// BO_ 256 Pack: 3 BMS
+    ///  SG_ Mux M : 0|4@1+ (1,0) [0|15] "" Vector__XXX
+    ///  SG_ Counter : 4|4@1+ (1,0) [0|15] "" Vector__XXX
+    ///  SG_ Temp m0 : 8|8@1+ (1,-40) [-40|215] "degC" Vector__XXX
+    ///  SG_ Voltage m1 : 8|16@1+ (0.5,0) [0|32767.5] "V" Vector__XXX
+    /// "#;
+    /// let mut parser = Parser::new();
+    /// parser.add_from_str(dbc)?;
+    ///
+    /// // Mux 0 carries Temp, mux 1 carries Voltage
+    /// let mut state = parser.decode_msg(256, &[0x00, 100, 0]).unwrap();
+    /// let update = parser.decode_msg(256, &[0x11, 0xE8, 0x03]).unwrap();
+    /// state.apply_update(&update);
+    ///
+    /// // Signals in the update are overwritten or appended, Temp keeps its value
+    /// assert_eq!(state.signals["Mux"].value.physical, 1.0);
+    /// assert_eq!(state.signals["Counter"].value.physical, 1.0);
+    /// assert_eq!(state.signals["Temp"].value.physical, 60.0);
+    /// assert_eq!(state.signals["Voltage"].value.physical, 500.0);
+    /// let names: Vec<&str> = state.signals.keys().map(|k| k.as_str()).collect();
+    /// assert_eq!(names, ["Mux", "Counter", "Temp", "Voltage"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn apply_update(&mut self, partial: &DecodedMessage) {
+        if partial.msg_id != self.msg_id {
+            log::warn!(
+                "Applying update from message ID {:#X} onto message ID {:#X}",
+                partial.msg_id,
+                self.msg_id
+            );
+        }
+
+        for (name, signal) in &partial.signals {
+            match self.signals.get_mut(name) {
+                Some(existing) => *existing = signal.clone(),
+                None => {
+                    self.signals.insert(name.clone(), signal.clone());
+                }
+            }
+        }
+    }
+
+    /// Compares this decode with another decode of the same message.
+    ///
+    /// Signals are compared by physical value. Signals present in only one of
+    /// the two (e.g. alternative multiplexed groups) are reported with `NaN` for
+    /// the missing side, and two `NaN` values compare equal.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The newer decode to compare against
+    ///
+    /// # Returns
+    ///
+    /// `(signal name, old value, new value)` for every signal that differs, in
+    /// this message's signal order followed by signals only present in `other`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use can_decode::Parser;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let dbc = r#"
+    /// VERSION ""
+    ///
+    /// BU_: ECU
+    ///
+    /// BO_ 256 Motor: 2 ECU
+    ///  SG_ Speed : 0|8@1+ (10,0) [0|2550] "rpm" Vector__XXX
+    ///  SG_ Temp : 8|8@1+ (1,-40) [-40|215] "degC" Vector__XXX
+    /// "#;
+    /// let mut parser = Parser::new();
+    /// parser.add_from_str(dbc)?;
+    ///
+    /// let old = parser.decode_msg(256, &[100, 60]).unwrap();
+    /// let new = parser.decode_msg(256, &[120, 60]).unwrap();
+    /// assert_eq!(old.diff(&new), [("Speed".to_string(), 1000.0, 1200.0)]);
+    /// assert!(old.diff(&old).is_empty());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn diff(&self, other: &DecodedMessage) -> Vec<(String, f64, f64)> {
+        let same = |a: f64, b: f64| a == b || (a.is_nan() && b.is_nan());
+
+        let mut changes: Vec<(String, f64, f64)> = self
+            .signals
+            .iter()
+            .filter_map(|(name, signal)| {
+                let old = signal.value.physical;
+                let new = other
+                    .signals
+                    .get(name)
+                    .map_or(f64::NAN, |signal| signal.value.physical);
+                (!same(old, new)).then(|| (name.clone(), old, new))
+            })
+            .collect();
+
+        changes.extend(
+            other
+                .signals
+                .iter()
+                .filter(|(name, _signal)| !self.signals.contains_key(*name))
+                .filter(|(_name, signal)| !signal.value.physical.is_nan())
+                .map(|(name, signal)| (name.clone(), f64::NAN, signal.value.physical)),
+        );
+        changes
+    }
+
+    /// Formats the decoded message as a single InfluxDB line protocol point.
+    ///
+    /// The output has the form `measurement,msg=Name sig1=v1,sig2=v2 timestamp`,
+    /// with one float field per signal holding its physical value. Units are
+    /// omitted since line protocol has no per-field metadata, and non-finite
+    /// values are skipped since they cannot be represented. Special characters
+    /// in the measurement, tag value, and field keys are escaped.
+    ///
+    /// # Arguments
+    ///
+    /// * `measurement` - The InfluxDB measurement name
+    /// * `timestamp_ns` - The point timestamp in nanoseconds since the Unix epoch
+    ///
+    /// # Returns
+    ///
+    /// The formatted line, or `None` if no signal has a finite value. A point
+    /// needs at least one field, and InfluxDB rejects a whole write batch that
+    /// contains a line without one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use can_decode::Parser;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let dbc = r#"
+    /// VERSION ""
+    ///
+    /// BU_: ECU
+    ///
+    /// BO_ 256 Motor: 2 ECU
+    ///  SG_ Speed : 0|8@1+ (10,0) [0|2550] "rpm" Vector__XXX
+    ///  SG_ Coolant_Temp : 8|8@1+ (0.5,-40) [-40|87.5] "degC" Vector__XXX
+    ///
+    /// BO_ 257 Sensor: 4 ECU
+    ///  SG_ Pressure : 0|32@1- (1,0) [0|0] "bar" Vector__XXX
+    ///
+    /// SIG_VALTYPE_ 257 Pressure : 1;
+    /// "#;
+    /// let mut parser = Parser::new();
+    /// parser.add_from_str(dbc)?;
+    ///
+    /// let decoded = parser.decode_msg(256, &[100, 121]).unwrap();
+    /// assert_eq!(
+    ///     decoded.to_line_protocol("can bus", 1_700_000_000_000_000_000).as_deref(),
+    ///     Some("can\\ bus,msg=Motor Speed=1000,Coolant_Temp=20.5 1700000000000000000")
+    /// );
+    ///
+    /// // A NaN pressure leaves no fields, so there is no valid line
+    /// let decoded = parser.decode_msg(257, &f32::NAN.to_le_bytes()).unwrap();
+    /// assert_eq!(decoded.to_line_protocol("can", 0), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_line_protocol(&self, measurement: &str, timestamp_ns: u64) -> Option<String> {
+        let fields = self
+            .signals
+            .iter()
+            // Line protocol has no representation for NaN/inf
+            .filter(|(_name, signal)| signal.value.physical.is_finite())
+            .map(|(name, signal)| {
+                format!(
+                    "{}={}",
+                    escape_line_protocol(name, &[',', '=', ' ']),
+                    signal.value.physical
+                )
+            })
+            .collect::<Vec<_>>();
+        if fields.is_empty() {
+            return None;
+        }
+
+        Some(format!(
+            "{},msg={} {} {}",
+            escape_line_protocol(measurement, &[',', ' ']),
+            escape_line_protocol(&self.name, &[',', '=', ' ']),
+            fields.join(","),
+            timestamp_ns
+        ))
+    }
+
+    /// Flattens the decoded message into one `(topic, value)` pair per signal.
+    ///
+    /// Topics have the form `prefix/MessageName/SignalName`, ready to publish
+    /// one signal per topic over MQTT. A trailing `/` on the prefix is ignored,
+    /// and an empty prefix yields `MessageName/SignalName`. Values are the
+    /// signals' physical values, in signal order.
+    ///
+    /// # Arguments
+    ///
+    /// * `prefix` - The topic prefix, e.g. `"vehicle/42/can"`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use can_decode::Parser;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let dbc = r#"
+    /// VERSION ""
+    ///
+    /// BU_: ECU
+    ///
+    /// BO_ 256 Motor: 2 ECU
+    ///  SG_ Speed : 0|8@1+ (10,0) [0|2550] "rpm" Vector__XXX
+    ///  SG_ Temp : 8|8@1+ (1,-40) [-40|215] "degC" Vector__XXX
+    /// "#;
+    /// let mut parser = Parser::new();
+    /// parser.add_from_str(dbc)?;
+    ///
+    /// let decoded = parser.decode_msg(256, &[100, 60]).unwrap();
+    /// assert_eq!(
+    ///     decoded.to_topic_payloads("car/can"),
+    ///     [
+    ///         ("car/can/Motor/Speed".to_string(), 1000.0),
+    ///         ("car/can/Motor/Temp".to_string(), 20.0),
+    ///     ]
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_topic_payloads(&self, prefix: &str) -> Vec<(String, f64)> {
+        let prefix = prefix.trim_end_matches('/');
+        self.signals
+            .iter()
+            .map(|(name, signal)| {
+                let topic = if prefix.is_empty() {
+                    format!("{}/{}", self.name, name)
+                } else {
+                    format!("{}/{}/{}", prefix, self.name, name)
+                };
+                (topic, signal.value.physical)
+            })
+            .collect()
+    }
+
+    /// Formats the physical values of the signals named in `header` as CSV fields.
+    ///
+    /// Use this with [`Parser::csv_header`] so that every row of a message's CSV
+    /// export has the same columns, even when some signals are missing from a
+    /// frame (e.g. inactive multiplexed groups or signals beyond a short payload).
+    ///
+    /// # Arguments
+    ///
+    /// * `header` - The signal names, in column order
+    ///
+    /// # Returns
+    ///
+    /// One field per header entry: the signal's physical value, or an empty
+    /// string if the signal is not in this message. Fields are quoted as
+    /// needed for RFC 4180 CSV.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use can_decode::Parser;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut parser = Parser::new();
+    /// parser.add_from_str(
+    ///     r#"
+    /// BO_ 256 Mixed: 2 ECU
+    ///  SG_ Mux M : 0|4@1+ (1,0) [0|15] "" Vector__XXX
+    ///  SG_ Temp m0 : 8|8@1+ (1,-40) [0|0] "degC" Vector__XXX
+    ///  SG_ Speed m1 : 8|8@1+ (0.5,0) [0|0] "km/h" Vector__XXX
+    /// "#,
+    /// )?;
+    ///
+    /// let header = parser.csv_header(256).unwrap();
+    /// assert_eq!(header, ["Mux", "Temp", "Speed"]);
+    ///
+    /// let decoded = parser.decode_msg(256, &[0x01, 0x65]).unwrap();
+    /// assert_eq!(decoded.csv_row(&header), ["1", "", "50.5"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn csv_row(&self, header: &[String]) -> Vec<String> {
+        header
+            .iter()
+            .map(|name| {
+                self.signals.get(name).map_or_else(String::new, |signal| {
+                    csv_field(&signal.value.physical.to_string())
+                })
+            })
+            .collect()
+    }
+}
+
+/// A decoded message along with the time its frame was received.
+///
+/// Returned by [`Parser::decode_timestamped`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TimestampedMessage {
+    /// The receive time supplied by the caller, e.g. a kernel timestamp
+    pub timestamp: std::time::SystemTime,
+    /// The decoded message
+    pub message: DecodedMessage,
+}
+
+/// A frame read from a line of a candump (SocketCAN) log.
+#[derive(Debug, Clone, PartialEq)]
+struct CandumpFrame {
+    /// Seconds since the epoch
+    timestamp: f64,
+    /// The CAN ID, with bit 31 set for extended IDs
+    msg_id: u32,
+    /// The payload
+    data: Vec<u8>,
+    /// Whether the frame is a CAN FD frame (`##` separator)
+    is_fd: bool,
+}
+
+/// Parses a line of `candump -l` output: `(timestamp) iface ID#DATA`.
+///
+/// IDs with 3 hex digits are standard and IDs with 8 hex digits are extended.
+/// CAN FD frames use `ID##FDATA`, where `F` is the FD flags nibble. Remote
+/// frames (`ID#R`) and anything malformed yield `None`.
+fn parse_candump_line(line: &str) -> Option<CandumpFrame> {
+    let mut fields = line.split_whitespace();
+    let timestamp = fields
+        .next()?
+        .strip_prefix('(')?
+        .strip_suffix(')')?
+        .parse()
+        .ok()?;
+    let _iface = fields.next()?;
+    let frame = fields.next()?;
+
+    let (id_str, payload) = frame.split_once('#')?;
+    let raw_id = u32::from_str_radix(id_str, 16).ok()?;
+    let msg_id = match id_str.len() {
+        3 if raw_id <= 0x7FF => raw_id,
+        8 if raw_id <= 0x1FFF_FFFF => raw_id | EXTENDED_ID_FLAG,
+        _ => return None,
+    };
+
+    let (payload, is_fd) = match payload.strip_prefix('#') {
+        // Skip the FD flags nibble
+        Some(fd_payload) => (fd_payload.get(1..)?, true),
+        None => (payload, false),
+    };
+    let hex: Vec<u8> = payload.bytes().filter(|&b| b != b'.').collect();
+    if !hex.len().is_multiple_of(2) {
+        return None;
+    }
+    let data = hex
+        .chunks(2)
+        .map(|pair| u8::from_str_radix(std::str::from_utf8(pair).ok()?, 16).ok())
+        .collect::<Option<Vec<u8>>>()?;
+
+    Some(CandumpFrame {
+        timestamp,
+        msg_id,
+        data,
+        is_fd,
+    })
+}
+
+/// Escapes the given special characters with a backslash, as required by
+/// InfluxDB line protocol.
+///
+/// Control characters are dropped, since a line break would end the line.
+fn escape_line_protocol(s: &str, special: &[char]) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars().filter(|c| !c.is_control()) {
+        if special.contains(&c) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Removes control characters, such as line breaks and tabs, from `s`.
+fn strip_control_chars(s: &str) -> String {
+    s.chars().filter(|c| !c.is_control()).collect()
+}
+
+/// Formats `s` as an RFC 4180 CSV field.
+///
+/// Fields containing a delimiter (`,` or `;`), a quote, or a line break are
+/// wrapped in double quotes, with embedded quotes doubled. Other fields are
+/// returned unchanged.
+fn csv_field(s: &str) -> String {
+    if s.contains([',', ';', '"', '\r', '\n']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+/// Resolves the backslash escapes `can_dbc` leaves in quoted DBC strings.
+///
+/// `\"` becomes `"` and `\\` becomes `\`. Any other backslash is kept as is.
+fn unescape_dbc_string(s: &str) -> String {
+    let mut unescaped = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some(next @ ('"' | '\\')) => unescaped.push(next),
+            Some(next) => {
+                unescaped.push(c);
+                unescaped.push(next);
+            }
+            None => unescaped.push(c),
+        }
+    }
+    unescaped
+}
+
+/// Converts a numeric DBC attribute value to `f64`.
+///
+/// # Returns
+///
+/// The value, or `None` for string attributes.
+fn attribute_to_f64(value: &can_dbc::AttributeValue) -> Option<f64> {
+    match value {
+        can_dbc::AttributeValue::Uint(v) => Some(*v as f64),
+        can_dbc::AttributeValue::Int(v) => Some(*v as f64),
+        can_dbc::AttributeValue::Double(v) => Some(*v),
+        can_dbc::AttributeValue::String(_) => None,
+    }
+}
+
+/// Converts a `can_dbc` parse error into a human-readable message.
+///
+/// Syntax errors include the line and column of the offending input along with
+/// the text of that line. Other errors use their `Display` message.
+fn describe_dbc_error(err: &can_dbc::DbcError) -> String {
+    let can_dbc::DbcError::Pest(pest_err) = err else {
+        return err.to_string();
+    };
+
+    // The position is only exposed through pest's own types, so read it from
+    // the ` --> line:col` line of the error report
+    let report = pest_err.to_string();
+    let position = report.lines().find_map(|line| {
+        let location = line.trim_start().strip_prefix("--> ")?;
+        let mut parts = location.rsplit(':');
+        let col: usize = parts.next()?.trim().parse().ok()?;
+        let line: usize = parts.next()?.parse().ok()?;
+        Some((line, col))
+    });
+    let Some((line, col)) = position else {
+        return report;
+    };
+
+    // Listing every rule that could start a new DBC section isn't useful to a
+    // user. Alternatives are listed as `a, b, c, or d`.
+    let reason = pest_err.variant.message();
+    let alternatives = reason
+        .rsplit_once("expected ")
+        .map_or(0, |(_, expected)| expected.matches(", ").count() + 1);
+    let reason = if alternatives > 4 {
+        "unexpected token".into()
+    } else {
+        reason
+    };
+
+    format!(
+        "syntax error at line {}, column {}: {} near `{}`",
+        line,
+        col,
+        reason,
+        pest_err.line().trim()
+    )
+}
+
+/// A `VAL_` entry that references a named `VAL_TABLE_` instead of listing values.
+struct ValueTableRef {
+    msg_id: u32,
+    signal_name: String,
+    table_name: String,
+}
+
+/// Removes `VAL_ <msg_id> <signal> <TableName> ;` references from a DBC file.
+///
+/// The DBC grammar used by `can_dbc` only accepts inline value descriptions,
+/// so references to shared value tables are cut out before parsing and
+/// resolved against the parsed `VAL_TABLE_` definitions afterwards. Removed
+/// lines are left empty so that line numbers in parse errors still match.
+fn extract_value_table_refs(buffer: &str) -> (std::borrow::Cow<'_, str>, Vec<ValueTableRef>) {
+    let parse_ref = |line: &str| -> Option<ValueTableRef> {
+        let rest = line.trim().strip_prefix("VAL_ ")?.strip_suffix(';')?;
+        let mut tokens = rest.split_whitespace();
+        let (msg_id, signal_name, table_name) = (tokens.next()?, tokens.next()?, tokens.next()?);
+        let is_identifier = table_name
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_ascii_alphabetic() || c == '_');
+        if tokens.next().is_some() || !is_identifier {
+            return None;
+        }
+
+        Some(ValueTableRef {
+            msg_id: msg_id.parse().ok()?,
+            signal_name: signal_name.to_string(),
+            table_name: table_name.to_string(),
+        })
+    };
+
+    let mut refs = Vec::new();
+    let mut stripped = String::new();
+    for line in buffer.split_inclusive('\n') {
+        match parse_ref(line) {
+            Some(table_ref) => {
+                refs.push(table_ref);
+                stripped.push_str(&line[line.trim_end().len()..]);
+            }
+            None => stripped.push_str(line),
+        }
+    }
+
+    if refs.is_empty() {
+        (std::borrow::Cow::Borrowed(buffer), refs)
+    } else {
+        (std::borrow::Cow::Owned(stripped), refs)
+    }
 }
 
 /// Represents the decoded value of a CAN signal.
+///
+/// The scaled physical value, the raw integer read off the wire, and the
+/// value description label are all produced by the same decode, so bit
+/// extraction can be checked independently of the scaling and UIs can show
+/// every representation without decoding again.
+///
+/// New fields may be added in minor releases; outside of decoding, build
+/// values with the `new_*` constructors.
+///
+/// # Example
+///
+/// ```
+/// use can_decode::Parser;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let dbc = r#"
+/// VERSION ""
+///
+/// BU_: ECU
+///
+/// BO_ 256 Motor: 3 ECU
+///  SG_ Temp : 0|8@1+ (0.5,-40) [-40|87.5] "degC" Vector__XXX
+///  SG_ Torque : 8|8@1- (2,0) [-256|254] "Nm" Vector__XXX
+///  SG_ State : 16|2@1+ (1,0) [0|3] "" Vector__XXX
+///
+/// VAL_ 256 State 0 "Off" 1 "Idle" 2 "Run";
+/// "#;
+/// let mut parser = Parser::new();
+/// parser.add_from_str(dbc)?;
+///
+/// let decoded = parser.decode_msg(256, &[100, 0xF6, 0x02]).unwrap();
+/// assert_eq!(decoded.signals["Temp"].value.raw, Some(100));
+/// assert_eq!(decoded.signals["Temp"].value.physical, 10.0);
+/// assert_eq!(decoded.signals["Temp"].value.enum_label, None);
+/// assert_eq!(decoded.signals["Torque"].value.raw, Some(-10));
+/// assert_eq!(decoded.signals["Torque"].value.physical, -20.0);
+///
+/// let state = &decoded.signals["State"].value;
+/// assert_eq!(state.raw, Some(2));
+/// assert_eq!(state.physical, 2.0);
+/// assert_eq!(state.enum_label.as_deref(), Some("Run"));
+/// # Ok(())
+/// # }
+/// ```
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
 pub struct DecodedSignalValue {
     /// The physical value of the signal after applying scaling and offset.
     pub physical: f64,
-    /// Contains the raw integer value (with sign accounting).
-    /// Present unless the signal is an IEEE float/double.
+    /// The raw integer read from the frame, before factor and offset are applied,
+    /// sign extended for signed signals. Present unless the signal is an IEEE
+    /// float/double.
     pub raw: Option<i128>,
     /// If the signal/value has an enum mapping, this contains the corresponding enum label.
     pub enum_label: Option<String>,
@@ -172,7 +857,38 @@ impl DecodedSignalValue {
 /// Represents a single signal from a CAN message after decoding. The value is
 /// either a numeric physical value (after scaling/offset) or an enum label from
 /// DBC value descriptions.
+///
+/// New fields may be added in minor releases, so signals are only produced by
+/// decoding.
+///
+/// The signal's position in the frame is copied from its definition, so bit
+/// layouts can be drawn straight from a decode result:
+///
+/// ```
+/// use can_decode::Parser;
+/// use can_decode::can_dbc::ByteOrder;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut parser = Parser::new();
+/// parser.add_from_str(
+///     r#"
+/// BO_ 256 Motor: 4 ECU
+///  SG_ Speed : 7|16@0+ (1,0) [0|0] "rpm" Vector__XXX
+///  SG_ Temp : 16|8@1+ (1,-40) [0|0] "degC" Vector__XXX
+/// "#,
+/// )?;
+///
+/// let decoded = parser.decode_msg(256, &[0x03, 0xE8, 0x64, 0x00]).unwrap();
+/// let speed = &decoded.signals["Speed"];
+/// assert_eq!((speed.start_bit, speed.size), (7, 16));
+/// assert_eq!(speed.byte_order, ByteOrder::BigEndian);
+/// assert_eq!(decoded.signals["Temp"].start_bit, 16);
+/// # Ok(())
+/// # }
+/// ```
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
 pub struct DecodedSignal {
     /// The name of the signal as defined in the DBC file
     pub name: String,
@@ -180,1032 +896,7003 @@ pub struct DecodedSignal {
     pub value: DecodedSignalValue,
     /// The unit of measurement (e.g., "km/h", "°C", "RPM")
     pub unit: String,
+    /// The natural type of the signal's value, derived from its DBC definition
+    pub kind: SignalKind,
+    /// Whether the physical value was computed by a transform set with
+    /// [`Parser::set_transform`] instead of the DBC factor and offset
+    pub transformed: bool,
+    /// The DBC scaling factor, i.e. the signal's resolution
+    pub factor: f64,
+    /// The DBC offset
+    pub offset: f64,
+    /// The DBC minimum physical value, or `None` if the DBC range is `[0|0]`
+    /// (unconstrained)
+    pub min: Option<f64>,
+    /// The DBC maximum physical value, or `None` if the DBC range is `[0|0]`
+    /// (unconstrained)
+    pub max: Option<f64>,
+    /// Whether the physical value lies within `[min, max]`, or `None` if the
+    /// DBC doesn't constrain the signal
+    pub in_range: Option<bool>,
+    /// Whether the raw value is the signal's "signal not available" sentinel
+    /// (see [`Parser::set_sna_detection`] and [`Parser::set_sna_value`])
+    pub is_sna: bool,
+    /// The DBC start bit (0 for composite signals)
+    pub start_bit: u16,
+    /// The signal length in bits (0 for composite signals)
+    pub size: u16,
+    /// The signal byte order (little-endian for composite signals)
+    pub byte_order: can_dbc::ByteOrder,
 }
 
-/// Specifies the IEEE-754 floating-point format for a signal.
-///
-/// Used internally to properly decode and encode signals that are stored as
-/// IEEE-754 floating-point values in CAN messages (via the `SIG_VALTYPE_` DBC field).
+/// The natural type of a signal's value, derived from its DBC definition.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
-pub enum FloatFormat {
-    /// 32-bit IEEE-754 single-precision float (f32)
-    F32,
-    /// 64-bit IEEE-754 double-precision float (f64)
-    F64,
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SignalKind {
+    /// Unscaled unsigned integer
+    Unsigned,
+    /// Unscaled signed integer
+    Signed,
+    /// Unscaled single-bit unsigned flag
+    Bool,
+    /// IEEE float/double, or an integer with a non-identity factor/offset
+    Float,
 }
 
-impl FloatFormat {
-    /// Converts a DBC signal extended value type to a FloatFormat if it's a float type.
+impl SignalKind {
+    /// Determines the kind of a signal from its definition and float format.
     ///
-    /// # Arguments
+    /// Integer signals are only reported as integers (or booleans) when their
+    /// factor is 1 and offset is 0, since otherwise the physical value may be
+    /// fractional.
+    pub fn from_signal_def(
+        signal_def: &can_dbc::Signal,
+        float_format: Option<FloatFormat>,
+    ) -> Self {
+        let is_identity = signal_def.factor == 1.0 && signal_def.offset == 0.0;
+        match (signal_def.value_type, float_format) {
+            (_, Some(_)) => SignalKind::Float,
+            _ if !is_identity => SignalKind::Float,
+            (can_dbc::ValueType::Signed, None) => SignalKind::Signed,
+            (can_dbc::ValueType::Unsigned, None) if signal_def.size == 1 => SignalKind::Bool,
+            (can_dbc::ValueType::Unsigned, None) => SignalKind::Unsigned,
+        }
+    }
+}
+
+/// A decoded signal value in its natural type.
+///
+/// Returned by [`DecodedSignal::typed_value`] for consumers that want precise
+/// types (e.g. for serialization) rather than a lossy `f64` for every signal.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SignalValue {
+    /// An unscaled unsigned integer
+    Unsigned(u64),
+    /// An unscaled signed integer
+    Signed(i64),
+    /// An IEEE float/double or scaled physical value
+    Float(f64),
+    /// The label of a DBC value description
+    Enum(String),
+    /// A single-bit flag
+    Bool(bool),
+}
+
+impl DecodedSignal {
+    /// Returns the signal's value in its natural type.
     ///
-    /// * `def` - The DBC signal extended value type definition
+    /// Enum labels take precedence; otherwise the value is typed according to
+    /// [`kind`](DecodedSignal::kind), falling back to the physical value as
+    /// [`SignalValue::Float`] if the raw value is unavailable.
     ///
-    /// # Returns
+    /// # Example
     ///
-    /// Returns `Some(FloatFormat)` if the definition is a float type, or `None`
-    /// if it's a signed/unsigned integer type.
-    pub fn from_dbc_def(def: can_dbc::SignalExtendedValueType) -> Option<Self> {
-        match def {
-            can_dbc::SignalExtendedValueType::IEEEfloat32Bit => Some(FloatFormat::F32),
-            can_dbc::SignalExtendedValueType::IEEEdouble64bit => Some(FloatFormat::F64),
-            can_dbc::SignalExtendedValueType::SignedOrUnsignedInteger => None,
-        }
-    }
-
-    /// Returns the size of this float format in bits.
+    /// ```
+    /// use can_decode::{Parser, SignalValue};
     ///
-    /// # Returns
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let dbc = r#"
+    /// VERSION ""
     ///
-    /// 32 for `F32`, or 64 for `F64`.
-    pub fn bit_size(&self) -> usize {
-        match self {
-            FloatFormat::F32 => 32,
-            FloatFormat::F64 => 64,
+    /// BU_: ECU
+    ///
+    /// BO_ 256 Status: 2 ECU
+    ///  SG_ Enabled : 0|1@1+ (1,0) [0|1] "" Vector__XXX
+    ///  SG_ Offset : 1|7@1- (1,0) [-64|63] "" Vector__XXX
+    ///  SG_ Voltage : 8|8@1+ (0.1,0) [0|25.5] "V" Vector__XXX
+    /// "#;
+    /// let mut parser = Parser::new();
+    /// parser.add_from_str(dbc)?;
+    ///
+    /// let decoded = parser.decode_msg(256, &[0xFD, 0x7B]).unwrap();
+    /// assert_eq!(decoded.signals["Enabled"].typed_value(), SignalValue::Bool(true));
+    /// assert_eq!(decoded.signals["Offset"].typed_value(), SignalValue::Signed(-2));
+    /// assert!(matches!(decoded.signals["Voltage"].typed_value(), SignalValue::Float(_)));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn typed_value(&self) -> SignalValue {
+        if let Some(label) = &self.value.enum_label {
+            return SignalValue::Enum(label.clone());
         }
-    }
-}
-
-/// Signal metadata for a signal's value interpretation and DBC description/comment.
-///
-/// Encapsulates optional formatting information for a signal, including
-/// enum mappings (for value descriptions) and float format specifications
-/// (for IEEE-754 encoded signals).
-///
-/// Also includes the signal-level description/comment from the DBC, if available.
-#[derive(Debug, Clone, Default)]
-pub struct SignalMeta {
-    /// Maps raw signal values to string enum labels from DBC value descriptions.
-    pub enum_map: std::collections::HashMap<i128, String>,
-
-    /// The IEEE-754 float format, if this signal is an IEEE float/double.
-    pub float_format: Option<FloatFormat>,
-
-    /// Signal-level description/comment from the DBC.
-    pub sig_comment: Option<String>,
-}
 
-/// Internal entry representing a loaded CAN message with its format definitions.
-///
-/// Stores both the base DBC message definition and the signal-specific format
-/// metadata (enums and float formats) in a unified structure.
-#[derive(Debug, Clone)]
-pub struct MsgEntry {
-    /// The base DBC message definition containing signals and metadata.
-    pub msg_def: can_dbc::Message,
-    /// Message-level description/comment from DBC (if available)
-    pub msg_desc: Option<String>,
-    /// Extra metadata for signals indexed by signal name.
-    /// This includes enum mappings for value descriptions and float format info
-    /// for IEEE float signals as well as signal-level comments.
-    pub signal_meta: std::collections::HashMap<String, SignalMeta>,
-}
+        let Some(raw) = self.value.raw else {
+            return SignalValue::Float(self.value.physical);
+        };
 
-impl MsgEntry {
-    /// Creates a new message entry from a can_dbc message definition.
-    ///
-    /// # Arguments
-    ///
-    /// * `msg_def` - The DBC message definition
-    ///
-    /// # Returns
-    ///
-    /// A new `MsgEntry` with the message definition, and empty description, and
-    /// an empty signal metadata map (to be populated with enums, float formats, and comments).
-    fn new(msg_def: can_dbc::Message) -> Self {
-        Self {
-            msg_def,
-            msg_desc: None,
-            signal_meta: std::collections::HashMap::new(),
+        match self.kind {
+            SignalKind::Unsigned => SignalValue::Unsigned(raw as u64),
+            SignalKind::Signed => SignalValue::Signed(raw as i64),
+            SignalKind::Bool => SignalValue::Bool(raw != 0),
+            SignalKind::Float => SignalValue::Float(self.value.physical),
         }
     }
-}
-
-/// A CAN message parser that uses DBC file definitions.
-///
-/// The parser loads message and signal definitions from DBC files and uses them
-/// to decode raw CAN frame data into structured messages with physical signal values.
-///
-/// # Example
-///
-/// ```no_run
-/// use can_decode::Parser;
-/// use std::path::Path;
-///
-/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
-/// let mut parser = Parser::new();
-/// parser.add_from_dbc_file(Path::new("engine.dbc"))?;
-/// parser.add_from_dbc_file(Path::new("transmission.dbc"))?;
-///
-/// let data = [0x00, 0x10, 0x20, 0x30, 0x40, 0x50, 0x60, 0x70];
-/// if let Some(decoded) = parser.decode_msg(0x100, &data) {
-///     println!("Decoded message: {}", decoded.name);
-/// }
-/// # Ok(())
-/// # }
-/// ```
-#[derive(Debug, Clone)]
-pub struct Parser {
-    msg_entries: std::collections::HashMap<u32, MsgEntry>,
-}
 
-impl Parser {
-    /// Creates a new empty parser.
+    /// Returns the `VAL_` description matching the signal's raw value, if any.
     ///
-    /// Use [`add_from_dbc_file`](Parser::add_from_dbc_file) or
-    /// [`add_from_str`](Parser::add_from_str) to add message definitions.
+    /// This is shorthand for [`value.enum_label`](DecodedSignalValue::enum_label).
     ///
     /// # Example
     ///
     /// ```
     /// use can_decode::Parser;
     ///
-    /// let parser = Parser::new();
-    /// ```
-    pub fn new() -> Self {
-        Self {
-            msg_entries: std::collections::HashMap::new(),
-        }
-    }
-
-    /// Creates a parser and loads definitions from a DBC file.
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let dbc = r#"
+    /// VERSION ""
     ///
-    /// This is a convenience method that combines [`new`](Parser::new) and
-    /// [`add_from_str`](Parser::add_from_str).
+    /// BU_: ECU
     ///
-    /// # Arguments
+    /// BO_ 256 Heater: 1 ECU
+    ///  SG_ State : 0|2@1+ (1,0) [0|3] "" Vector__XXX
     ///
-    /// * `path` - Path to the DBC file
+    /// VAL_ 256 State 0 "Off" 1 "On" 2 "Fault" ;
+    /// "#;
+    /// let mut parser = Parser::new();
+    /// parser.add_from_str(dbc)?;
     ///
-    /// # Errors
+    /// let decoded = parser.decode_msg(256, &[2]).unwrap();
+    /// assert_eq!(decoded.signals["State"].value_description(), Some("Fault"));
+    ///
+    /// // Raw values without a description decode as plain numbers
+    /// let decoded = parser.decode_msg(256, &[3]).unwrap();
+    /// assert_eq!(decoded.signals["State"].value_description(), None);
+    /// assert_eq!(decoded.signals["State"].value.physical, 3.0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn value_description(&self) -> Option<&str> {
+        self.value.enum_label.as_deref()
+    }
+
+    /// Returns the physical value snapped to the signal's resolution.
     ///
-    /// Returns an error if the file cannot be read or parsed.
+    /// The value is rounded to the nearest `offset + n * factor`, removing
+    /// floating-point artifacts such as `0.30000000000000004` for a signal with
+    /// factor 0.1. Factors that are the reciprocal of an integer are applied by
+    /// division, so decimal resolutions produce the closest `f64` to the decimal
+    /// value. IEEE float signals and values computed by a
+    /// [`set_transform`](Parser::set_transform) transform don't lie on the
+    /// factor grid, so they are returned as is, as are non-finite values and
+    /// signals with a zero factor.
     ///
     /// # Example
     ///
-    /// ```no_run
+    /// ```
     /// use can_decode::Parser;
-    /// use std::path::Path;
     ///
     /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
-    /// let parser = Parser::from_dbc_file(Path::new("my_database.dbc"))?;
+    /// let dbc = r#"
+    /// VERSION ""
+    ///
+    /// BU_: ECU
+    ///
+    /// BO_ 256 Status: 1 ECU
+    ///  SG_ Voltage : 0|8@1+ (0.1,0) [0|25.5] "V" Vector__XXX
+    ///
+    /// BO_ 257 Sensors: 5 ECU
+    ///  SG_ Pressure : 0|32@1- (1,0) [0|0] "bar" Vector__XXX
+    ///  SG_ Level : 32|8@1+ (1,0) [0|0] "%" Vector__XXX
+    ///
+    /// SIG_VALTYPE_ 257 Pressure : 1;
+    /// "#;
+    /// let mut parser = Parser::new();
+    /// parser.add_from_str(dbc)?;
+    ///
+    /// let decoded = parser.decode_msg(256, &[3]).unwrap();
+    /// let voltage = &decoded.signals["Voltage"];
+    /// assert_eq!(voltage.value.physical, 3.0 * 0.1);
+    /// assert_eq!(voltage.quantized(), 0.3);
+    ///
+    /// // IEEE floats and transform outputs are left off the factor grid
+    /// parser.set_transform(257, "Level", Box::new(|raw| raw as f64 / 2.55))?;
+    /// let mut data = 3.75f32.to_le_bytes().to_vec();
+    /// data.push(51);
+    /// let decoded = parser.decode_msg(257, &data).unwrap();
+    /// assert_eq!(decoded.signals["Pressure"].quantized(), 3.75);
+    /// assert!(decoded.signals["Level"].transformed);
+    /// assert_eq!(decoded.signals["Level"].quantized(), 51.0 / 2.55);
     /// # Ok(())
     /// # }
     /// ```
-    pub fn from_dbc_file(path: &std::path::Path) -> Result<Self, Box<dyn std::error::Error>> {
-        let mut parser = Self::new();
-        parser.add_from_dbc_file(path)?;
-        Ok(parser)
+    pub fn quantized(&self) -> f64 {
+        let physical = self.value.physical;
+        let float_backed = self.value.raw.is_none();
+        if float_backed || self.transformed || self.factor == 0.0 || !physical.is_finite() {
+            return physical;
+        }
+
+        let steps = ((physical - self.offset) / self.factor).round();
+        let inverse = 1.0 / self.factor;
+        let scaled = if (inverse - inverse.round()).abs() <= inverse.abs() * 1e-9 {
+            steps / inverse.round()
+        } else {
+            steps * self.factor
+        };
+        scaled + self.offset
     }
 
-    /// Adds message and enum/value-description definitions from a DBC string.
-    ///
-    /// This method parses DBC content from a string slice and adds all message
-    /// definitions to the parser. If a message ID already exists, it will be
-    /// overwritten and a warning will be logged. Signal value descriptions
-    /// (enumerations) are also captured for enum decoding. Signal extended
-    /// value types (`SIG_VALTYPE_`) are captured for IEEE float/double decoding.
-    ///
-    /// # Arguments
+    /// Returns the unit with characters that break delimited output neutralized.
     ///
-    /// * `buffer` - String slice containing the full DBC file contents
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if the DBC content cannot be parsed.
+    /// Control characters (including newlines and tabs) from malformed DBCs are
+    /// stripped. If what remains contains a delimiter (`,` or `;`), a quote, or
+    /// a line break, it is quoted as an RFC 4180 CSV field, with embedded quotes
+    /// doubled. The result can be written into a CSV row as is.
     ///
     /// # Example
     ///
-    /// ```no_run
+    /// ```
     /// use can_decode::Parser;
     ///
     /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
-    /// let dbc_content = "VERSION \"\"..."; // DBC file content as &str
     /// let mut parser = Parser::new();
-    /// parser.add_from_str(dbc_content)?;
+    /// parser.add_from_str(
+    ///     r#"
+    /// BO_ 256 Flow: 2 ECU
+    ///  SG_ Rate : 0|8@1+ (1,0) [0|0] "m3,h" Vector__XXX
+    ///  SG_ Level : 8|8@1+ (1,0) [0|0] "%" Vector__XXX
+    /// "#,
+    /// )?;
+    ///
+    /// let decoded = parser.decode_msg(256, &[1, 2]).unwrap();
+    /// assert_eq!(decoded.signals["Rate"].sanitized_unit(), "\"m3,h\"");
+    /// assert_eq!(decoded.signals["Level"].sanitized_unit(), "%");
     /// # Ok(())
     /// # }
     /// ```
-    pub fn add_from_str(&mut self, buffer: &str) -> Result<(), Box<dyn std::error::Error>> {
-        let dbc = can_dbc::Dbc::try_from(buffer).map_err(|e| {
-            log::error!("Failed to parse DBC: {:?}", e);
-            format!("{:?}", e)
-        })?;
-
-        // Insert message definitions
-        for msg_def in dbc.messages {
-            let msg_id = msg_def.id.raw();
-            if self.msg_entries.contains_key(&msg_id) {
-                log::warn!(
-                    "Duplicate message ID {msg_id:#X} ({}). Overwriting existing definition.",
-                    msg_def.name
-                );
-            }
-            self.msg_entries.insert(msg_id, MsgEntry::new(msg_def));
-        }
-
-        // Enum handling
-        for val_desc in dbc.value_descriptions {
-            let can_dbc::ValueDescription::Signal {
-                message_id,
-                name,
-                value_descriptions,
-            } = val_desc
-            else {
-                continue;
-            };
+    pub fn sanitized_unit(&self) -> String {
+        csv_field(&strip_control_chars(&self.unit))
+    }
+}
 
-            let msg_id = message_id.raw();
+/// How decoding handles signals whose scaled physical value is NaN or infinite.
+///
+/// Set with [`Parser::set_non_finite_policy`].
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub enum NonFinitePolicy {
+    /// Include the signal with its non-finite value
+    #[default]
+    Propagate,
+    /// Leave the signal out of the decoded message
+    Omit,
+    /// Treat the signal as failed to decode: reported as
+    /// [`SignalDecodeError::NonFinite`] by [`Parser::decode_msg_detailed`], and
+    /// causing the other decode methods to return `None`
+    Error,
+}
 
-            let Some(msg_entry) = self.msg_entries.get_mut(&msg_id) else {
-                log::warn!(
-                    "Value description for signal '{}' references unknown message ID {:#X}. \
-                    Skipping.",
-                    name,
-                    msg_id
-                );
-                continue;
-            };
+/// How decoding handles a multiplexor switch value that selects no defined
+/// multiplexed group.
+///
+/// Such a value usually means the frame is corrupt or the DBC is missing a
+/// group. Set with [`Parser::set_unknown_mux_policy`].
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub enum UnknownMuxPolicy {
+    /// Decode the plain signals and the switch, without any multiplexed signals
+    #[default]
+    Ignore,
+    /// Treat the message as failed to decode: reported as
+    /// [`SignalDecodeError::UnknownMuxValue`] by [`Parser::decode_msg_detailed`],
+    /// and causing the other decode methods to return `None`
+    Error,
+}
 
-            let enum_def = SignalMeta {
-                enum_map: value_descriptions
-                    .iter()
-                    .map(|vd| (vd.id as i128, vd.description.clone()))
-                    .collect(),
-                ..Default::default()
-            };
+/// How the message ID passed to the decode methods is mapped to a definition.
+///
+/// Extended message definitions are keyed with bit 31 set, matching
+/// `can_dbc::MessageId::raw` and the SocketCAN `CAN_EFF_FLAG`.
+///
+/// Set with [`Parser::set_id_flag_handling`].
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub enum IdFlagMode {
+    /// Look up the ID exactly as given
+    #[default]
+    Raw,
+    /// Treat the ID as a SocketCAN `can_id`: the RTR (bit 30) and error (bit 29)
+    /// flags are cleared, and bit 31 marks an extended ID
+    SocketCan,
+    /// Look up the ID bitwise ANDed with the given mask
+    Masked(u32),
+}
 
-            if let Some(existing) = msg_entry.signal_meta.get_mut(&name) {
-                existing.enum_map = enum_def.enum_map;
+impl IdFlagMode {
+    /// SocketCAN remote transmission request flag
+    const RTR_FLAG: u32 = 0x4000_0000;
+    /// SocketCAN error frame flag
+    const ERR_FLAG: u32 = 0x2000_0000;
 
-                log::warn!(
-                    "Duplicate value description for signal '{}' in message ID {:#X}. \
-                    Overwriting existing enum definition.",
-                    name,
-                    msg_id
-                );
-            } else {
-                msg_entry.signal_meta.insert(name.clone(), enum_def);
-            }
+    /// Maps a message ID as passed by the caller to a definition key.
+    fn apply(self, msg_id: u32) -> u32 {
+        match self {
+            IdFlagMode::Raw => msg_id,
+            IdFlagMode::SocketCan => msg_id & !(Self::RTR_FLAG | Self::ERR_FLAG),
+            IdFlagMode::Masked(mask) => msg_id & mask,
         }
+    }
+}
 
-        // Float handling
-        for sig_ext_val_typ in dbc.signal_extended_value_type_list {
-            let Some(float_format) =
-                FloatFormat::from_dbc_def(sig_ext_val_typ.signal_extended_value_type)
-            else {
-                continue;
-            };
-
-            let msg_id = sig_ext_val_typ.message_id.raw();
-            let signal_name = &sig_ext_val_typ.signal_name;
+/// Specifies the IEEE-754 floating-point format for a signal.
+///
+/// Used internally to properly decode and encode signals that are stored as
+/// IEEE-754 floating-point values in CAN messages (via the `SIG_VALTYPE_` DBC field).
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum FloatFormat {
+    /// 32-bit IEEE-754 single-precision float (f32)
+    F32,
+    /// 64-bit IEEE-754 double-precision float (f64)
+    F64,
+}
 
-            let Some(msg_entry) = self.msg_entries.get_mut(&msg_id) else {
-                log::warn!(
-                    "Float definition for signal '{}' references unknown message ID {:#X}. \
-                    Skipping.",
-                    signal_name,
-                    msg_id
-                );
-                continue;
-            };
+impl FloatFormat {
+    /// Converts a DBC signal extended value type to a FloatFormat if it's a float type.
+    ///
+    /// # Arguments
+    ///
+    /// * `def` - The DBC signal extended value type definition
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(FloatFormat)` if the definition is a float type, or `None`
+    /// if it's a signed/unsigned integer type.
+    pub fn from_dbc_def(def: can_dbc::SignalExtendedValueType) -> Option<Self> {
+        match def {
+            can_dbc::SignalExtendedValueType::IEEEfloat32Bit => Some(FloatFormat::F32),
+            can_dbc::SignalExtendedValueType::IEEEdouble64bit => Some(FloatFormat::F64),
+            can_dbc::SignalExtendedValueType::SignedOrUnsignedInteger => None,
+        }
+    }
 
-            let Some(signal_def) = msg_entry
-                .msg_def
-                .signals
-                .iter()
-                .find(|s| s.name == *signal_name)
-            else {
-                log::warn!(
-                    "Float definition for signal '{}' references unknown signal in message ID {:#X}. \
-                    Skipping.",
-                    signal_name,
-                    msg_id
-                );
-                continue;
-            };
+    /// Returns the size of this float format in bits.
+    ///
+    /// # Returns
+    ///
+    /// 32 for `F32`, or 64 for `F64`.
+    pub fn bit_size(&self) -> usize {
+        match self {
+            FloatFormat::F32 => 32,
+            FloatFormat::F64 => 64,
+        }
+    }
+}
 
-            if signal_def.size != float_format.bit_size() as u64 {
-                log::warn!(
-                    "Signal '{}' in message ID {:#X} marked as {} but size is {} bits. \
-                    Skipping float definition.",
-                    signal_name,
-                    msg_id,
-                    match float_format {
-                        FloatFormat::F32 => "f32",
-                        FloatFormat::F64 => "f64",
-                    },
-                    signal_def.size
-                );
-                continue;
+/// The value of a DBC attribute (`BA_`), typed by its definition (`BA_DEF_`).
+///
+/// Returned by [`Parser::message_attribute`] and [`Parser::signal_attribute`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum AttributeValue {
+    /// An `INT` or `HEX` attribute
+    Int(i64),
+    /// A `FLOAT` attribute
+    Float(f64),
+    /// A `STRING` attribute
+    String(String),
+    /// The label of an `ENUM` attribute's value
+    Enum(String),
+}
+
+impl AttributeValue {
+    /// Converts a parsed attribute value according to its definition.
+    ///
+    /// Enum attribute values are given as an index into the definition's
+    /// labels (defaults may also give the label itself). Attributes without a
+    /// definition are typed by their literal.
+    ///
+    /// # Returns
+    ///
+    /// The typed value, or `None` if the value doesn't match its definition.
+    fn from_dbc(
+        value: &can_dbc::AttributeValue,
+        value_type: Option<&can_dbc::AttributeValueType>,
+    ) -> Option<Self> {
+        let as_i64 = |value: &can_dbc::AttributeValue| match *value {
+            can_dbc::AttributeValue::Uint(v) => i64::try_from(v).ok(),
+            can_dbc::AttributeValue::Int(v) => Some(v),
+            can_dbc::AttributeValue::Double(v) => (v.fract() == 0.0).then_some(v as i64),
+            can_dbc::AttributeValue::String(_) => None,
+        };
+
+        match (value_type, value) {
+            (
+                Some(can_dbc::AttributeValueType::Enum(_)),
+                can_dbc::AttributeValue::String(label),
+            ) => Some(Self::Enum(label.clone())),
+            (Some(can_dbc::AttributeValueType::Enum(labels)), value) => {
+                let index = usize::try_from(as_i64(value)?).ok()?;
+                labels.get(index).cloned().map(Self::Enum)
+            }
+            (
+                Some(can_dbc::AttributeValueType::Int(..) | can_dbc::AttributeValueType::Hex(..)),
+                value,
+            ) => as_i64(value).map(Self::Int),
+            (Some(can_dbc::AttributeValueType::Float(..)), value) => {
+                attribute_to_f64(value).map(Self::Float)
             }
+            (_, can_dbc::AttributeValue::String(text)) => Some(Self::String(text.clone())),
+            (Some(can_dbc::AttributeValueType::String), _) => None,
+            (None, can_dbc::AttributeValue::Double(v)) => Some(Self::Float(*v)),
+            (None, value) => as_i64(value).map(Self::Int),
+        }
+    }
 
-            let format_def = SignalMeta {
-                float_format: Some(float_format),
-                ..Default::default()
-            };
+    /// Returns the value as a number, for `Int` and `Float` attributes.
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            AttributeValue::Int(v) => Some(*v as f64),
+            AttributeValue::Float(v) => Some(*v),
+            AttributeValue::String(_) | AttributeValue::Enum(_) => None,
+        }
+    }
 
-            if let Some(existing) = msg_entry.signal_meta.get_mut(signal_name) {
-                existing.float_format = format_def.float_format;
+    /// Returns the text of `String` attributes and the label of `Enum` attributes.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            AttributeValue::String(text) | AttributeValue::Enum(text) => Some(text),
+            AttributeValue::Int(_) | AttributeValue::Float(_) => None,
+        }
+    }
+}
 
-                log::warn!(
-                    "Duplicate float definition for signal '{}' in message ID {:#X}. \
-                    Overwriting existing float definition.",
-                    signal_name,
-                    msg_id
-                );
-            } else {
-                msg_entry
-                    .signal_meta
-                    .insert(signal_name.clone(), format_def);
+/// Bit 31 of a raw DBC message ID, set for extended (29-bit) IDs.
+const EXTENDED_ID_FLAG: u32 = 1 << 31;
+
+/// Payload lengths in bytes for each CAN (FD) DLC code, indexed by DLC.
+///
+/// Codes 0-8 map directly to their byte count, while codes 9-15 are only valid
+/// for CAN FD and map to the larger FD payload sizes.
+const DLC_TO_LEN: [usize; 16] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 12, 16, 20, 24, 32, 48, 64];
+
+/// Errors returned when loading DBC definitions.
+#[derive(Debug)]
+pub enum CanDecodeError {
+    /// The DBC file could not be read
+    Io(std::io::Error),
+    /// The DBC file is not valid UTF-8
+    Utf8(std::string::FromUtf8Error),
+    /// The DBC content could not be parsed, with a description of the problem
+    Parse(String),
+    /// Compiled definitions (see [`Parser::from_compiled_bytes`]) are malformed
+    /// or were written by an incompatible version, with a description of the
+    /// problem
+    InvalidCompiled(String),
+    /// No message with this ID is loaded
+    UnknownMessage(u32),
+    /// The message has no signal with this name
+    UnknownSignal {
+        /// The CAN message identifier
+        msg_id: u32,
+        /// The signal name
+        signal: String,
+    },
+    /// The loaded definitions failed strict validation (see
+    /// [`ParserBuilder::strict_validation`])
+    InvalidDefinitions(Vec<DefinitionIssue>),
+}
+
+impl std::fmt::Display for CanDecodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CanDecodeError::Io(e) => write!(f, "failed to read DBC file: {e}"),
+            CanDecodeError::Utf8(e) => write!(f, "DBC file is not valid UTF-8: {e}"),
+            CanDecodeError::Parse(msg) => write!(f, "failed to parse DBC: {msg}"),
+            CanDecodeError::InvalidCompiled(msg) => {
+                write!(f, "invalid compiled definitions: {msg}")
+            }
+            CanDecodeError::UnknownMessage(msg_id) => write!(f, "unknown message ID {msg_id:#X}"),
+            CanDecodeError::UnknownSignal { msg_id, signal } => {
+                write!(f, "unknown signal '{signal}' in message ID {msg_id:#X}")
+            }
+            CanDecodeError::InvalidDefinitions(issues) => {
+                write!(f, "{} invalid definition(s)", issues.len())?;
+                for issue in issues {
+                    write!(f, "; {issue}")?;
+                }
+                Ok(())
             }
         }
+    }
+}
 
-        // Descriptions/comments handling
-        for comment in dbc.comments {
-            match comment {
-                can_dbc::Comment::Message { id, comment } => {
-                    let msg_id = id.raw();
+impl std::error::Error for CanDecodeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            CanDecodeError::Io(e) => Some(e),
+            CanDecodeError::Utf8(e) => Some(e),
+            CanDecodeError::Parse(_)
+            | CanDecodeError::InvalidCompiled(_)
+            | CanDecodeError::UnknownMessage(_)
+            | CanDecodeError::UnknownSignal { .. }
+            | CanDecodeError::InvalidDefinitions(_) => None,
+        }
+    }
+}
 
-                    let Some(msg_entry) = self.msg_entries.get_mut(&msg_id) else {
-                        log::warn!("Comment for unknown message ID {:#X}. Skipping.", msg_id);
-                        continue;
-                    };
+impl From<std::io::Error> for CanDecodeError {
+    fn from(e: std::io::Error) -> Self {
+        CanDecodeError::Io(e)
+    }
+}
 
-                    if msg_entry.msg_desc.is_some() {
-                        log::warn!(
-                            "Duplicate comment for message ID {:#X}. \
-                            Overwriting existing message comment.",
-                            msg_id
-                        );
-                    }
+impl From<std::string::FromUtf8Error> for CanDecodeError {
+    fn from(e: std::string::FromUtf8Error) -> Self {
+        CanDecodeError::Utf8(e)
+    }
+}
 
-                    msg_entry.msg_desc = Some(comment);
-                }
-                can_dbc::Comment::Signal {
-                    message_id,
-                    name,
-                    comment,
-                } => {
-                    let msg_id = message_id.raw();
+/// A malformed message definition, as reported by [`Parser::validate_definitions`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DefinitionIssue {
+    /// The message is larger than the 64-byte CAN FD maximum
+    MessageTooLarge {
+        /// The CAN message identifier (bit 31 set for extended IDs)
+        msg_id: u32,
+        /// The message size in bytes as defined in the DBC
+        size: u64,
+    },
+    /// The signal is 0 bits or more than 64 bits wide
+    InvalidSignalSize {
+        /// The CAN message identifier (bit 31 set for extended IDs)
+        msg_id: u32,
+        /// The signal name
+        signal: String,
+        /// The signal length in bits
+        size: u64,
+    },
+    /// Some of the signal's bits lie beyond the end of the message
+    SignalOutOfBounds {
+        /// The CAN message identifier (bit 31 set for extended IDs)
+        msg_id: u32,
+        /// The signal name
+        signal: String,
+    },
+    /// Two signals that can be present in the same frame share bits
+    OverlappingSignals {
+        /// The CAN message identifier (bit 31 set for extended IDs)
+        msg_id: u32,
+        /// The signal declared first
+        first: String,
+        /// The signal declared second
+        second: String,
+    },
+}
 
-                    let Some(msg_entry) = self.msg_entries.get_mut(&msg_id) else {
-                        log::warn!(
-                            "Comment for signal '{}' references unknown message ID {:#X}. Skipping.",
-                            name,
-                            msg_id
-                        );
-                        continue;
-                    };
+impl std::fmt::Display for DefinitionIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DefinitionIssue::MessageTooLarge { msg_id, size } => write!(
+                f,
+                "message {msg_id:#X} is {size} bytes, more than the 64-byte maximum"
+            ),
+            DefinitionIssue::InvalidSignalSize {
+                msg_id,
+                signal,
+                size,
+            } => write!(
+                f,
+                "signal '{signal}' of message {msg_id:#X} is {size} bits wide"
+            ),
+            DefinitionIssue::SignalOutOfBounds { msg_id, signal } => write!(
+                f,
+                "signal '{signal}' extends beyond the end of message {msg_id:#X}"
+            ),
+            DefinitionIssue::OverlappingSignals {
+                msg_id,
+                first,
+                second,
+            } => write!(
+                f,
+                "signals '{first}' and '{second}' of message {msg_id:#X} overlap"
+            ),
+        }
+    }
+}
 
-                    let signal_meta = msg_entry.signal_meta.entry(name.clone()).or_default();
+/// Errors reported when validating a received frame against its DBC definition.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FrameError {
+    /// No message with this ID (and any extended flag) is defined
+    UnknownMessage(u32),
+    /// The message is defined, but with the opposite standard/extended ID format
+    ExtendedMismatch {
+        /// The CAN message ID as received
+        msg_id: u32,
+        /// Whether the frame was received with an extended ID
+        is_extended: bool,
+    },
+    /// The DLC code is not a valid CAN or CAN FD DLC (0-15)
+    InvalidDlc(u8),
+    /// The received payload length doesn't match the length encoded by the DLC
+    DlcMismatch {
+        /// The DLC code from the frame header
+        dlc: u8,
+        /// The number of payload bytes received
+        data_len: usize,
+    },
+    /// The received payload length doesn't match the message size from the DBC
+    LengthMismatch {
+        /// The message size in bytes as defined in the DBC
+        expected: usize,
+        /// The number of payload bytes received
+        actual: usize,
+    },
+    /// The payload is longer than the parser accepts (see
+    /// [`Parser::set_max_data_len`])
+    PayloadTooLong {
+        /// The maximum payload length in bytes
+        max_len: usize,
+        /// The number of payload bytes received
+        actual: usize,
+    },
+    /// The payload has the expected length, but a signal failed to decode,
+    /// e.g. because of a non-finite value with [`NonFinitePolicy::Error`]
+    DecodeFailed(u32),
+}
 
-                    if signal_meta.sig_comment.is_some() {
-                        log::warn!(
-                            "Duplicate comment for signal '{}' in message ID {:#X}. \
-                            Overwriting existing signal comment.",
-                            name,
-                            msg_id
-                        );
-                    }
+impl std::fmt::Display for FrameError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FrameError::UnknownMessage(msg_id) => write!(f, "unknown message ID {msg_id:#X}"),
+            FrameError::ExtendedMismatch {
+                msg_id,
+                is_extended,
+            } => write!(
+                f,
+                "message ID {msg_id:#X} received as {} but defined as {}",
+                if *is_extended { "extended" } else { "standard" },
+                if *is_extended { "standard" } else { "extended" }
+            ),
+            FrameError::InvalidDlc(dlc) => write!(f, "invalid DLC {dlc}"),
+            FrameError::DlcMismatch { dlc, data_len } => write!(
+                f,
+                "DLC {dlc} does not match payload length of {data_len} bytes"
+            ),
+            FrameError::LengthMismatch { expected, actual } => write!(
+                f,
+                "payload length of {actual} bytes does not match message size of {expected} bytes"
+            ),
+            FrameError::PayloadTooLong { max_len, actual } => write!(
+                f,
+                "payload length of {actual} bytes exceeds the maximum of {max_len} bytes"
+            ),
+            FrameError::DecodeFailed(msg_id) => {
+                write!(f, "failed to decode message ID {msg_id:#X}")
+            }
+        }
+    }
+}
 
-                    signal_meta.sig_comment = Some(comment);
-                }
-                _ => {}
+impl std::error::Error for FrameError {}
+
+/// Per-signal problems reported by [`Parser::decode_msg_detailed`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SignalDecodeError {
+    /// A signal's bits extend beyond the end of the received payload
+    OutOfFrame {
+        /// The signal name
+        signal: String,
+        /// The number of payload bytes received
+        data_len: usize,
+    },
+    /// A multiplexed signal of the active group extends beyond the end of the
+    /// received payload, e.g. because the group is transmitted in a shorter frame
+    MuxSignalOutOfFrame {
+        /// The signal name
+        signal: String,
+        /// The active multiplexer value
+        mux_value: u64,
+        /// The number of payload bytes received
+        data_len: usize,
+    },
+    /// A signal's physical value is NaN or infinite and the parser's
+    /// [`NonFinitePolicy`] is [`Error`](NonFinitePolicy::Error)
+    NonFinite {
+        /// The signal name
+        signal: String,
+    },
+    /// The multiplexor switch selects no defined multiplexed group and the
+    /// parser's [`UnknownMuxPolicy`] is [`Error`](UnknownMuxPolicy::Error)
+    UnknownMuxValue {
+        /// The name of the multiplexor switch signal
+        signal: String,
+        /// The switch value
+        mux_value: u64,
+    },
+}
+
+impl std::fmt::Display for SignalDecodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SignalDecodeError::OutOfFrame { signal, data_len } => write!(
+                f,
+                "signal '{signal}' extends beyond the {data_len}-byte payload"
+            ),
+            SignalDecodeError::MuxSignalOutOfFrame {
+                signal,
+                mux_value,
+                data_len,
+            } => write!(
+                f,
+                "signal '{signal}' of multiplexed group {mux_value} extends beyond the {data_len}-byte payload"
+            ),
+            SignalDecodeError::NonFinite { signal } => {
+                write!(f, "signal '{signal}' has a non-finite physical value")
+            }
+            SignalDecodeError::UnknownMuxValue { signal, mux_value } => write!(
+                f,
+                "multiplexor '{signal}' value {mux_value} selects no defined group"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for SignalDecodeError {}
+
+/// Signals of a message grouped by health, as returned by [`Parser::decode_msg_triaged`].
+#[derive(Debug, Clone, Default)]
+pub struct TriagedDecode {
+    /// Signals decoded within their DBC range (or with an unspecified range)
+    pub good: SignalMap,
+    /// Signals decoded outside of their DBC range
+    pub suspicious: SignalMap,
+    /// Signals that couldn't be decoded
+    pub failed: Vec<SignalDecodeError>,
+}
+
+/// Errors reported by [`FrameBuilder`] while assembling a frame.
+#[derive(Debug, Clone, PartialEq)]
+pub enum EncodeError {
+    /// No message with this ID is defined
+    UnknownMessage(u32),
+    /// The message has no signal with this name
+    UnknownSignal(String),
+    /// The physical value is outside the signal's DBC range
+    OutOfRange {
+        /// The signal name
+        signal: String,
+        /// The rejected physical value
+        value: f64,
+        /// The minimum physical value from the DBC
+        min: f64,
+        /// The maximum physical value from the DBC
+        max: f64,
+    },
+    /// The signal doesn't fit in the message payload
+    EncodingFailed(String),
+}
+
+impl std::fmt::Display for EncodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EncodeError::UnknownMessage(msg_id) => write!(f, "unknown message ID {msg_id:#X}"),
+            EncodeError::UnknownSignal(name) => write!(f, "unknown signal '{name}'"),
+            EncodeError::OutOfRange {
+                signal,
+                value,
+                min,
+                max,
+            } => write!(
+                f,
+                "value {value} for signal '{signal}' is outside its range [{min}, {max}]"
+            ),
+            EncodeError::EncodingFailed(name) => {
+                write!(f, "signal '{name}' does not fit in the message payload")
             }
         }
+    }
+}
+
+impl std::error::Error for EncodeError {}
+
+/// Incrementally assembles the payload of a single CAN message.
+///
+/// Created by [`Parser::frame`]. The payload starts out with every signal set
+/// to its start value (the `GenSigStartValue` attribute, or raw 0 if absent),
+/// and each [`set`](FrameBuilder::set) call validates and encodes one signal.
+///
+/// # Example
+///
+/// ```
+/// use can_decode::Parser;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let dbc = r#"
+/// VERSION ""
+///
+/// BU_: ECU
+///
+/// BO_ 256 Engine: 8 ECU
+///  SG_ RPM : 0|16@1+ (1,0) [0|8000] "rpm" Vector__XXX
+///  SG_ Gear : 16|4@1+ (1,0) [0|8] "" Vector__XXX
+///  SG_ Mode : 20|4@1+ (1,0) [0|15] "" Vector__XXX
+///
+/// BA_DEF_ SG_ "GenSigStartValue" INT 0 65535;
+/// BA_ "GenSigStartValue" SG_ 256 Mode 5;
+/// "#;
+/// let mut parser = Parser::new();
+/// parser.add_from_str(dbc)?;
+///
+/// let data = parser.frame(256)?.set("RPM", 4200.0)?.set("Gear", 3.0)?.build();
+/// assert_eq!(data, [0x68, 0x10, 0x53, 0, 0, 0, 0, 0]);
+///
+/// assert!(parser.frame(256)?.set("Gear", 9.0).is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct FrameBuilder<'a> {
+    parser: &'a Parser,
+    msg_id: u32,
+    data: Vec<u8>,
+}
+
+impl FrameBuilder<'_> {
+    /// Sets a signal to a physical value.
+    ///
+    /// # Arguments
+    ///
+    /// * `signal_name` - The name of the signal within the message
+    /// * `value` - The physical value to encode
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the signal doesn't exist, the value is outside the
+    /// signal's DBC range, or the signal doesn't fit in the payload.
+    pub fn set(mut self, signal_name: &str, value: f64) -> Result<Self, EncodeError> {
+        let msg_entry = &self.parser.msg_entries[&self.msg_id];
+        let signal_def = msg_entry
+            .msg_def
+            .signals
+            .iter()
+            .find(|s| s.name == signal_name)
+            .ok_or_else(|| EncodeError::UnknownSignal(signal_name.to_string()))?;
+
+        if !in_dbc_range(signal_def, value) {
+            return Err(EncodeError::OutOfRange {
+                signal: signal_name.to_string(),
+                value,
+                min: numeric_to_f64(&signal_def.min),
+                max: numeric_to_f64(&signal_def.max),
+            });
+        }
+
+        self.parser
+            .encode_signal(self.msg_id, signal_def, value, &mut self.data)
+            .ok_or_else(|| EncodeError::EncodingFailed(signal_name.to_string()))?;
+        Ok(self)
+    }
+
+    /// Returns the assembled message payload.
+    pub fn build(self) -> Vec<u8> {
+        self.data
+    }
+}
+
+/// A DBC source added to a [`ParserBuilder`].
+#[derive(Debug, Clone)]
+enum DbcSource {
+    Str(String),
+    File(std::path::PathBuf),
+}
+
+/// Builds a [`Parser`] from one or more DBC sources.
+///
+/// Created with [`Parser::builder`]. Sources are loaded in the order they were
+/// added when [`build`](ParserBuilder::build) is called.
+///
+/// # Example
+///
+/// ```
+/// use can_decode::{CanDecodeError, Parser};
+///
+/// let dbc = r#"
+/// BO_ 256 Motor: 2 ECU
+///  SG_ Speed : 0|16@1+ (1,0) [0|0] "rpm" Vector__XXX
+///  SG_ Temp : 8|8@1+ (1,-40) [0|0] "degC" Vector__XXX
+/// "#;
+///
+/// // Speed and Temp share byte 1, which is only an error with strict validation
+/// assert!(Parser::builder().dbc_str(dbc).build().is_ok());
+///
+/// let err = Parser::builder()
+///     .dbc_str(dbc)
+///     .strict_validation(true)
+///     .build()
+///     .unwrap_err();
+/// assert!(matches!(err, CanDecodeError::InvalidDefinitions(issues) if issues.len() == 1));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ParserBuilder {
+    sources: Vec<DbcSource>,
+    strict_validation: bool,
+}
+
+impl ParserBuilder {
+    /// Creates a builder with no DBC sources and strict validation disabled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds DBC content to load.
+    ///
+    /// # Arguments
+    ///
+    /// * `buffer` - The DBC content
+    pub fn dbc_str(mut self, buffer: impl Into<String>) -> Self {
+        self.sources.push(DbcSource::Str(buffer.into()));
+        self
+    }
+
+    /// Adds a DBC file to load.
+    ///
+    /// The file is read when [`build`](ParserBuilder::build) is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to the DBC file
+    pub fn dbc_file(mut self, path: impl AsRef<std::path::Path>) -> Self {
+        self.sources
+            .push(DbcSource::File(path.as_ref().to_path_buf()));
+        self
+    }
+
+    /// Sets whether [`build`](ParserBuilder::build) rejects malformed definitions.
+    ///
+    /// With strict validation, every issue reported by
+    /// [`Parser::validate_definitions`] is fatal: messages larger than 64
+    /// bytes, signals that are 0 or more than 64 bits wide, signals extending
+    /// beyond the end of their message, and signals that overlap another
+    /// signal present in the same frame. Anything else that can only be
+    /// detected while decoding, such as values outside of a signal's DBC range
+    /// or undefined multiplexor values, is not checked.
+    ///
+    /// # Arguments
+    ///
+    /// * `strict` - Whether to fail on malformed definitions
+    pub fn strict_validation(mut self, strict: bool) -> Self {
+        self.strict_validation = strict;
+        self
+    }
+
+    /// Loads all sources into a new parser.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error from loading a source (see
+    /// [`Parser::add_from_dbc_file`]), or
+    /// [`CanDecodeError::InvalidDefinitions`] with every issue found if strict
+    /// validation is enabled and any definition is malformed.
+    pub fn build(self) -> Result<Parser, CanDecodeError> {
+        let mut parser = Parser::new();
+        for source in &self.sources {
+            match source {
+                DbcSource::Str(buffer) => parser.add_from_str(buffer)?,
+                DbcSource::File(path) => parser.add_from_dbc_file(path)?,
+            }
+        }
+
+        if self.strict_validation {
+            let issues = parser.validate_definitions();
+            if !issues.is_empty() {
+                return Err(CanDecodeError::InvalidDefinitions(issues));
+            }
+        }
+
+        Ok(parser)
+    }
+}
+
+/// Returns whether a physical value lies within a signal's DBC `[min|max]` range.
+///
+/// A `[0|0]` range means the DBC doesn't constrain the signal, so any value
+/// other than NaN is accepted.
+fn in_dbc_range(signal_def: &can_dbc::Signal, value: f64) -> bool {
+    match dbc_range(signal_def) {
+        Some((min, max)) => (min..=max).contains(&value),
+        None => !value.is_nan(),
+    }
+}
+
+/// Returns a signal's DBC `[min|max]` range, or `None` for an unconstrained
+/// `[0|0]` range.
+fn dbc_range(signal_def: &can_dbc::Signal) -> Option<(f64, f64)> {
+    let min = numeric_to_f64(&signal_def.min);
+    let max = numeric_to_f64(&signal_def.max);
+    (min != 0.0 || max != 0.0).then_some((min, max))
+}
+
+/// Converts a DBC numeric value (e.g. a signal's min/max) to `f64`.
+fn numeric_to_f64(value: &can_dbc::NumericValue) -> f64 {
+    match *value {
+        can_dbc::NumericValue::Uint(v) => v as f64,
+        can_dbc::NumericValue::Int(v) => v as f64,
+        can_dbc::NumericValue::Double(v) => v,
+    }
+}
+
+/// Signal metadata for a signal's value interpretation and DBC description/comment.
+///
+/// Encapsulates optional formatting information for a signal, including
+/// enum mappings (for value descriptions) and float format specifications
+/// (for IEEE-754 encoded signals).
+///
+/// Also includes the signal-level description/comment from the DBC, if available.
+#[derive(Debug, Clone, Default)]
+pub struct SignalMeta {
+    /// Maps raw signal values to string enum labels from DBC value descriptions.
+    pub enum_map: std::collections::HashMap<i128, String>,
+
+    /// The IEEE-754 float format, if this signal is an IEEE float/double.
+    pub float_format: Option<FloatFormat>,
+
+    /// Signal-level description/comment from the DBC.
+    pub sig_comment: Option<String>,
+
+    /// Whether the signal's bits are transmitted in reverse order within its field.
+    pub bit_reverse: bool,
+
+    /// Custom raw-to-physical conversion replacing the DBC factor and offset.
+    pub transform: Option<SignalTransform>,
+
+    /// Whether the signal is skipped when decoding.
+    pub disabled: bool,
+
+    /// Raw value that marks the signal as not available, overriding SNA detection.
+    pub sna_value: Option<u64>,
+
+    /// Attribute values (`BA_`) keyed by attribute name, including defaults.
+    pub attributes: std::collections::HashMap<String, AttributeValue>,
+}
+
+impl SignalMeta {
+    /// Returns the raw start value from the `GenSigStartValue` attribute or its
+    /// `BA_DEF_DEF_` default, if available.
+    pub fn start_value(&self) -> Option<f64> {
+        self.attributes.get("GenSigStartValue")?.as_f64()
+    }
+}
+
+/// A user-supplied conversion from a signal's raw value to its physical value.
+///
+/// Registered with [`Parser::set_transform`].
+#[derive(Clone)]
+pub struct SignalTransform(std::sync::Arc<dyn Fn(i128) -> f64 + Send + Sync>);
+
+impl SignalTransform {
+    /// Applies the conversion to a (sign-extended) raw value.
+    pub fn apply(&self, raw: i128) -> f64 {
+        (self.0)(raw)
+    }
+}
+
+impl std::fmt::Debug for SignalTransform {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("SignalTransform(..)")
+    }
+}
+
+/// Internal entry representing a loaded CAN message with its format definitions.
+///
+/// Stores both the base DBC message definition and the signal-specific format
+/// metadata (enums and float formats) in a unified structure.
+#[derive(Debug, Clone)]
+pub struct MsgEntry {
+    /// The base DBC message definition containing signals and metadata.
+    pub msg_def: can_dbc::Message,
+    /// Whether the message uses an extended (29-bit) CAN ID.
+    pub is_extended: bool,
+    /// Message-level description/comment from DBC (if available)
+    pub msg_desc: Option<String>,
+    /// Attribute values (`BA_`) keyed by attribute name, including defaults
+    pub attributes: std::collections::HashMap<String, AttributeValue>,
+    /// Extra metadata for signals indexed by signal name.
+    /// This includes enum mappings for value descriptions and float format info
+    /// for IEEE float signals as well as signal-level comments.
+    pub signal_meta: std::collections::HashMap<String, SignalMeta>,
+    /// Derived signals assembled from the raw values of other signals.
+    pub composites: Vec<CompositeSignal>,
+}
+
+impl MsgEntry {
+    /// Creates a new message entry from a can_dbc message definition.
+    ///
+    /// # Arguments
+    ///
+    /// * `msg_def` - The DBC message definition
+    ///
+    /// # Returns
+    ///
+    /// A new `MsgEntry` with the message definition, its extended ID flag, an
+    /// empty description, no attributes, an empty signal metadata map (to be
+    /// populated with enums, float formats, and comments), and no composite
+    /// signals.
+    fn new(msg_def: can_dbc::Message) -> Self {
+        Self {
+            is_extended: matches!(msg_def.id, can_dbc::MessageId::Extended(_)),
+            msg_def,
+            msg_desc: None,
+            attributes: std::collections::HashMap::new(),
+            signal_meta: std::collections::HashMap::new(),
+            composites: Vec::new(),
+        }
+    }
+
+    /// Returns the cycle time in milliseconds from the `GenMsgCycleTime`
+    /// attribute or its `BA_DEF_DEF_` default, if available.
+    pub fn cycle_time_ms(&self) -> Option<u64> {
+        let cycle_time = self.attributes.get("GenMsgCycleTime")?.as_f64()?;
+        Some(cycle_time as u64)
+    }
+}
+
+/// A derived signal whose raw value is assembled from several DBC signals.
+///
+/// Registered with [`Parser::add_composite_signal`] for values that a DBC splits
+/// across multiple signals (e.g. separate high and low parts).
+#[derive(Debug, Clone)]
+pub struct CompositeSignal {
+    /// The name of the derived signal in decoded messages
+    pub name: String,
+    /// The source signal names and the left shift applied to each one's raw bits
+    pub parts: Vec<(String, u32)>,
+}
+
+/// A precompiled set of signals to decode for one message.
+///
+/// Created by [`Parser::compile_allowlist`] and used with
+/// [`Parser::decode_with_allowlist`] to avoid resolving signal names on every frame.
+#[derive(Debug, Clone)]
+pub struct Allowlist {
+    /// The CAN message ID this allowlist applies to
+    msg_id: u32,
+    /// Whether each signal (indexed by position in the message definition) is allowed
+    allowed: Vec<bool>,
+}
+
+impl Allowlist {
+    /// Returns the CAN message ID this allowlist applies to.
+    pub fn msg_id(&self) -> u32 {
+        self.msg_id
+    }
+}
+
+/// Metadata of a signal, as returned by [`Parser::signal_catalog`] and
+/// [`Parser::signal_metadata`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SignalCatalogEntry {
+    /// The name of the message containing the signal
+    pub msg_name: String,
+    /// The CAN message identifier (bit 31 set for extended IDs)
+    pub msg_id: u32,
+    /// The name of the signal
+    pub signal_name: String,
+    /// The DBC start bit
+    pub start_bit: u64,
+    /// The signal length in bits
+    pub size: u64,
+    /// The signal byte order
+    pub byte_order: can_dbc::ByteOrder,
+    /// Whether the raw value is signed
+    pub is_signed: bool,
+    /// The scaling factor
+    pub factor: f64,
+    /// The scaling offset
+    pub offset: f64,
+    /// The unit of measurement
+    pub unit: String,
+    /// The minimum physical value from the DBC
+    pub min: f64,
+    /// The maximum physical value from the DBC
+    pub max: f64,
+}
+
+impl SignalCatalogEntry {
+    /// Extracts the metadata of a signal within its message definition.
+    fn new(msg_def: &can_dbc::Message, signal_def: &can_dbc::Signal) -> Self {
+        Self {
+            msg_name: msg_def.name.clone(),
+            msg_id: msg_def.id.raw(),
+            signal_name: signal_def.name.clone(),
+            start_bit: signal_def.start_bit,
+            size: signal_def.size,
+            byte_order: signal_def.byte_order,
+            is_signed: signal_def.value_type == can_dbc::ValueType::Signed,
+            factor: signal_def.factor,
+            offset: signal_def.offset,
+            unit: signal_def.unit.clone(),
+            min: numeric_to_f64(&signal_def.min),
+            max: numeric_to_f64(&signal_def.max),
+        }
+    }
+}
+
+/// The position of a signal's bits within its message.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct SignalLayout {
+    /// The DBC start bit
+    pub start_bit: u64,
+    /// The signal length in bits
+    pub size: u64,
+    /// The signal byte order
+    pub byte_order: can_dbc::ByteOrder,
+}
+
+/// A signal whose bit layout differs between two databases, as returned by
+/// [`Parser::layout_changes`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LayoutChange {
+    /// The CAN message identifier (bit 31 set for extended IDs)
+    pub msg_id: u32,
+    /// The name of the message in the newer database
+    pub msg_name: String,
+    /// The name of the signal
+    pub signal_name: String,
+    /// The layout in the older database
+    pub old: SignalLayout,
+    /// The layout in the newer database
+    pub new: SignalLayout,
+}
+
+/// How well the loaded definitions cover a set of observed message IDs.
+///
+/// Returned by [`Parser::decode_coverage`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Coverage {
+    /// Number of observed IDs with a loaded definition
+    pub known: usize,
+    /// Number of observed IDs without a loaded definition
+    pub unknown: usize,
+    /// The distinct IDs without a loaded definition, sorted
+    pub unknown_ids: Vec<u32>,
+}
+
+/// A snapshot of the decode counters of a [`Parser`].
+///
+/// Returned by [`Parser::decode_stats`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DecodeStats {
+    /// Number of messages successfully decoded
+    pub messages_decoded: u64,
+    /// Number of frames with a message ID that has no loaded definition
+    pub unknown_ids: u64,
+    /// Number of frames of known messages that failed to decode
+    pub decode_failures: u64,
+}
+
+/// Decode counters, updated through a shared reference while decoding.
+#[derive(Debug, Default)]
+struct DecodeCounters {
+    messages_decoded: std::sync::atomic::AtomicU64,
+    unknown_ids: std::sync::atomic::AtomicU64,
+    decode_failures: std::sync::atomic::AtomicU64,
+}
+
+impl DecodeCounters {
+    fn snapshot(&self) -> DecodeStats {
+        use std::sync::atomic::Ordering::Relaxed;
+        DecodeStats {
+            messages_decoded: self.messages_decoded.load(Relaxed),
+            unknown_ids: self.unknown_ids.load(Relaxed),
+            decode_failures: self.decode_failures.load(Relaxed),
+        }
+    }
+}
+
+impl Clone for DecodeCounters {
+    fn clone(&self) -> Self {
+        let stats = self.snapshot();
+        Self {
+            messages_decoded: stats.messages_decoded.into(),
+            unknown_ids: stats.unknown_ids.into(),
+            decode_failures: stats.decode_failures.into(),
+        }
+    }
+}
+
+/// The last decode of each message, reused while its payload repeats.
+///
+/// Holds at most one entry per loaded message definition.
+#[derive(Debug, Default)]
+struct DecodeCache {
+    enabled: bool,
+    entries: std::sync::Mutex<std::collections::HashMap<u32, (Vec<u8>, DecodedMessage)>>,
+}
+
+impl DecodeCache {
+    /// Returns the cached decode of a message if `data` matches the cached payload.
+    fn get(&self, key: u32, data: &[u8]) -> Option<DecodedMessage> {
+        let entries = self
+            .entries
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        entries
+            .get(&key)
+            .filter(|(cached_data, _decoded)| cached_data.as_slice() == data)
+            .map(|(_data, decoded)| decoded.clone())
+    }
+
+    /// Replaces the cached decode of a message.
+    fn insert(&self, key: u32, data: &[u8], decoded: &DecodedMessage) {
+        let mut entries = self
+            .entries
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        match entries.get_mut(&key) {
+            Some((cached_data, cached)) => {
+                cached_data.clear();
+                cached_data.extend_from_slice(data);
+                cached.clone_from(decoded);
+            }
+            None => {
+                entries.insert(key, (data.to_vec(), decoded.clone()));
+            }
+        }
+    }
+
+    /// Drops all cached decodes, e.g. after definitions change.
+    fn invalidate(&mut self) {
+        self.entries
+            .get_mut()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .clear();
+    }
+}
+
+impl Clone for DecodeCache {
+    fn clone(&self) -> Self {
+        let entries = self
+            .entries
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        Self {
+            enabled: self.enabled,
+            entries: entries.clone().into(),
+        }
+    }
+}
+
+/// A resolved message definition for repeated decoding of one message ID.
+///
+/// Created by [`Parser::resolve`] and used with [`Parser::decode_with_handle`]
+/// to skip looking up the message definition on every decode.
+#[derive(Debug, Clone, Copy)]
+pub struct MsgHandle<'a> {
+    entry: &'a MsgEntry,
+}
+
+impl MsgHandle<'_> {
+    /// Returns the CAN message ID this handle resolves to.
+    pub fn msg_id(&self) -> u32 {
+        self.entry.msg_def.id.raw()
+    }
+}
+
+/// A CAN message parser that uses DBC file definitions.
+///
+/// The parser loads message and signal definitions from DBC files and uses them
+/// to decode raw CAN frame data into structured messages with physical signal values.
+///
+/// # Example
+///
+/// ```no_run
+/// use can_decode::Parser;
+/// use std::path::Path;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut parser = Parser::new();
+/// parser.add_from_dbc_file(Path::new("engine.dbc"))?;
+/// parser.add_from_dbc_file(Path::new("transmission.dbc"))?;
+///
+/// let data = [0x00, 0x10, 0x20, 0x30, 0x40, 0x50, 0x60, 0x70];
+/// if let Some(decoded) = parser.decode_msg(0x100, &data) {
+///     println!("Decoded message: {}", decoded.name);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Parser {
+    msg_entries: std::collections::HashMap<u32, MsgEntry>,
+    /// Message IDs keyed by message name, the last loaded message winning on duplicates
+    msg_ids_by_name: std::collections::HashMap<String, u32>,
+    /// Extended message IDs keyed by J1939 PGN, the lowest ID winning on duplicates
+    #[cfg(feature = "j1939")]
+    msg_ids_by_pgn: std::collections::HashMap<u32, u32>,
+    /// Definitions loaded for a specific bus, keyed by bus name
+    buses: std::collections::HashMap<String, Parser>,
+    non_finite_policy: NonFinitePolicy,
+    unknown_mux_policy: UnknownMuxPolicy,
+    /// Whether decoding logs a warning for signals outside of their DBC range
+    warn_out_of_range: bool,
+    /// Whether decoding flags maximum raw values and "SNA"-labeled raw values as not available
+    sna_detection: bool,
+    id_flag_mode: IdFlagMode,
+    /// Whether [`decode_msg`](Parser::decode_msg) rejects payloads shorter than the message size
+    strict_dlc: bool,
+    /// Longest payload [`decode_msg`](Parser::decode_msg) accepts, in bytes
+    max_data_len: usize,
+    counters: DecodeCounters,
+    decode_cache: DecodeCache,
+}
+
+impl Parser {
+    /// Creates a new empty parser.
+    ///
+    /// Use [`add_from_dbc_file`](Parser::add_from_dbc_file) or
+    /// [`add_from_str`](Parser::add_from_str) to add message definitions.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use can_decode::Parser;
+    ///
+    /// let parser = Parser::new();
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            msg_entries: std::collections::HashMap::new(),
+            msg_ids_by_name: std::collections::HashMap::new(),
+            #[cfg(feature = "j1939")]
+            msg_ids_by_pgn: std::collections::HashMap::new(),
+            buses: std::collections::HashMap::new(),
+            non_finite_policy: NonFinitePolicy::default(),
+            unknown_mux_policy: UnknownMuxPolicy::default(),
+            warn_out_of_range: false,
+            sna_detection: false,
+            id_flag_mode: IdFlagMode::default(),
+            strict_dlc: false,
+            max_data_len: 64,
+            counters: DecodeCounters::default(),
+            decode_cache: DecodeCache::default(),
+        }
+    }
+
+    /// Creates a [`ParserBuilder`] for loading several sources or validating
+    /// the definitions up front.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use can_decode::Parser;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let parser = Parser::builder()
+    ///     .dbc_file("vehicle.dbc")
+    ///     .dbc_file("diagnostics.dbc")
+    ///     .strict_validation(true)
+    ///     .build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn builder() -> ParserBuilder {
+        ParserBuilder::new()
+    }
+
+    /// Creates a parser and loads definitions from a DBC file.
+    ///
+    /// This is a convenience method that combines [`new`](Parser::new) and
+    /// [`add_from_str`](Parser::add_from_str).
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to the DBC file
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CanDecodeError::Io`] if the file cannot be read,
+    /// [`CanDecodeError::Utf8`] if it isn't valid UTF-8, or
+    /// [`CanDecodeError::Parse`] if it cannot be parsed.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use can_decode::Parser;
+    /// use std::path::Path;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let parser = Parser::from_dbc_file(Path::new("my_database.dbc"))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_dbc_file(path: &std::path::Path) -> Result<Self, CanDecodeError> {
+        let mut parser = Self::new();
+        parser.add_from_dbc_file(path)?;
+        Ok(parser)
+    }
+
+    /// Adds message and enum/value-description definitions from a DBC string.
+    ///
+    /// This method parses DBC content from a string slice and adds all message
+    /// definitions to the parser. If a message ID already exists, it will be
+    /// overwritten and a warning will be logged. Signal value descriptions
+    /// (enumerations) are also captured for enum decoding. Signal extended
+    /// value types (`SIG_VALTYPE_`) are captured for IEEE float/double decoding.
+    ///
+    /// Signals may also reference a shared `VAL_TABLE_` by name
+    /// (`VAL_ <msg_id> <signal> <TableName> ;`). The table must be defined in
+    /// the same DBC content.
+    ///
+    /// # Arguments
+    ///
+    /// * `buffer` - String slice containing the full DBC file contents
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CanDecodeError::Parse`] if the DBC content cannot be parsed.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use can_decode::Parser;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let dbc_content = "VERSION \"\"..."; // DBC file content as &str
+    /// let mut parser = Parser::new();
+    /// parser.add_from_str(dbc_content)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Malformed content can be told apart from other failures:
+    ///
+    /// ```
+    /// use can_decode::{CanDecodeError, Parser};
+    ///
+    /// let mut parser = Parser::new();
+    /// let result = parser.add_from_str("BO_ 256 Broken: ECU");
+    /// assert!(matches!(result, Err(CanDecodeError::Parse(_))));
+    /// assert_eq!(
+    ///     result.unwrap_err().to_string(),
+    ///     "failed to parse DBC: syntax error at line 1, column 17: \
+    ///      expected message_size near `BO_ 256 Broken: ECU`"
+    /// );
+    /// ```
+    ///
+    /// Two signals sharing one value table:
+    ///
+    /// ```
+    /// use can_decode::Parser;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let dbc = r#"
+    /// VERSION ""
+    ///
+    /// BU_: ECU
+    ///
+    /// VAL_TABLE_ GearTable 3 "D" 2 "N" 1 "R" 0 "P" ;
+    ///
+    /// BO_ 256 Transmission: 2 ECU
+    ///  SG_ CurrentGear : 0|8@1+ (1,0) [0|3] "" Vector__XXX
+    ///  SG_ RequestedGear : 8|8@1+ (1,0) [0|3] "" Vector__XXX
+    ///
+    /// VAL_ 256 CurrentGear GearTable ;
+    /// VAL_ 256 RequestedGear GearTable ;
+    /// "#;
+    /// let mut parser = Parser::new();
+    /// parser.add_from_str(dbc)?;
+    ///
+    /// let decoded = parser.decode_msg(256, &[0x01, 0x03]).unwrap();
+    /// assert_eq!(decoded.signals["CurrentGear"].value.enum_label.as_deref(), Some("R"));
+    /// assert_eq!(decoded.signals["RequestedGear"].value.enum_label.as_deref(), Some("D"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn add_from_str(&mut self, buffer: &str) -> Result<(), CanDecodeError> {
+        let (buffer, value_table_refs) = extract_value_table_refs(buffer);
+        let mut dbc = can_dbc::Dbc::try_from(buffer.as_ref()).map_err(|e| {
+            let msg = describe_dbc_error(&e);
+            log::error!("Failed to parse DBC: {}", msg);
+            CanDecodeError::Parse(msg)
+        })?;
+        self.decode_cache.invalidate();
+
+        // Resolve value table references into regular value descriptions
+        for table_ref in value_table_refs {
+            let Some(table) = dbc
+                .value_tables
+                .iter()
+                .find(|table| table.name == table_ref.table_name)
+            else {
+                log::warn!(
+                    "Signal '{}' in message ID {:#X} references unknown value table '{}'. Skipping.",
+                    table_ref.signal_name,
+                    table_ref.msg_id,
+                    table_ref.table_name
+                );
+                continue;
+            };
+
+            let message_id = if table_ref.msg_id & EXTENDED_ID_FLAG != 0 {
+                can_dbc::MessageId::Extended(table_ref.msg_id & !EXTENDED_ID_FLAG)
+            } else {
+                can_dbc::MessageId::Standard(table_ref.msg_id as u16)
+            };
+            dbc.value_descriptions
+                .push(can_dbc::ValueDescription::Signal {
+                    message_id,
+                    name: table_ref.signal_name,
+                    value_descriptions: table.descriptions.clone(),
+                });
+        }
+
+        // Insert message definitions
+        let mut loaded_ids = Vec::with_capacity(dbc.messages.len());
+        for msg_def in dbc.messages {
+            loaded_ids.push(msg_def.id.raw());
+            self.insert_msg_entry(MsgEntry::new(msg_def));
+        }
+
+        // Enum handling
+        for val_desc in dbc.value_descriptions {
+            let can_dbc::ValueDescription::Signal {
+                message_id,
+                name,
+                value_descriptions,
+            } = val_desc
+            else {
+                continue;
+            };
+
+            let msg_id = message_id.raw();
+
+            let Some(msg_entry) = self.msg_entries.get_mut(&msg_id) else {
+                log::warn!(
+                    "Value description for signal '{}' references unknown message ID {:#X}. \
+                    Skipping.",
+                    name,
+                    msg_id
+                );
+                continue;
+            };
+
+            let enum_def = SignalMeta {
+                enum_map: value_descriptions
+                    .iter()
+                    .map(|vd| (vd.id as i128, vd.description.clone()))
+                    .collect(),
+                ..Default::default()
+            };
+
+            if let Some(existing) = msg_entry.signal_meta.get_mut(&name) {
+                existing.enum_map = enum_def.enum_map;
+
+                log::warn!(
+                    "Duplicate value description for signal '{}' in message ID {:#X}. \
+                    Overwriting existing enum definition.",
+                    name,
+                    msg_id
+                );
+            } else {
+                msg_entry.signal_meta.insert(name.clone(), enum_def);
+            }
+        }
+
+        // Float handling
+        for sig_ext_val_typ in dbc.signal_extended_value_type_list {
+            let Some(float_format) =
+                FloatFormat::from_dbc_def(sig_ext_val_typ.signal_extended_value_type)
+            else {
+                continue;
+            };
+
+            let msg_id = sig_ext_val_typ.message_id.raw();
+            let signal_name = &sig_ext_val_typ.signal_name;
+
+            let Some(msg_entry) = self.msg_entries.get_mut(&msg_id) else {
+                log::warn!(
+                    "Float definition for signal '{}' references unknown message ID {:#X}. \
+                    Skipping.",
+                    signal_name,
+                    msg_id
+                );
+                continue;
+            };
+
+            let Some(signal_def) = msg_entry
+                .msg_def
+                .signals
+                .iter()
+                .find(|s| s.name == *signal_name)
+            else {
+                log::warn!(
+                    "Float definition for signal '{}' references unknown signal in message ID {:#X}. \
+                    Skipping.",
+                    signal_name,
+                    msg_id
+                );
+                continue;
+            };
+
+            if signal_def.size != float_format.bit_size() as u64 {
+                log::warn!(
+                    "Signal '{}' in message ID {:#X} marked as {} but size is {} bits. \
+                    Skipping float definition.",
+                    signal_name,
+                    msg_id,
+                    match float_format {
+                        FloatFormat::F32 => "f32",
+                        FloatFormat::F64 => "f64",
+                    },
+                    signal_def.size
+                );
+                continue;
+            }
+
+            let format_def = SignalMeta {
+                float_format: Some(float_format),
+                ..Default::default()
+            };
+
+            if let Some(existing) = msg_entry.signal_meta.get_mut(signal_name) {
+                existing.float_format = format_def.float_format;
+
+                log::warn!(
+                    "Duplicate float definition for signal '{}' in message ID {:#X}. \
+                    Overwriting existing float definition.",
+                    signal_name,
+                    msg_id
+                );
+            } else {
+                msg_entry
+                    .signal_meta
+                    .insert(signal_name.clone(), format_def);
+            }
+        }
+
+        // Descriptions/comments handling
+        for comment in dbc.comments {
+            match comment {
+                can_dbc::Comment::Message { id, comment } => {
+                    let msg_id = id.raw();
+
+                    let Some(msg_entry) = self.msg_entries.get_mut(&msg_id) else {
+                        log::warn!("Comment for unknown message ID {:#X}. Skipping.", msg_id);
+                        continue;
+                    };
+
+                    if msg_entry.msg_desc.is_some() {
+                        log::warn!(
+                            "Duplicate comment for message ID {:#X}. \
+                            Overwriting existing message comment.",
+                            msg_id
+                        );
+                    }
+
+                    msg_entry.msg_desc = Some(unescape_dbc_string(&comment));
+                }
+                can_dbc::Comment::Signal {
+                    message_id,
+                    name,
+                    comment,
+                } => {
+                    let msg_id = message_id.raw();
+
+                    let Some(msg_entry) = self.msg_entries.get_mut(&msg_id) else {
+                        log::warn!(
+                            "Comment for signal '{}' references unknown message ID {:#X}. Skipping.",
+                            name,
+                            msg_id
+                        );
+                        continue;
+                    };
+
+                    let signal_meta = msg_entry.signal_meta.entry(name.clone()).or_default();
+
+                    if signal_meta.sig_comment.is_some() {
+                        log::warn!(
+                            "Duplicate comment for signal '{}' in message ID {:#X}. \
+                            Overwriting existing signal comment.",
+                            name,
+                            msg_id
+                        );
+                    }
+
+                    signal_meta.sig_comment = Some(unescape_dbc_string(&comment));
+                }
+                _ => {}
+            }
+        }
+
+        self.load_attributes(
+            &dbc.attribute_definitions,
+            &dbc.attribute_defaults,
+            &dbc.attribute_values_message,
+            &dbc.attribute_values_signal,
+            &loaded_ids,
+        );
+
+        Ok(())
+    }
+
+    /// Stores the message and signal attribute values (`BA_`) of a parsed DBC.
+    ///
+    /// Attribute defaults (`BA_DEF_DEF_`) are applied to every message or
+    /// signal loaded from the same DBC, and explicit values override them.
+    fn load_attributes(
+        &mut self,
+        definitions: &[can_dbc::AttributeDefinition],
+        defaults: &[can_dbc::AttributeDefault],
+        message_values: &[can_dbc::AttributeValueForMessage],
+        signal_values: &[can_dbc::AttributeValueForSignal],
+        loaded_ids: &[u32],
+    ) {
+        let mut message_types = std::collections::HashMap::new();
+        let mut signal_types = std::collections::HashMap::new();
+        for definition in definitions {
+            match definition {
+                can_dbc::AttributeDefinition::Message(name, value_type) => {
+                    message_types.insert(name.as_str(), value_type);
+                }
+                can_dbc::AttributeDefinition::Signal(name, value_type) => {
+                    signal_types.insert(name.as_str(), value_type);
+                }
+                _ => {}
+            }
+        }
+
+        for default in defaults {
+            let name = default.name.as_str();
+            let (is_message, value_type) = match (message_types.get(name), signal_types.get(name)) {
+                (Some(value_type), _) => (true, *value_type),
+                (None, Some(value_type)) => (false, *value_type),
+                (None, None) => continue,
+            };
+            let Some(value) = AttributeValue::from_dbc(&default.value, Some(value_type)) else {
+                log::warn!("Default of attribute '{name}' doesn't match its definition. Skipping.");
+                continue;
+            };
+
+            for msg_id in loaded_ids {
+                let Some(msg_entry) = self.msg_entries.get_mut(msg_id) else {
+                    continue;
+                };
+                if is_message {
+                    msg_entry.attributes.insert(name.to_string(), value.clone());
+                    continue;
+                }
+                for signal_def in &msg_entry.msg_def.signals {
+                    msg_entry
+                        .signal_meta
+                        .entry(signal_def.name.clone())
+                        .or_default()
+                        .attributes
+                        .insert(name.to_string(), value.clone());
+                }
+            }
+        }
+
+        for attr in message_values {
+            let msg_id = attr.message_id.raw();
+            let Some(msg_entry) = self.msg_entries.get_mut(&msg_id) else {
+                log::warn!(
+                    "Attribute '{}' references unknown message ID {:#X}. Skipping.",
+                    attr.name,
+                    msg_id
+                );
+                continue;
+            };
+            let value_type = message_types.get(attr.name.as_str()).copied();
+            let Some(value) = AttributeValue::from_dbc(&attr.value, value_type) else {
+                log::warn!(
+                    "Value of attribute '{}' for message ID {:#X} doesn't match its definition. \
+                    Skipping.",
+                    attr.name,
+                    msg_id
+                );
+                continue;
+            };
+            msg_entry.attributes.insert(attr.name.clone(), value);
+        }
+
+        for attr in signal_values {
+            let msg_id = attr.message_id.raw();
+            let Some(msg_entry) = self.msg_entries.get_mut(&msg_id) else {
+                log::warn!(
+                    "Attribute '{}' for signal '{}' references unknown message ID {:#X}. Skipping.",
+                    attr.name,
+                    attr.signal_name,
+                    msg_id
+                );
+                continue;
+            };
+            let value_type = signal_types.get(attr.name.as_str()).copied();
+            let Some(value) = AttributeValue::from_dbc(&attr.value, value_type) else {
+                log::warn!(
+                    "Value of attribute '{}' for signal '{}' in message ID {:#X} doesn't match \
+                    its definition. Skipping.",
+                    attr.name,
+                    attr.signal_name,
+                    msg_id
+                );
+                continue;
+            };
+            msg_entry
+                .signal_meta
+                .entry(attr.signal_name.clone())
+                .or_default()
+                .attributes
+                .insert(attr.name.clone(), value);
+        }
+    }
+
+    /// Adds definitions from a DBC string with a prefix on every name.
+    ///
+    /// The prefix is prepended to every message and signal name as stored, so
+    /// that merged databases with overlapping names stay distinguishable. All
+    /// lookups by name, and the names in decoded messages, use the prefixed
+    /// names. Otherwise this behaves like [`add_from_str`](Parser::add_from_str).
+    ///
+    /// # Arguments
+    ///
+    /// * `buffer` - String slice containing the full DBC file contents
+    /// * `prefix` - The prefix for message and signal names, e.g. `"ENG_"`
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CanDecodeError::Parse`] if the DBC content cannot be parsed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use can_decode::Parser;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let engine = r#"
+    /// BO_ 256 Status: 1 ECU
+    ///  SG_ Temp : 0|8@1+ (1,-40) [0|0] "degC" Vector__XXX
+    /// "#;
+    /// let battery = r#"
+    /// BO_ 512 Status: 1 BMS
+    ///  SG_ Temp : 0|8@1+ (1,-40) [0|0] "degC" Vector__XXX
+    /// "#;
+    /// let mut parser = Parser::new();
+    /// parser.add_from_str_prefixed(engine, "ENG_")?;
+    /// parser.add_from_str_prefixed(battery, "BAT_")?;
+    ///
+    /// let decoded = parser.decode_msg(256, &[130]).unwrap();
+    /// assert_eq!(decoded.name, "ENG_Status");
+    /// assert_eq!(decoded.signals["ENG_Temp"].value.physical, 90.0);
+    /// assert_eq!(parser.msg_id_for_name("BAT_Status"), Some(512));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn add_from_str_prefixed(
+        &mut self,
+        buffer: &str,
+        prefix: &str,
+    ) -> Result<(), CanDecodeError> {
+        let mut source = Self::new();
+        source.add_from_str(buffer)?;
+
+        for (_msg_id, mut msg_entry) in source.msg_entries {
+            msg_entry.msg_def.name.insert_str(0, prefix);
+            for signal_def in &mut msg_entry.msg_def.signals {
+                signal_def.name.insert_str(0, prefix);
+            }
+            msg_entry.signal_meta = msg_entry
+                .signal_meta
+                .into_iter()
+                .map(|(name, meta)| (format!("{prefix}{name}"), meta))
+                .collect();
+            self.insert_msg_entry(msg_entry);
+        }
+
+        self.decode_cache.invalidate();
+        Ok(())
+    }
+
+    /// Inserts a message definition, replacing any definition with the same ID.
+    ///
+    /// Keeps the name and PGN indexes up to date, warning about duplicate IDs and
+    /// names.
+    fn insert_msg_entry(&mut self, msg_entry: MsgEntry) {
+        let msg_id = msg_entry.msg_def.id.raw();
+        let name = &msg_entry.msg_def.name;
+
+        if let Some(existing) = self.msg_entries.get(&msg_id) {
+            log::warn!(
+                "Duplicate message ID {msg_id:#X} ({name}). Overwriting existing definition."
+            );
+            // Stop resolving the replaced message's name to this ID
+            let old_name = &existing.msg_def.name;
+            if self.msg_ids_by_name.get(old_name) == Some(&msg_id) {
+                self.msg_ids_by_name.remove(old_name);
+            }
+        }
+        if let Some(&other_id) = self.msg_ids_by_name.get(name)
+            && other_id != msg_id
+        {
+            log::warn!(
+                "Duplicate message name '{name}' (IDs {other_id:#X} and {msg_id:#X}). \
+                Name lookups now resolve to {msg_id:#X}."
+            );
+        }
+
+        self.msg_ids_by_name.insert(name.clone(), msg_id);
+        self.msg_entries.insert(msg_id, msg_entry);
+        #[cfg(feature = "j1939")]
+        self.index_pgn(msg_id);
+    }
+
+    /// Adds message definitions from a DBC string for a specific bus.
+    ///
+    /// Vehicles with several CAN buses often reuse the same IDs for unrelated
+    /// messages. Definitions added here are only used by
+    /// [`decode_on_bus`](Parser::decode_on_bus) with the same bus name, and
+    /// don't conflict with definitions on other buses or with those added by
+    /// [`add_from_str`](Parser::add_from_str). Use [`bus`](Parser::bus) and
+    /// [`bus_mut`](Parser::bus_mut) to query or adjust them.
+    ///
+    /// # Arguments
+    ///
+    /// * `bus` - The bus name, e.g. `"powertrain"`
+    /// * `buffer` - String slice containing the full DBC file contents
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the DBC content cannot be parsed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use can_decode::Parser;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let powertrain = r#"
+    /// VERSION ""
+    ///
+    /// BU_: ECU
+    ///
+    /// BO_ 256 Motor: 1 ECU
+    ///  SG_ Torque : 0|8@1+ (2,0) [0|510] "Nm" Vector__XXX
+    /// "#;
+    /// let body = r#"
+    /// VERSION ""
+    ///
+    /// BU_: BCM
+    ///
+    /// BO_ 256 Doors: 1 BCM
+    ///  SG_ DoorsOpen : 0|8@1+ (1,0) [0|4] "" Vector__XXX
+    /// "#;
+    /// let mut parser = Parser::new();
+    /// parser.add_from_str_on_bus("powertrain", powertrain)?;
+    /// parser.add_from_str_on_bus("body", body)?;
+    ///
+    /// assert_eq!(parser.decode_on_bus("powertrain", 256, &[10]).unwrap().name, "Motor");
+    /// assert_eq!(parser.decode_on_bus("body", 256, &[1]).unwrap().name, "Doors");
+    /// assert!(parser.decode_msg(256, &[1]).is_none());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn add_from_str_on_bus(&mut self, bus: &str, buffer: &str) -> Result<(), CanDecodeError> {
+        if let Some(bus_parser) = self.buses.get_mut(bus) {
+            return bus_parser.add_from_str(buffer);
+        }
+
+        let mut bus_parser = Self::new();
+        bus_parser.add_from_str(buffer)?;
+        self.buses.insert(bus.to_string(), bus_parser);
+        Ok(())
+    }
+
+    /// Returns the definitions loaded for a bus.
+    ///
+    /// The returned parser holds only that bus's messages, so the per-message
+    /// queries, such as [`msg_comment`](Parser::msg_comment),
+    /// [`message_attribute`](Parser::message_attribute), or
+    /// [`to_dbc_string`](Parser::to_dbc_string), answer for the bus.
+    ///
+    /// # Arguments
+    ///
+    /// * `bus` - The bus name passed to [`add_from_str_on_bus`](Parser::add_from_str_on_bus)
+    ///
+    /// # Returns
+    ///
+    /// The bus's definitions, or `None` if nothing was loaded for the bus.
+    pub fn bus(&self, bus: &str) -> Option<&Parser> {
+        self.buses.get(bus)
+    }
+
+    /// Returns the definitions loaded for a bus, for modification.
+    ///
+    /// Per-message changes made through the returned parser, such as
+    /// [`disable_signal`](Parser::disable_signal) or
+    /// [`set_transform`](Parser::set_transform), apply to
+    /// [`decode_on_bus`](Parser::decode_on_bus) for that bus. Decoding options,
+    /// such as policies and strict DLC checking, are always taken from `self`.
+    ///
+    /// # Arguments
+    ///
+    /// * `bus` - The bus name passed to [`add_from_str_on_bus`](Parser::add_from_str_on_bus)
+    ///
+    /// # Returns
+    ///
+    /// The bus's definitions, or `None` if nothing was loaded for the bus.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use can_decode::Parser;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut parser = Parser::new();
+    /// parser.add_from_str_on_bus(
+    ///     "powertrain",
+    ///     r#"
+    /// BO_ 256 Motor: 2 ECU
+    ///  SG_ Torque : 0|8@1+ (2,0) [0|510] "Nm" Vector__XXX
+    ///  SG_ Speed : 8|8@1+ (10,0) [0|2550] "rpm" Vector__XXX
+    /// "#,
+    /// )?;
+    /// parser.add_from_str_on_bus(
+    ///     "body",
+    ///     r#"
+    /// BO_ 256 Doors: 1 BCM
+    ///  SG_ DoorsOpen : 0|8@1+ (1,0) [0|4] "" Vector__XXX
+    /// "#,
+    /// )?;
+    ///
+    /// parser.bus_mut("powertrain").unwrap().disable_signal(256, "Speed")?;
+    ///
+    /// let decoded = parser.decode_on_bus("powertrain", 256, &[10, 100]).unwrap();
+    /// assert!(!decoded.signals.contains_key("Speed"));
+    /// assert_eq!(parser.bus("body").unwrap().msg_defs().len(), 1);
+    /// assert!(parser.bus("chassis").is_none());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn bus_mut(&mut self, bus: &str) -> Option<&mut Parser> {
+        self.buses.get_mut(bus)
+    }
+
+    /// Decodes a raw CAN message using the definitions loaded for a bus.
+    ///
+    /// # Arguments
+    ///
+    /// * `bus` - The bus name passed to [`add_from_str_on_bus`](Parser::add_from_str_on_bus)
+    /// * `msg_id` - The CAN message identifier
+    /// * `data` - The raw message data bytes
+    ///
+    /// # Returns
+    ///
+    /// Returns the decoded message, or `None` if the bus or message ID is not
+    /// known or any signal failed to decode.
+    pub fn decode_on_bus(&self, bus: &str, msg_id: u32, data: &[u8]) -> Option<DecodedMessage> {
+        let msg_entry = self.buses.get(bus)?.msg_entries.get(&msg_id)?;
+        self.check_payload(msg_entry, data).ok()?;
+        self.decode_msg_inner(msg_entry, data, None, None, |_idx, _def| true)
+    }
+
+    /// Adds message definitions from a DBC file.
+    ///
+    /// Reads and parses a DBC file from disk, adding all message definitions
+    /// to the parser. Multiple DBC files can be loaded to combine definitions
+    /// from different sources.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to the DBC file
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CanDecodeError::Io`] if the file cannot be read,
+    /// [`CanDecodeError::Utf8`] if it isn't valid UTF-8, or
+    /// [`CanDecodeError::Parse`] if it cannot be parsed.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use can_decode::Parser;
+    /// use std::path::Path;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut parser = Parser::new();
+    /// parser.add_from_dbc_file(Path::new("vehicle.dbc"))?;
+    /// parser.add_from_dbc_file(Path::new("diagnostics.dbc"))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn add_from_dbc_file(&mut self, path: &std::path::Path) -> Result<(), CanDecodeError> {
+        let buffer = std::fs::read(path)?;
+        let s = String::from_utf8(buffer)?;
+        self.add_from_str(&s)?;
+        Ok(())
+    }
+
+    /// Validates a received frame against its DBC message definition.
+    ///
+    /// Performs the usual pre-decode sanity checks in one call:
+    /// 1. The message ID is defined, with the same standard/extended format
+    /// 2. The DLC code is valid (0-8 for classic CAN, 9-15 for CAN FD)
+    /// 3. The payload length matches the length encoded by the DLC
+    /// 4. The payload length matches the message size from the DBC
+    ///
+    /// # Arguments
+    ///
+    /// * `msg_id` - The CAN message identifier, with or without the extended flag (bit 31)
+    /// * `is_extended` - Whether the frame was received with an extended (29-bit) ID
+    /// * `dlc` - The DLC code from the frame header
+    /// * `data_len` - The number of payload bytes received
+    ///
+    /// # Errors
+    ///
+    /// Returns the first [`FrameError`] found, checked in the order above.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use can_decode::Parser;
+    /// use std::path::Path;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let parser = Parser::from_dbc_file(Path::new("my_database.dbc"))?;
+    ///
+    /// let data = [0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC, 0xDE, 0xF0];
+    /// match parser.validate_frame(0x123, false, 8, data.len()) {
+    ///     Ok(()) => println!("{:?}", parser.decode_msg(0x123, &data)),
+    ///     Err(e) => println!("Dropping bad frame: {}", e),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn validate_frame(
+        &self,
+        msg_id: u32,
+        is_extended: bool,
+        dlc: u8,
+        data_len: usize,
+    ) -> Result<(), FrameError> {
+        let (key, other_key) = if is_extended {
+            (msg_id | EXTENDED_ID_FLAG, msg_id & !EXTENDED_ID_FLAG)
+        } else {
+            (msg_id, msg_id | EXTENDED_ID_FLAG)
+        };
+
+        let Some(msg_entry) = self.msg_entries.get(&key) else {
+            if self.msg_entries.contains_key(&other_key) {
+                return Err(FrameError::ExtendedMismatch {
+                    msg_id,
+                    is_extended,
+                });
+            }
+            return Err(FrameError::UnknownMessage(msg_id));
+        };
+
+        let dlc_len = *DLC_TO_LEN
+            .get(dlc as usize)
+            .ok_or(FrameError::InvalidDlc(dlc))?;
+        if dlc_len != data_len {
+            return Err(FrameError::DlcMismatch { dlc, data_len });
+        }
+
+        let msg_size = msg_entry.msg_def.size as usize;
+        if msg_size != data_len {
+            return Err(FrameError::LengthMismatch {
+                expected: msg_size,
+                actual: data_len,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Decodes a raw CAN message into structured data.
+    ///
+    /// Takes a CAN message ID and raw data bytes, then decodes all signals
+    /// according to the DBC definitions. Each signal is extracted, scaled,
+    /// and converted to its physical value or enum label (if defined).
+    ///
+    /// If the payload length doesn't match the message size from the DBC, the
+    /// decode still succeeds with whatever signals fit in `data`, and the mismatch
+    /// is reported in [`DecodedMessage::dlc_mismatch`]. With
+    /// [`set_strict_dlc`](Parser::set_strict_dlc) enabled, payloads shorter than
+    /// the message size are rejected instead. Payloads longer than the
+    /// [`max_data_len`](Parser::set_max_data_len) (64 bytes by default) are
+    /// always rejected.
+    ///
+    /// For multiplexed messages, the multiplexor switch is decoded first. Plain
+    /// (non-multiplexed) signals and the switch itself are always included, while
+    /// multiplexed signals are only included when their multiplexer value matches
+    /// the switch value in `data`.
+    ///
+    /// # Arguments
+    ///
+    /// * `msg_id` - The CAN message identifier
+    /// * `data` - The raw message data bytes (typically 0-8 bytes for standard CAN)
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(DecodedMessage)` if the message ID is known, or `None` if
+    /// the message ID is not found in the loaded DBC definitions, the payload is
+    /// too long, or it is too short in strict mode.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use can_decode::Parser;
+    /// use std::path::Path;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let parser = Parser::from_dbc_file(Path::new("my_database.dbc"))?;
+    ///
+    /// let msg_id = 0x123;
+    /// let data = [0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC, 0xDE, 0xF0];
+    ///
+    /// if let Some(decoded) = parser.decode_msg(msg_id, &data) {
+    ///     println!("Message: {} (ID: {:#X})", decoded.name, decoded.msg_id);
+    ///     for (name, signal) in &decoded.signals {
+    ///         println!("  {}: {:?} {}", name, signal.value, signal.unit);
+    ///     }
+    /// } else {
+    ///     println!("Unknown message ID: {:#X}", msg_id);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Decoding a message that interleaves plain signals with multiplexed groups:
+    ///
+    /// ```
+    /// use can_decode::Parser;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let dbc = r#"
+    /// VERSION ""
+    ///
+    /// BU_: ECU
+    ///
+    /// BO_ 256 Mixed: 8 ECU
+    ///  SG_ Counter : 0|4@1+ (1,0) [0|15] "" Vector__XXX
+    ///  SG_ Mux M : 4|4@1+ (1,0) [0|15] "" Vector__XXX
+    ///  SG_ Temp m0 : 8|8@1+ (1,-40) [-40|215] "degC" Vector__XXX
+    ///  SG_ Speed m1 : 8|16@1+ (0.1,0) [0|250] "km/h" Vector__XXX
+    ///  SG_ Status : 56|8@1+ (1,0) [0|255] "" Vector__XXX
+    /// "#;
+    /// let mut parser = Parser::new();
+    /// parser.add_from_str(dbc)?;
+    ///
+    /// // Mux = 0 selects Temp; Counter and Status are always present
+    /// let decoded = parser.decode_msg(256, &[0x03, 0x64, 0, 0, 0, 0, 0, 0x2A]).unwrap();
+    /// let names: Vec<&str> = decoded.signals.keys().map(|k| k.as_str()).collect();
+    /// assert_eq!(names, ["Counter", "Mux", "Temp", "Status"]);
+    /// assert_eq!(decoded.signals["Temp"].value.physical, 60.0);
+    ///
+    /// // Mux = 1 selects Speed instead
+    /// let decoded = parser.decode_msg(256, &[0x13, 0xE8, 0x03, 0, 0, 0, 0, 0x2A]).unwrap();
+    /// let names: Vec<&str> = decoded.signals.keys().map(|k| k.as_str()).collect();
+    /// assert_eq!(names, ["Counter", "Mux", "Speed", "Status"]);
+    /// assert_eq!(decoded.signals["Speed"].value.physical, 100.0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn decode_msg(&self, msg_id: u32, data: &[u8]) -> Option<DecodedMessage> {
+        use std::sync::atomic::Ordering::Relaxed;
+
+        let Some(msg_entry) = self.lookup(msg_id) else {
+            self.counters.unknown_ids.fetch_add(1, Relaxed);
+            return None;
+        };
+
+        if self.check_payload(msg_entry, data).is_err() {
+            self.counters.decode_failures.fetch_add(1, Relaxed);
+            return None;
+        }
+
+        let cache_key = msg_entry.msg_def.id.raw();
+        if self.decode_cache.enabled
+            && let Some(decoded) = self.decode_cache.get(cache_key, data)
+        {
+            self.counters.messages_decoded.fetch_add(1, Relaxed);
+            return Some(decoded);
+        }
+
+        let decoded = self.decode_msg_inner(msg_entry, data, None, None, |_idx, _def| true);
+        match &decoded {
+            Some(decoded) => {
+                self.counters.messages_decoded.fetch_add(1, Relaxed);
+                if self.decode_cache.enabled {
+                    self.decode_cache.insert(cache_key, data, decoded);
+                }
+            }
+            None => {
+                self.counters.decode_failures.fetch_add(1, Relaxed);
+            }
+        }
+        decoded
+    }
+
+    /// Decodes a raw CAN message, with the extended ID flag given separately.
+    ///
+    /// Definitions are keyed by ID and format, so a standard and an extended
+    /// message with the same numeric ID can both be loaded. With
+    /// [`decode_msg`](Parser::decode_msg), extended IDs are selected by setting
+    /// bit 31 of the ID (as in `can_dbc::MessageId::raw` and SocketCAN's
+    /// `CAN_EFF_FLAG`), while this takes the numeric ID and flag as read from a
+    /// frame header.
+    ///
+    /// # Arguments
+    ///
+    /// * `msg_id` - The numeric CAN identifier, without flag bits
+    /// * `is_extended` - Whether the frame has an extended (29-bit) ID
+    /// * `data` - The raw message data bytes
+    ///
+    /// # Returns
+    ///
+    /// Returns the decoded message, or `None` under the same conditions as
+    /// [`decode_msg`](Parser::decode_msg).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use can_decode::Parser;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut parser = Parser::new();
+    /// parser.add_from_str(
+    ///     r#"
+    /// BO_ 291 Standard: 1 ECU
+    ///  SG_ A : 0|8@1+ (1,0) [0|0] "" Vector__XXX
+    /// BO_ 2147483939 Extended: 1 ECU
+    ///  SG_ B : 0|8@1+ (1,0) [0|0] "" Vector__XXX
+    /// "#,
+    /// )?;
+    ///
+    /// assert_eq!(parser.decode_msg_ext(0x123, false, &[1]).unwrap().name, "Standard");
+    /// assert_eq!(parser.decode_msg_ext(0x123, true, &[1]).unwrap().name, "Extended");
+    /// assert_eq!(parser.decode_msg(0x123, &[1]).unwrap().name, "Standard");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn decode_msg_ext(
+        &self,
+        msg_id: u32,
+        is_extended: bool,
+        data: &[u8],
+    ) -> Option<DecodedMessage> {
+        let msg_id = if is_extended {
+            msg_id | EXTENDED_ID_FLAG
+        } else {
+            msg_id & !EXTENDED_ID_FLAG
+        };
+        self.decode_msg(msg_id, data)
+    }
+
+    /// Decodes a raw CAN message, rejecting payloads shorter than the message size.
+    ///
+    /// Unlike [`decode_msg`](Parser::decode_msg), which decodes whatever signals
+    /// fit in a short payload, this always requires `data` to hold at least the
+    /// message size from the DBC, regardless of
+    /// [`set_strict_dlc`](Parser::set_strict_dlc). Longer payloads are accepted.
+    ///
+    /// # Arguments
+    ///
+    /// * `msg_id` - The CAN message identifier
+    /// * `data` - The raw message data bytes
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FrameError::UnknownMessage`] if the message ID is not defined,
+    /// [`FrameError::LengthMismatch`] if the payload is too short,
+    /// [`FrameError::PayloadTooLong`] if it is longer than
+    /// [`set_max_data_len`](Parser::set_max_data_len) allows, and
+    /// [`FrameError::DecodeFailed`] if a signal fails to decode.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use can_decode::{FrameError, Parser};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut parser = Parser::new();
+    /// parser.add_from_str(
+    ///     r#"
+    /// BO_ 256 Motor: 8 ECU
+    ///  SG_ Speed : 0|16@1+ (1,0) [0|0] "rpm" Vector__XXX
+    ///  SG_ Torque : 48|16@1- (1,0) [0|0] "Nm" Vector__XXX
+    /// "#,
+    /// )?;
+    ///
+    /// let err = parser.try_decode_msg(256, &[0xE8, 0x03, 0x00]).unwrap_err();
+    /// assert_eq!(err, FrameError::LengthMismatch { expected: 8, actual: 3 });
+    ///
+    /// let decoded = parser.try_decode_msg(256, &[0xE8, 0x03, 0, 0, 0, 0, 0x10, 0])?;
+    /// assert_eq!(decoded.signals["Speed"].value.physical, 1000.0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn try_decode_msg(&self, msg_id: u32, data: &[u8]) -> Result<DecodedMessage, FrameError> {
+        let msg_entry = self
+            .lookup(msg_id)
+            .ok_or(FrameError::UnknownMessage(msg_id))?;
+
+        self.check_payload(msg_entry, data)?;
+        let msg_size = msg_entry.msg_def.size as usize;
+        if data.len() < msg_size {
+            return Err(FrameError::LengthMismatch {
+                expected: msg_size,
+                actual: data.len(),
+            });
+        }
+
+        self.decode_msg_inner(msg_entry, data, None, None, |_idx, _def| true)
+            .ok_or(FrameError::DecodeFailed(msg_id))
+    }
+
+    /// Sets the longest payload the decode methods accept.
+    ///
+    /// Longer payloads are rejected by every decode method before any signal is
+    /// extracted, which bounds the work per call when frames come from
+    /// untrusted sources. The default of 64 bytes is the largest CAN FD payload.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_data_len` - The maximum payload length in bytes
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use can_decode::Parser;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut parser = Parser::new();
+    /// parser.add_from_str(
+    ///     r#"
+    /// BO_ 256 Motor: 8 ECU
+    ///  SG_ Speed : 0|16@1+ (1,0) [0|0] "rpm" Vector__XXX
+    /// "#,
+    /// )?;
+    ///
+    /// assert!(parser.decode_msg(256, &[0u8; 64]).is_some());
+    /// assert!(parser.decode_msg(256, &vec![0u8; 1 << 20]).is_none());
+    ///
+    /// parser.set_max_data_len(8);
+    /// assert!(parser.decode_msg(256, &[0u8; 12]).is_none());
+    /// assert!(parser.decode_raw_msg(256, &[0u8; 12]).is_none());
+    /// assert!(parser.decode_msg_detailed(256, &[0u8; 12]).is_none());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_max_data_len(&mut self, max_data_len: usize) {
+        self.max_data_len = max_data_len;
+    }
+
+    /// Sets whether the decode methods reject short payloads.
+    ///
+    /// By default, a payload shorter than the message size from the DBC is
+    /// decoded partially, skipping (and warning about) every signal that
+    /// doesn't fit. In strict mode, such payloads make
+    /// [`decode_msg`](Parser::decode_msg) and the other decode methods return
+    /// `None` instead. Use [`try_decode_msg`](Parser::try_decode_msg) to get
+    /// the reason as an error.
+    ///
+    /// # Arguments
+    ///
+    /// * `strict` - Whether to reject payloads shorter than the message size
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use can_decode::Parser;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut parser = Parser::new();
+    /// parser.add_from_str(
+    ///     r#"
+    /// BO_ 256 Motor: 8 ECU
+    ///  SG_ Speed : 0|16@1+ (1,0) [0|0] "rpm" Vector__XXX
+    ///  SG_ Torque : 48|16@1- (1,0) [0|0] "Nm" Vector__XXX
+    /// "#,
+    /// )?;
+    ///
+    /// let decoded = parser.decode_msg(256, &[0xE8, 0x03, 0x00]).unwrap();
+    /// assert!(!decoded.signals.contains_key("Torque"));
+    /// assert_eq!(decoded.dlc_mismatch, Some((8, 3)));
+    ///
+    /// parser.set_strict_dlc(true);
+    /// assert!(parser.decode_msg(256, &[0xE8, 0x03, 0x00]).is_none());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_strict_dlc(&mut self, strict: bool) {
+        self.strict_dlc = strict;
+    }
+
+    /// Enables or disables reuse of the previous decode for repeated payloads.
+    ///
+    /// With the cache enabled, [`decode_msg`](Parser::decode_msg) remembers the
+    /// last payload and result for each message, and returns a copy of that
+    /// result when the same payload is decoded again, skipping signal
+    /// extraction. This pays off on steady-state buses where most frames repeat.
+    /// The cache holds at most one entry per loaded message, and is emptied
+    /// whenever definitions or decode settings change. It is disabled by default.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether to cache decodes
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use can_decode::Parser;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut parser = Parser::new();
+    /// parser.add_from_str(
+    ///     r#"
+    /// BO_ 256 Motor: 1 ECU
+    ///  SG_ Speed : 0|8@1+ (10,0) [0|0] "rpm" Vector__XXX
+    /// "#,
+    /// )?;
+    /// parser.enable_decode_cache(true);
+    ///
+    /// let first = parser.decode_msg(256, &[100]).unwrap();
+    /// let repeated = parser.decode_msg(256, &[100]).unwrap();
+    /// assert_eq!(first.signals["Speed"].value.physical, 1000.0);
+    /// assert_eq!(repeated.signals["Speed"].value.physical, 1000.0);
+    ///
+    /// let changed = parser.decode_msg(256, &[50]).unwrap();
+    /// assert_eq!(changed.signals["Speed"].value.physical, 500.0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn enable_decode_cache(&mut self, enabled: bool) {
+        self.decode_cache.enabled = enabled;
+        self.decode_cache.invalidate();
+    }
+
+    /// Returns the number of messages decoded, unknown IDs, and decode failures
+    /// seen by [`decode_msg`](Parser::decode_msg).
+    ///
+    /// The counters are cumulative and can be forwarded to a metrics system,
+    /// e.g. as Prometheus counters. They are only updated by `decode_msg` and
+    /// the methods that decode through it, such as
+    /// [`decode_msg_by_name`](Parser::decode_msg_by_name),
+    /// [`decode_fd_msg`](Parser::decode_fd_msg),
+    /// [`decode_batch`](Parser::decode_batch) and [`AscReader`]. Methods with
+    /// their own decode path, such as [`try_decode_msg`](Parser::try_decode_msg),
+    /// [`decode_msg_with_def`](Parser::decode_msg_with_def),
+    /// [`decode_on_bus`](Parser::decode_on_bus),
+    /// [`decode_each`](Parser::decode_each) and
+    /// [`decode_signal_by_name`](Parser::decode_signal_by_name), don't count.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use can_decode::Parser;
+    ///
+    /// let parser = Parser::new();
+    /// assert!(parser.decode_msg(0x123, &[0u8; 8]).is_none());
+    ///
+    /// let stats = parser.decode_stats();
+    /// assert_eq!(stats.unknown_ids, 1);
+    /// assert_eq!(stats.messages_decoded, 0);
+    ///
+    /// // decode_each has its own decode path and isn't counted
+    /// assert!(parser.decode_each(0x123, &[0u8; 8], |_, _| {}).is_none());
+    /// assert_eq!(parser.decode_stats().unknown_ids, 1);
+    /// ```
+    pub fn decode_stats(&self) -> DecodeStats {
+        self.counters.snapshot()
+    }
+
+    /// Resets all decode counters to zero.
+    pub fn reset_decode_stats(&mut self) {
+        self.counters = DecodeCounters::default();
+    }
+
+    /// Decodes a raw CAN message and returns its DBC definition alongside it.
+    ///
+    /// This avoids a second lookup for consumers that need the definition as
+    /// well, e.g. to access signal ranges or receivers.
+    ///
+    /// # Arguments
+    ///
+    /// * `msg_id` - The CAN message identifier
+    /// * `data` - The raw message data bytes
+    ///
+    /// # Returns
+    ///
+    /// Returns the message definition and the decoded message, or `None` under
+    /// the same conditions as [`decode_msg`](Parser::decode_msg).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use can_decode::Parser;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let dbc = r#"
+    /// VERSION ""
+    ///
+    /// BU_: ECU DASH
+    ///
+    /// BO_ 256 Motor: 2 ECU
+    ///  SG_ Speed : 0|16@1+ (1,0) [0|8000] "rpm" DASH
+    /// "#;
+    /// let mut parser = Parser::new();
+    /// parser.add_from_str(dbc)?;
+    ///
+    /// let (msg_def, decoded) = parser.decode_msg_with_def(256, &[0xE8, 0x03]).unwrap();
+    /// assert_eq!(msg_def.name, "Motor");
+    /// assert_eq!(msg_def.id.raw(), 256);
+    /// assert_eq!(msg_def.size, 2);
+    /// assert_eq!(msg_def.signals[0].receivers, ["DASH"]);
+    /// assert_eq!(decoded.signals["Speed"].value.physical, 1000.0);
+    ///
+    /// assert!(parser.decode_msg_with_def(0x123, &[0xE8, 0x03]).is_none());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn decode_msg_with_def(
+        &self,
+        msg_id: u32,
+        data: &[u8],
+    ) -> Option<(&can_dbc::Message, DecodedMessage)> {
+        let msg_entry = self.lookup(msg_id)?;
+        self.check_payload(msg_entry, data).ok()?;
+        let decoded = self.decode_msg_inner(msg_entry, data, None, None, |_idx, _def| true)?;
+        Some((&msg_entry.msg_def, decoded))
+    }
+
+    /// Decodes a raw CAN message identified by its DBC message name.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the message as defined in the DBC file
+    /// * `data` - The raw message data bytes
+    ///
+    /// # Returns
+    ///
+    /// Returns the decoded message, or `None` if no message has this name or
+    /// under the same conditions as [`decode_msg`](Parser::decode_msg).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use can_decode::Parser;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut parser = Parser::new();
+    /// parser.add_from_str(
+    ///     r#"
+    /// BO_ 1280 MotorTemps: 2 ECU
+    ///  SG_ Winding : 0|8@1+ (1,-40) [0|0] "degC" Vector__XXX
+    ///  SG_ Inverter : 8|8@1+ (1,-40) [0|0] "degC" Vector__XXX
+    /// "#,
+    /// )?;
+    ///
+    /// let decoded = parser.decode_msg_by_name("MotorTemps", &[100, 90]).unwrap();
+    /// assert_eq!(decoded.msg_id, 1280);
+    /// assert_eq!(decoded.signals["Winding"].value.physical, 60.0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn decode_msg_by_name(&self, name: &str, data: &[u8]) -> Option<DecodedMessage> {
+        self.decode_msg(self.msg_id_for_name(name)?, data)
+    }
+
+    /// Returns the ID of the message with the given DBC name.
+    ///
+    /// Message names are indexed as definitions are loaded. If several loaded
+    /// messages share a name (e.g. across merged DBC files), the last one loaded
+    /// wins and a warning is logged, mirroring how duplicate IDs are handled.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the message as defined in the DBC file
+    ///
+    /// # Returns
+    ///
+    /// The message ID (with bit 31 set for extended IDs), or `None` if no
+    /// message has this name.
+    pub fn msg_id_for_name(&self, name: &str) -> Option<u32> {
+        self.msg_ids_by_name.get(name).copied()
+    }
+
+    /// Decodes a CAN FD frame after checking its payload length.
+    ///
+    /// CAN FD payloads can be up to 64 bytes, but only the lengths encodable by
+    /// a DLC are valid: 0-8, 12, 16, 20, 24, 32, 48 and 64 bytes. Frames with
+    /// any other length are rejected as malformed instead of being decoded.
+    ///
+    /// # Arguments
+    ///
+    /// * `msg_id` - The CAN message identifier
+    /// * `data` - The frame payload
+    ///
+    /// # Returns
+    ///
+    /// Returns the decoded message, or `None` if the payload length is not a
+    /// valid CAN FD length or under the same conditions as
+    /// [`decode_msg`](Parser::decode_msg).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use can_decode::Parser;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let dbc = r#"
+    /// VERSION ""
+    ///
+    /// BU_: ECU
+    ///
+    /// BO_ 1024 FdStatus: 64 ECU
+    ///  SG_ Header : 0|8@1+ (1,0) [0|255] "" Vector__XXX
+    ///  SG_ Pressure : 320|16@1+ (0.1,0) [0|6553.5] "kPa" Vector__XXX
+    ///  SG_ Counter : 511|8@0+ (1,0) [0|255] "" Vector__XXX
+    /// "#;
+    /// let mut parser = Parser::new();
+    /// parser.add_from_str(dbc)?;
+    ///
+    /// let mut data = [0u8; 64];
+    /// data[40] = 0xD2;
+    /// data[41] = 0x04;
+    /// data[63] = 0x2A;
+    ///
+    /// let decoded = parser.decode_fd_msg(1024, &data).unwrap();
+    /// assert_eq!(decoded.signals["Pressure"].value.raw, Some(1234));
+    /// assert_eq!(decoded.signals["Counter"].value.raw, Some(42));
+    ///
+    /// // 40 bytes is not a valid CAN FD payload length
+    /// assert!(parser.decode_fd_msg(1024, &data[..40]).is_none());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn decode_fd_msg(&self, msg_id: u32, data: &[u8]) -> Option<DecodedMessage> {
+        if !DLC_TO_LEN.contains(&data.len()) {
+            log::warn!(
+                "Invalid CAN FD payload length of {} bytes for message ID {:#X}",
+                data.len(),
+                msg_id
+            );
+            return None;
+        }
+
+        self.decode_msg(msg_id, data)
+    }
+
+    /// Decodes a raw CAN message whose ID is given as a string.
+    ///
+    /// IDs prefixed with `0x` (or `0X`) are parsed as hexadecimal, anything else
+    /// as decimal. Surrounding whitespace is ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `id_str` - The CAN message identifier, e.g. `"0x123"` or `"291"`
+    /// * `data` - The raw message data bytes
+    ///
+    /// # Returns
+    ///
+    /// Returns the decoded message, or `None` if the ID can't be parsed or under
+    /// the same conditions as [`decode_msg`](Parser::decode_msg).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use can_decode::Parser;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut parser = Parser::new();
+    /// parser.add_from_str(
+    ///     r#"
+    /// BO_ 291 Motor: 1 ECU
+    ///  SG_ Speed : 0|8@1+ (1,0) [0|0] "" Vector__XXX
+    /// "#,
+    /// )?;
+    ///
+    /// assert!(parser.decode_msg_str_id("0x123", &[42]).is_some());
+    /// assert!(parser.decode_msg_str_id("291", &[42]).is_some());
+    /// assert!(parser.decode_msg_str_id("0xZZZ", &[42]).is_none());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn decode_msg_str_id(&self, id_str: &str, data: &[u8]) -> Option<DecodedMessage> {
+        let id_str = id_str.trim();
+        let msg_id = match id_str
+            .strip_prefix("0x")
+            .or_else(|| id_str.strip_prefix("0X"))
+        {
+            Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+            None => id_str.parse().ok()?,
+        };
+
+        self.decode_msg(msg_id, data)
+    }
+
+    /// Decodes a line of a candump (SocketCAN) log.
+    ///
+    /// Lines are expected in the `candump -l` format `(timestamp) iface ID#DATA`,
+    /// e.g. `(1436509052.249713) can0 123#DEADBEEF`. IDs with 3 hex digits are
+    /// standard and IDs with 8 hex digits are extended. CAN FD frames in the
+    /// `ID##FDATA` form (`F` being the FD flags nibble) are decoded with
+    /// [`decode_fd_msg`](Parser::decode_fd_msg).
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - A line of the log
+    ///
+    /// # Returns
+    ///
+    /// The timestamp in seconds and the decoded message, or `None` if the line
+    /// is malformed, is a remote frame, or can't be decoded.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use can_decode::Parser;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut parser = Parser::new();
+    /// parser.add_from_str(
+    ///     r#"
+    /// BO_ 291 Motor: 2 ECU
+    ///  SG_ Speed : 0|16@1+ (1,0) [0|0] "rpm" Vector__XXX
+    /// BO_ 2566844926 Pack: 12 BMS
+    ///  SG_ Voltage : 64|16@1+ (0.01,0) [0|0] "V" Vector__XXX
+    /// "#,
+    /// )?;
+    ///
+    /// let (timestamp, decoded) = parser
+    ///     .decode_candump_line("(1436509052.250000) can0 123#E803")
+    ///     .unwrap();
+    /// assert_eq!(timestamp, 1436509052.25);
+    /// assert_eq!(decoded.signals["Speed"].value.physical, 1000.0);
+    ///
+    /// // Extended ID, CAN FD frame with flags nibble 1 (bit rate switch)
+    /// let line = "(1436509052.300000) can0 18FEF1FE##1000000000000000064050000";
+    /// let (_, decoded) = parser.decode_candump_line(line).unwrap();
+    /// assert_eq!(decoded.signals["Voltage"].value.physical, 13.8);
+    ///
+    /// assert!(parser.decode_candump_line("(1436509052.4) can0 123#R").is_none());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn decode_candump_line(&self, line: &str) -> Option<(f64, DecodedMessage)> {
+        let frame = parse_candump_line(line)?;
+        let decoded = if frame.is_fd {
+            self.decode_fd_msg(frame.msg_id, &frame.data)?
+        } else {
+            self.decode_msg(frame.msg_id, &frame.data)?
+        };
+        Some((frame.timestamp, decoded))
+    }
+
+    /// Lazily decodes a stream of frames.
+    ///
+    /// The adaptor works with any frame source, such as a live bus socket wrapped
+    /// in [`std::iter::from_fn`]. Each frame is decoded with
+    /// [`decode_msg`](Parser::decode_msg) as it is pulled, and the iterator ends
+    /// when the source does, so a source that stops on a read error or timeout
+    /// ends the stream.
+    ///
+    /// # Arguments
+    ///
+    /// * `frames` - The `(message ID, payload)` pairs to decode
+    ///
+    /// # Returns
+    ///
+    /// An iterator yielding one decode result per frame, `None` for frames that
+    /// can't be decoded.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use can_decode::Parser;
+    /// use std::path::Path;
+    ///
+    /// # struct Socket;
+    /// # impl Socket {
+    /// #     fn read_frame(&self) -> std::io::Result<(u32, [u8; 8])> { unimplemented!() }
+    /// # }
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let parser = Parser::from_dbc_file(Path::new("my_database.dbc"))?;
+    /// # let socket = Socket;
+    ///
+    /// // Read until the socket errors or times out
+    /// let frames = std::iter::from_fn(|| socket.read_frame().ok());
+    /// for decoded in parser.decode_frames(frames).flatten() {
+    ///     println!("{}: {:?}", decoded.name, decoded.signals);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn decode_frames<I, D>(&self, frames: I) -> impl Iterator<Item = Option<DecodedMessage>>
+    where
+        I: IntoIterator<Item = (u32, D)>,
+        D: AsRef<[u8]>,
+    {
+        frames
+            .into_iter()
+            .map(|(msg_id, data)| self.decode_msg(msg_id, data.as_ref()))
+    }
+
+    /// Decodes a batch of frames, such as a whole log buffer.
+    ///
+    /// This is the eager counterpart of [`decode_frames`](Parser::decode_frames).
+    ///
+    /// # Arguments
+    ///
+    /// * `frames` - The `(message ID, payload)` pairs to decode
+    ///
+    /// # Returns
+    ///
+    /// One decode result per frame, in the same order as `frames`, with `None`
+    /// for frames that can't be decoded (e.g. unknown IDs).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use can_decode::Parser;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut parser = Parser::new();
+    /// parser.add_from_str(
+    ///     r#"
+    /// BO_ 256 Motor: 1 ECU
+    ///  SG_ Speed : 0|8@1+ (10,0) [0|0] "rpm" Vector__XXX
+    /// "#,
+    /// )?;
+    ///
+    /// let frames = [(256, vec![1]), (0x7FF, vec![0]), (256, vec![2])];
+    /// let decoded = parser.decode_batch(&frames);
+    ///
+    /// assert_eq!(decoded.len(), 3);
+    /// assert_eq!(decoded[0].as_ref().unwrap().signals["Speed"].value.physical, 10.0);
+    /// assert!(decoded[1].is_none());
+    /// assert_eq!(decoded[2].as_ref().unwrap().signals["Speed"].value.physical, 20.0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn decode_batch(&self, frames: &[(u32, Vec<u8>)]) -> Vec<Option<DecodedMessage>> {
+        self.decode_frames(frames.iter().map(|(msg_id, data)| (*msg_id, data)))
+            .collect()
+    }
+
+    /// Decodes a raw CAN message into a list of every defined signal.
+    ///
+    /// Unlike [`decode_msg`](Parser::decode_msg), which only contains the
+    /// signals present in the frame, the result has one entry per signal of the
+    /// message definition, in DBC declaration order followed by composite
+    /// signals (the same columns as [`csv_header`](Parser::csv_header)).
+    /// Signals that weren't decoded, such as inactive multiplexed signals or
+    /// signals beyond a short payload, are `None`.
+    ///
+    /// # Arguments
+    ///
+    /// * `msg_id` - The CAN message identifier
+    /// * `data` - The raw message data bytes
+    ///
+    /// # Returns
+    ///
+    /// The `(signal name, decoded signal)` pairs, or `None` if the message is
+    /// unknown or can't be decoded.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use can_decode::Parser;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let dbc = r#"
+    /// VERSION ""
+    ///
+    /// BU_: ECU
+    ///
+    /// BO_ 256 Mixed: 4 ECU
+    ///  SG_ Mux M : 0|4@1+ (1,0) [0|15] "" Vector__XXX
+    ///  SG_ Temp m0 : 8|8@1+ (1,-40) [-40|215] "degC" Vector__XXX
+    ///  SG_ Speed m1 : 8|16@1+ (0.1,0) [0|250] "km/h" Vector__XXX
+    ///  SG_ Status : 24|8@1+ (1,0) [0|255] "" Vector__XXX
+    /// "#;
+    /// let mut parser = Parser::new();
+    /// parser.add_from_str(dbc)?;
+    ///
+    /// // Mux = 0 selects Temp, and the 3-byte payload cuts off Status
+    /// let signals = parser.decode_msg_vec(256, &[0x00, 0x64, 0x00]).unwrap();
+    /// let present: Vec<(&str, bool)> = signals
+    ///     .iter()
+    ///     .map(|(name, signal)| (name.as_str(), signal.is_some()))
+    ///     .collect();
+    /// assert_eq!(
+    ///     present,
+    ///     [("Mux", true), ("Temp", true), ("Speed", false), ("Status", false)]
+    /// );
+    /// assert_eq!(signals[1].1.as_ref().unwrap().value.physical, 60.0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn decode_msg_vec(
+        &self,
+        msg_id: u32,
+        data: &[u8],
+    ) -> Option<Vec<(String, Option<DecodedSignal>)>> {
+        let msg_entry = self.lookup(msg_id)?;
+        let mut decoded = self.decode_msg(msg_id, data)?;
+
+        let signals = msg_entry.msg_def.signals.iter().map(|s| &s.name);
+        let composites = msg_entry.composites.iter().map(|c| &c.name);
+        Some(
+            signals
+                .chain(composites)
+                .map(|name| (name.clone(), decoded.signals.swap_remove(name)))
+                .collect(),
+        )
+    }
+
+    /// Scores how plausibly each loaded message definition fits a frame.
+    ///
+    /// Meant for reverse engineering unlabeled frames: `data` is decoded with
+    /// every loaded definition, and each definition is scored by the fraction
+    /// of its present signals (after multiplexer selection) whose value is
+    /// plausible. A value is plausible when it fits in `data`, lies within the
+    /// signal's DBC range (if constrained), and, for signals with value
+    /// descriptions, has a defined label.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - The payload of the unlabeled frame
+    ///
+    /// # Returns
+    ///
+    /// `(message ID, score)` pairs with scores between 0 and 1, best first.
+    /// Equal scores are ordered by how closely the message size matches
+    /// `data.len()`, then by ID. Messages without signals are left out.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use can_decode::Parser;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut parser = Parser::new();
+    /// parser.add_from_str(
+    ///     r#"
+    /// BO_ 256 Battery: 2 ECU
+    ///  SG_ SoC : 0|8@1+ (1,0) [0|100] "%" Vector__XXX
+    ///  SG_ State : 8|8@1+ (1,0) [0|0] "" Vector__XXX
+    /// BO_ 512 Motor: 2 ECU
+    ///  SG_ Speed : 0|16@1+ (1,0) [0|8000] "rpm" Vector__XXX
+    /// VAL_ 256 State 0 "Idle" 1 "Charging" ;
+    /// "#,
+    /// )?;
+    ///
+    /// // SoC 80% while charging; as a Motor frame, Speed would be 336 rpm
+    /// let candidates = parser.candidate_messages(&[80, 1]);
+    /// assert_eq!(candidates, [(256, 1.0), (512, 1.0)]);
+    ///
+    /// // SoC 250% is out of range and State 7 has no label, while 2042 rpm is plausible
+    /// let candidates = parser.candidate_messages(&[0xFA, 0x07]);
+    /// assert_eq!(candidates, [(512, 1.0), (256, 0.0)]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn candidate_messages(&self, data: &[u8]) -> Vec<(u32, f64)> {
+        let mut candidates: Vec<(u32, f64, usize)> = Vec::new();
+
+        for (&msg_id, msg_entry) in &self.msg_entries {
+            let mux_value = self.decode_mux_value(&msg_entry.msg_def, data);
+            let mut present = 0usize;
+            let mut plausible = 0usize;
+
+            for signal_def in &msg_entry.msg_def.signals {
+                if !Self::is_signal_active(msg_entry, signal_def, mux_value) {
+                    continue;
+                }
+                present += 1;
+
+                let Some(raw_value) = self.extract_signal_raw(msg_entry, signal_def, data) else {
+                    continue;
+                };
+                let signal_meta = msg_entry.signal_meta.get(&signal_def.name);
+                let physical = Self::physical_value(signal_def, signal_meta, raw_value);
+                let has_label = signal_meta
+                    .filter(|meta| !meta.enum_map.is_empty())
+                    .is_none_or(|meta| {
+                        meta.enum_map
+                            .contains_key(&Self::sign_extend(signal_def, raw_value))
+                    });
+                if has_label && in_dbc_range(signal_def, physical) {
+                    plausible += 1;
+                }
+            }
+
+            if present > 0 {
+                let size_diff = (msg_entry.msg_def.size as usize).abs_diff(data.len());
+                candidates.push((msg_id, plausible as f64 / present as f64, size_diff));
+            }
+        }
+
+        candidates.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.2.cmp(&b.2)).then(a.0.cmp(&b.0)));
+        candidates
+            .into_iter()
+            .map(|(msg_id, score, _)| (msg_id, score))
+            .collect()
+    }
+
+    /// Decodes a raw CAN message, ignoring bytes beyond the message's DBC size.
+    ///
+    /// Logs with fixed-size records pad short frames out to the record length.
+    /// Padding never affects signals that fit within the message size, but a
+    /// signal mistakenly defined past the end of the message would otherwise
+    /// read it. Here `data` is clamped to the DBC size before decoding, so such
+    /// signals are left out instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `msg_id` - The CAN message identifier
+    /// * `data` - The raw message data bytes, possibly with trailing padding
+    ///
+    /// # Returns
+    ///
+    /// Returns the decoded message, or `None` if the message ID is not known or
+    /// decoding fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use can_decode::Parser;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let dbc = r#"
+    /// VERSION ""
+    ///
+    /// BU_: ECU
+    ///
+    /// BO_ 256 Short: 4 ECU
+    ///  SG_ Speed : 0|16@1+ (1,0) [0|0] "" Vector__XXX
+    ///  SG_ Stray : 32|8@1+ (1,0) [0|0] "" Vector__XXX
+    /// "#;
+    /// let mut parser = Parser::new();
+    /// parser.add_from_str(dbc)?;
+    ///
+    /// // A DLC 4 frame padded to an 8-byte log record
+    /// let data = [0x34, 0x12, 0x00, 0x00, 0xAA, 0xAA, 0xAA, 0xAA];
+    ///
+    /// let decoded = parser.decode_msg_truncating(256, &data).unwrap();
+    /// assert_eq!(decoded.signals["Speed"].value.physical, 4660.0);
+    /// assert!(!decoded.signals.contains_key("Stray"));
+    ///
+    /// // Without truncation, the signal defined past the DLC reads the padding
+    /// let decoded = parser.decode_msg(256, &data).unwrap();
+    /// assert_eq!(decoded.signals["Stray"].value.physical, 170.0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn decode_msg_truncating(&self, msg_id: u32, data: &[u8]) -> Option<DecodedMessage> {
+        let msg_entry = self.lookup(msg_id)?;
+        self.check_payload(msg_entry, data).ok()?;
+        let msg_size = msg_entry.msg_def.size as usize;
+        let data = &data[..data.len().min(msg_size)];
+        let frame_bits = data.len() * 8;
+
+        self.decode_msg_inner(msg_entry, data, None, None, |_idx, signal_def| {
+            Self::signal_bit_positions(signal_def)
+                .iter()
+                .all(|&bit| bit < frame_bits)
+        })
+    }
+
+    /// Decodes a raw CAN message, dropping signals outside their DBC range.
+    ///
+    /// Each decoded signal's physical value is checked against the signal's
+    /// `[min|max]` range from the DBC, and implausible values are left out of the
+    /// result. Signals with an unspecified `[0|0]` range are always kept, as are
+    /// composite signals.
+    ///
+    /// # Arguments
+    ///
+    /// * `msg_id` - The CAN message identifier
+    /// * `data` - The raw message data bytes
+    ///
+    /// # Returns
+    ///
+    /// Returns the decoded message with only in-range signals, or `None` under
+    /// the same conditions as [`decode_msg`](Parser::decode_msg).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use can_decode::Parser;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let dbc = r#"
+    /// VERSION ""
+    ///
+    /// BU_: ECU
+    ///
+    /// BO_ 256 Battery: 2 ECU
+    ///  SG_ SoC : 0|8@1+ (1,0) [0|100] "%" Vector__XXX
+    ///  SG_ Counter : 8|8@1+ (1,0) [0|0] "" Vector__XXX
+    /// "#;
+    /// let mut parser = Parser::new();
+    /// parser.add_from_str(dbc)?;
+    ///
+    /// let decoded = parser.decode_msg_in_range(256, &[250, 250]).unwrap();
+    /// assert!(!decoded.signals.contains_key("SoC"));
+    /// assert!(decoded.signals.contains_key("Counter"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn decode_msg_in_range(&self, msg_id: u32, data: &[u8]) -> Option<DecodedMessage> {
+        let msg_def = &self.lookup(msg_id)?.msg_def;
+        let mut decoded = self.decode_msg(msg_id, data)?;
+
+        decoded.signals.retain(|name, signal| {
+            let Some(signal_def) = msg_def.signals.iter().find(|s| s.name == *name) else {
+                return true;
+            };
+            let in_range = in_dbc_range(signal_def, signal.value.physical);
+            if !in_range {
+                log::debug!(
+                    "Dropping signal {} from message {}: value {} outside of DBC range",
+                    name,
+                    msg_def.name,
+                    signal.value.physical
+                );
+            }
+            in_range
+        });
+
+        Some(decoded)
+    }
+
+    /// Decodes a CAN message whose payload is given as a sequence of bits.
+    ///
+    /// This is meant for bit-oriented transports whose payloads aren't aligned to
+    /// whole bytes. Bits are taken most significant bit first, matching the
+    /// `Msb0` ordering of the `bitvec` crate, so a `BitSlice<u8, Msb0>` can be
+    /// passed as `bits.iter().by_vals()`. If the number of bits isn't a multiple
+    /// of 8, the last byte is padded with zero bits. At most one byte more than
+    /// [`set_max_data_len`](Parser::set_max_data_len) allows is read from
+    /// `bits`, so an endless iterator is rejected instead of exhausting memory.
+    ///
+    /// # Arguments
+    ///
+    /// * `msg_id` - The CAN message identifier
+    /// * `bits` - The payload bits, most significant bit of the first byte first
+    ///
+    /// # Returns
+    ///
+    /// Returns the decoded message, or `None` under the same conditions as
+    /// [`decode_msg`](Parser::decode_msg).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use can_decode::Parser;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let dbc = r#"
+    /// VERSION ""
+    ///
+    /// BU_: ECU
+    ///
+    /// BO_ 256 Status: 2 ECU
+    ///  SG_ Mode : 0|4@1+ (1,0) [0|15] "" Vector__XXX
+    ///  SG_ Level : 4|12@1+ (0.5,0) [0|2047.5] "" Vector__XXX
+    /// "#;
+    /// let mut parser = Parser::new();
+    /// parser.add_from_str(dbc)?;
+    ///
+    /// // 0x12, 0x34 as bits, most significant bit of each byte first
+    /// let bits = [0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 1, 1, 0, 1, 0, 0].map(|b| b == 1);
+    /// let from_bits = parser.decode_msg_bits(256, bits).unwrap();
+    /// let from_bytes = parser.decode_msg(256, &[0x12, 0x34]).unwrap();
+    /// assert_eq!(format!("{from_bits:?}"), format!("{from_bytes:?}"));
+    /// assert_eq!(from_bits.signals["Mode"].value.raw, Some(0x2));
+    /// assert_eq!(from_bits.signals["Level"].value.raw, Some(0x341));
+    ///
+    /// // Payloads longer than the maximum data length are rejected
+    /// assert!(parser.decode_msg_bits(256, std::iter::repeat(false)).is_none());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn decode_msg_bits(
+        &self,
+        msg_id: u32,
+        bits: impl IntoIterator<Item = bool>,
+    ) -> Option<DecodedMessage> {
+        // Read one byte past the limit, so that too long payloads still fail the length check
+        let max_bits = self.max_data_len.saturating_add(1).saturating_mul(8);
+        let mut data = Vec::with_capacity(8);
+        for (idx, bit) in bits.into_iter().take(max_bits).enumerate() {
+            if idx % 8 == 0 {
+                data.push(0u8);
+            }
+            if bit {
+                *data.last_mut()? |= 0x80 >> (idx % 8);
+            }
+        }
+
+        self.decode_msg(msg_id, &data)
+    }
+
+    /// Decodes a raw CAN message and stamps it with the current system time.
+    ///
+    /// This is a convenience for simple tools that don't have their own clock.
+    /// The timestamp is taken when decoding finishes, so consumers that need
+    /// precise receive times should capture their own timestamp instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `msg_id` - The CAN message identifier
+    /// * `data` - The raw message data bytes
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some((timestamp, DecodedMessage))`, or `None` under the same
+    /// conditions as [`decode_msg`](Parser::decode_msg).
+    pub fn decode_msg_stamped(
+        &self,
+        msg_id: u32,
+        data: &[u8],
+    ) -> Option<(std::time::SystemTime, DecodedMessage)> {
+        let decoded = self.decode_msg(msg_id, data)?;
+        Some((std::time::SystemTime::now(), decoded))
+    }
+
+    /// Decodes a raw CAN message received at a known time.
+    ///
+    /// Use this with receive timestamps taken by the kernel or the CAN
+    /// hardware (e.g. via `SO_TIMESTAMPING` on a SocketCAN socket), which are
+    /// more accurate than a timestamp taken in userspace after the read. The
+    /// timestamp is carried through unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `timestamp` - When the frame was received
+    /// * `msg_id` - The CAN message identifier
+    /// * `data` - The raw message data bytes
+    ///
+    /// # Returns
+    ///
+    /// Returns the timestamped message, or `None` under the same conditions as
+    /// [`decode_msg`](Parser::decode_msg).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use can_decode::Parser;
+    /// use std::time::{Duration, UNIX_EPOCH};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut parser = Parser::new();
+    /// parser.add_from_str(
+    ///     r#"
+    /// BO_ 256 Motor: 1 ECU
+    ///  SG_ Speed : 0|8@1+ (10,0) [0|0] "rpm" Vector__XXX
+    /// "#,
+    /// )?;
+    ///
+    /// // A `timespec` from the socket's control message
+    /// let (tv_sec, tv_nsec) = (1_700_000_000, 123_456_789);
+    /// let timestamp = UNIX_EPOCH + Duration::new(tv_sec, tv_nsec);
+    ///
+    /// let stamped = parser.decode_timestamped(timestamp, 256, &[42]).unwrap();
+    /// assert_eq!(stamped.timestamp, timestamp);
+    /// assert_eq!(stamped.message.signals["Speed"].value.physical, 420.0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn decode_timestamped(
+        &self,
+        timestamp: std::time::SystemTime,
+        msg_id: u32,
+        data: &[u8],
+    ) -> Option<TimestampedMessage> {
+        let message = self.decode_msg(msg_id, data)?;
+        Some(TimestampedMessage { timestamp, message })
+    }
+
+    /// Decodes a raw CAN message into raw integers and scaling parameters.
+    ///
+    /// Unlike [`decode_msg`](Parser::decode_msg), no floating-point scaling is
+    /// applied. Each signal present in the frame (after multiplexer selection)
+    /// maps to its sign-extended raw value along with its factor and offset, so
+    /// fixed-point pipelines can apply scaling in their own arithmetic. Raw
+    /// values are `i128`, like [`DecodedSignalValue::raw`], so unsigned 64-bit
+    /// signals keep their full range. IEEE float/double signals report their
+    /// raw bit pattern.
+    ///
+    /// # Arguments
+    ///
+    /// * `msg_id` - The CAN message identifier
+    /// * `data` - The raw message data bytes
+    ///
+    /// # Returns
+    ///
+    /// Returns a map of signal name to `(raw, factor, offset)`, or `None` if the
+    /// message ID is not known or any present signal could not be extracted.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use can_decode::Parser;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let dbc = r#"
+    /// VERSION ""
+    ///
+    /// BU_: ECU
+    ///
+    /// BO_ 256 Motor: 3 ECU
+    ///  SG_ Current : 0|16@1- (0.05,0) [-1638.4|1638.35] "A" Vector__XXX
+    ///  SG_ Temp : 16|8@1+ (0.5,-40) [-40|87.5] "degC" Vector__XXX
+    ///
+    /// BO_ 257 Counter: 8 ECU
+    ///  SG_ Total : 0|64@1+ (1,0) [0|0] "" Vector__XXX
+    /// "#;
+    /// let mut parser = Parser::new();
+    /// parser.add_from_str(dbc)?;
+    ///
+    /// // Current = -200 (0xFF38), Temp = 130
+    /// let raw = parser.decode_raw_msg(256, &[0x38, 0xFF, 130]).unwrap();
+    /// assert_eq!(raw["Current"], (-200, 0.05, 0.0));
+    /// assert_eq!(raw["Temp"], (130, 0.5, -40.0));
+    ///
+    /// // Unsigned 64-bit values above i64::MAX aren't wrapped
+    /// let raw = parser.decode_raw_msg(257, &[0xFF; 8]).unwrap();
+    /// assert_eq!(raw["Total"].0, u64::MAX as i128);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn decode_raw_msg(
+        &self,
+        msg_id: u32,
+        data: &[u8],
+    ) -> Option<std::collections::HashMap<String, (i128, f64, f64)>> {
+        let msg_entry = self.lookup(msg_id)?;
+        self.check_payload(msg_entry, data).ok()?;
+        let mux_value = self.decode_mux_value(&msg_entry.msg_def, data);
+
+        msg_entry
+            .msg_def
+            .signals
+            .iter()
+            .filter(|signal_def| Self::is_signal_active(msg_entry, signal_def, mux_value))
+            .map(|signal_def| {
+                let raw_value = self.extract_signal_raw(msg_entry, signal_def, data)?;
+                let raw_value_with_sign = Self::sign_extend(signal_def, raw_value);
+
+                Some((
+                    signal_def.name.clone(),
+                    (raw_value_with_sign, signal_def.factor, signal_def.offset),
+                ))
+            })
+            .collect()
+    }
+
+    /// Decodes a raw CAN message straight into per-signal time series.
+    ///
+    /// For each signal present in the frame (after multiplexer selection), a
+    /// `(timestamp, physical value)` point is pushed onto the back of the
+    /// signal's buffer in `series`, creating the buffer on first use. No
+    /// [`DecodedMessage`] is built, so enum labels are not resolved. Signals
+    /// that don't fit in the payload are skipped, as are non-finite values
+    /// unless the [`NonFinitePolicy`] is `Propagate`.
+    ///
+    /// # Arguments
+    ///
+    /// * `msg_id` - The CAN message identifier
+    /// * `data` - The raw message data bytes
+    /// * `timestamp` - The timestamp of the frame, in the caller's time base
+    /// * `series` - Buffers keyed by signal name to append the points to
+    ///
+    /// # Returns
+    ///
+    /// The number of points appended, or `None` under the same conditions as
+    /// [`decode_each`](Parser::decode_each).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use can_decode::Parser;
+    /// use std::collections::{HashMap, VecDeque};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let dbc = r#"
+    /// VERSION ""
+    ///
+    /// BU_: ECU
+    ///
+    /// BO_ 256 Motor: 2 ECU
+    ///  SG_ Speed : 0|8@1+ (10,0) [0|2550] "rpm" Vector__XXX
+    ///  SG_ Temp : 8|8@1+ (1,-40) [-40|215] "degC" Vector__XXX
+    /// "#;
+    /// let mut parser = Parser::new();
+    /// parser.add_from_str(dbc)?;
+    ///
+    /// let mut series: HashMap<String, VecDeque<(f64, f64)>> = HashMap::new();
+    /// assert_eq!(parser.decode_into_series(256, &[100, 60], 0.0, &mut series), Some(2));
+    /// assert_eq!(parser.decode_into_series(256, &[120, 61], 0.01, &mut series), Some(2));
+    ///
+    /// assert_eq!(series["Speed"], [(0.0, 1000.0), (0.01, 1200.0)]);
+    /// assert_eq!(series["Temp"], [(0.0, 20.0), (0.01, 21.0)]);
+    ///
+    /// // Unknown IDs append nothing
+    /// assert_eq!(parser.decode_into_series(0x123, &[0, 0], 0.02, &mut series), None);
+    /// assert_eq!(series["Speed"].len(), 2);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn decode_into_series(
+        &self,
+        msg_id: u32,
+        data: &[u8],
+        timestamp: f64,
+        series: &mut std::collections::HashMap<String, std::collections::VecDeque<(f64, f64)>>,
+    ) -> Option<usize> {
+        self.decode_each(msg_id, data, |name, value| {
+            let point = (timestamp, value);
+
+            // Only allocate a key the first time a signal is seen
+            match series.get_mut(name) {
+                Some(buffer) => buffer.push_back(point),
+                None => {
+                    series.insert(name.to_string(), [point].into());
+                }
+            }
+        })
+    }
+
+    /// Decodes a raw CAN message, passing each physical value to a callback.
+    ///
+    /// This is the allocation-free building block for writing decoded values
+    /// straight into another representation, such as a FlatBuffers or Cap'n
+    /// Proto builder, without going through a [`DecodedMessage`]. Enum labels
+    /// are not resolved. Signals that don't fit in the payload are skipped, as
+    /// are non-finite values unless the [`NonFinitePolicy`] is `Propagate`.
+    ///
+    /// # Arguments
+    ///
+    /// * `msg_id` - The CAN message identifier
+    /// * `data` - The raw message data bytes
+    /// * `visit` - Called with the name and physical value of each decoded signal,
+    ///   in DBC declaration order
+    ///
+    /// # Returns
+    ///
+    /// The number of signals visited, or `None` if the message ID is not known,
+    /// the payload fails the [`set_max_data_len`](Parser::set_max_data_len) or
+    /// [`set_strict_dlc`](Parser::set_strict_dlc) checks, or
+    /// [`UnknownMuxPolicy::Error`] rejects the multiplexor value. No signal is
+    /// visited in these cases.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use can_decode::{Parser, UnknownMuxPolicy};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut parser = Parser::new();
+    /// parser.add_from_str(
+    ///     r#"
+    /// BO_ 256 Motor: 4 ECU
+    ///  SG_ Speed : 0|16@1+ (0.5,0) [0|0] "rpm" Vector__XXX
+    ///  SG_ Temp : 16|8@1- (1,-40) [0|0] "degC" Vector__XXX
+    /// "#,
+    /// )?;
+    ///
+    /// let mut total = 0.0;
+    /// let visited = parser.decode_each(256, &[0x10, 0x00, 0x64, 0x00], |_name, value| {
+    ///     total += value;
+    /// });
+    ///
+    /// assert_eq!(visited, Some(2));
+    /// assert_eq!(total, 8.0 + 60.0);
+    ///
+    /// // Frames whose multiplexor selects no defined group can be rejected
+    /// parser.add_from_str(
+    ///     r#"
+    /// BO_ 257 Cells: 2 BMS
+    ///  SG_ Page M : 0|8@1+ (1,0) [0|0] "" Vector__XXX
+    ///  SG_ Voltage m0 : 8|8@1+ (0.1,0) [0|0] "V" Vector__XXX
+    /// "#,
+    /// )?;
+    /// parser.set_unknown_mux_policy(UnknownMuxPolicy::Error);
+    /// let mut visited = 0;
+    /// assert_eq!(parser.decode_each(257, &[0, 33], |_, _| visited += 1), Some(2));
+    /// assert_eq!(parser.decode_each(257, &[7, 33], |_, _| visited += 1), None);
+    /// assert_eq!(visited, 2);
+    /// assert!(parser.decode_msg_stack::<4>(257, &[7, 33]).is_none());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn decode_each(
+        &self,
+        msg_id: u32,
+        data: &[u8],
+        mut visit: impl FnMut(&str, f64),
+    ) -> Option<usize> {
+        let msg_entry = self.lookup(msg_id)?;
+        self.check_payload(msg_entry, data).ok()?;
+        self.visit_signals(msg_entry, data, |signal_def, value| {
+            visit(&signal_def.name, value)
+        })
+    }
+
+    /// Passes the definition and physical value of each decoded signal to
+    /// `visit`, as described for [`decode_each`](Parser::decode_each).
+    ///
+    /// # Returns
+    ///
+    /// The number of signals visited, or `None` without visiting any signal if
+    /// [`UnknownMuxPolicy::Error`] rejects the frame's multiplexor value.
+    fn visit_signals<'a>(
+        &self,
+        msg_entry: &'a MsgEntry,
+        data: &[u8],
+        mut visit: impl FnMut(&'a can_dbc::Signal, f64),
+    ) -> Option<usize> {
+        let mux_value = self.decode_mux_value(&msg_entry.msg_def, data);
+        if let Some((signal, mux_value)) = self.rejected_mux_value(&msg_entry.msg_def, mux_value) {
+            log::error!(
+                "Multiplexor {} of message {} has undefined value {}",
+                signal,
+                msg_entry.msg_def.name,
+                mux_value
+            );
+            return None;
+        }
+
+        let mut visited = 0;
+        for signal_def in &msg_entry.msg_def.signals {
+            if !Self::is_signal_active(msg_entry, signal_def, mux_value) {
+                continue;
+            }
+            let Some(raw_value) = self.extract_signal_raw(msg_entry, signal_def, data) else {
+                continue;
+            };
+
+            let signal_meta = msg_entry.signal_meta.get(&signal_def.name);
+            let value = Self::physical_value(signal_def, signal_meta, raw_value);
+            if !value.is_finite() && self.non_finite_policy != NonFinitePolicy::Propagate {
+                continue;
+            }
+
+            visit(signal_def, value);
+            visited += 1;
+        }
+
+        Some(visited)
+    }
+
+    /// Decodes a raw CAN message into a fixed-size array on the stack.
+    ///
+    /// Built on [`decode_each`](Parser::decode_each), so no heap allocation
+    /// takes place: signal names are borrowed from the parser, and enum labels
+    /// are not resolved. This is meant for constrained targets that decode into
+    /// a preallocated, bounded buffer.
+    ///
+    /// # Arguments
+    ///
+    /// * `msg_id` - The CAN message identifier
+    /// * `data` - The raw message data bytes
+    ///
+    /// # Returns
+    ///
+    /// An array whose first `len` elements are the name and physical value of
+    /// each decoded signal in DBC declaration order, along with `len`. The
+    /// remaining elements are `("", 0.0)`. Returns `None` under the same
+    /// conditions as [`decode_each`](Parser::decode_each), or if more than `N`
+    /// signals were decoded.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use can_decode::Parser;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut parser = Parser::new();
+    /// parser.add_from_str(
+    ///     r#"
+    /// BO_ 256 Motor: 4 ECU
+    ///  SG_ Speed : 0|16@1+ (0.5,0) [0|0] "rpm" Vector__XXX
+    ///  SG_ Temp : 16|8@1- (1,-40) [0|0] "degC" Vector__XXX
+    /// "#,
+    /// )?;
+    /// let data = [0x10, 0x00, 0x64, 0x00];
+    ///
+    /// let (signals, len) = parser.decode_msg_stack::<4>(256, &data).unwrap();
+    /// assert_eq!(&signals[..len], [("Speed", 8.0), ("Temp", 60.0)]);
+    ///
+    /// assert!(parser.decode_msg_stack::<1>(256, &data).is_none());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn decode_msg_stack<const N: usize>(
+        &self,
+        msg_id: u32,
+        data: &[u8],
+    ) -> Option<([(&str, f64); N], usize)> {
+        let msg_entry = self.lookup(msg_id)?;
+        self.check_payload(msg_entry, data).ok()?;
+        let mut signals = [("", 0.0); N];
+        let mut len = 0;
+
+        let visited = self.visit_signals(msg_entry, data, |signal_def, value| {
+            if let Some(slot) = signals.get_mut(len) {
+                *slot = (signal_def.name.as_str(), value);
+                len += 1;
+            }
+        })?;
+
+        if visited > N {
+            log::warn!(
+                "Message {} decoded to {} signals, more than {}",
+                msg_entry.msg_def.name,
+                visited,
+                N
+            );
+            return None;
+        }
+        Some((signals, len))
+    }
+
+    /// Decodes a raw CAN message, decoding at most `max_signals` signals.
+    ///
+    /// Signals are decoded in DBC declaration order (after multiplexer selection),
+    /// and decoding stops once `max_signals` signals have been decoded. This bounds
+    /// the work done per frame for messages with a pathological number of signals.
+    ///
+    /// # Arguments
+    ///
+    /// * `msg_id` - The CAN message identifier
+    /// * `data` - The raw message data bytes
+    /// * `max_signals` - The maximum number of signals to decode
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some((DecodedMessage, truncated))` if the message ID is known, where
+    /// `truncated` is `true` if some signals were left out because of the cap, or
+    /// `None` under the same conditions as [`decode_msg`](Parser::decode_msg).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use can_decode::Parser;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let dbc = r#"
+    /// VERSION ""
+    ///
+    /// BU_: ECU
+    ///
+    /// BO_ 256 Status: 4 ECU
+    ///  SG_ A : 0|8@1+ (1,0) [0|255] "" Vector__XXX
+    ///  SG_ B : 8|8@1+ (1,0) [0|255] "" Vector__XXX
+    ///  SG_ C : 16|8@1+ (1,0) [0|255] "" Vector__XXX
+    ///  SG_ D : 24|8@1+ (1,0) [0|255] "" Vector__XXX
+    /// "#;
+    /// let mut parser = Parser::new();
+    /// parser.add_from_str(dbc)?;
+    ///
+    /// let data = [1, 2, 3, 4];
+    /// let (decoded, truncated) = parser.decode_msg_capped(256, &data, 2).unwrap();
+    /// assert!(truncated);
+    /// let names: Vec<&str> = decoded.signals.keys().map(|k| k.as_str()).collect();
+    /// assert_eq!(names, ["A", "B"]);
+    ///
+    /// // A cap of at least the signal count decodes everything
+    /// let (decoded, truncated) = parser.decode_msg_capped(256, &data, 4).unwrap();
+    /// assert!(!truncated);
+    /// assert_eq!(decoded.signals.len(), 4);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn decode_msg_capped(
+        &self,
+        msg_id: u32,
+        data: &[u8],
+        max_signals: usize,
+    ) -> Option<(DecodedMessage, bool)> {
+        let mut decoded_count = 0;
+        let mut truncated = false;
+
+        let decoded = self.decode_msg_where(msg_id, data, |_idx, _signal_def| {
+            if decoded_count >= max_signals {
+                truncated = true;
+                return false;
+            }
+            decoded_count += 1;
+            true
+        })?;
+
+        Some((decoded, truncated))
+    }
+
+    /// Decodes a raw CAN message, reporting every signal that couldn't be decoded.
+    ///
+    /// Unlike [`decode_msg`](Parser::decode_msg), a signal that fails to decode
+    /// never causes the whole message to be dropped. Instead, the signal is left
+    /// out of the decoded message and the reason is added to the returned error
+    /// list. Multiplexed signals that don't fit in the payload are reported as
+    /// [`SignalDecodeError::MuxSignalOutOfFrame`], so short multiplexed groups
+    /// can be told apart from a generally truncated frame.
+    ///
+    /// # Arguments
+    ///
+    /// * `msg_id` - The CAN message identifier
+    /// * `data` - The raw message data bytes
+    ///
+    /// # Returns
+    ///
+    /// The decoded message together with the per-signal errors, or `None` if
+    /// the message ID is not known.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use can_decode::{Parser, SignalDecodeError};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let dbc = r#"
+    /// VERSION ""
+    ///
+    /// BU_: ECU
+    ///
+    /// BO_ 256 Status: 4 ECU
+    ///  SG_ Mux M : 0|8@1+ (1,0) [0|255] "" Vector__XXX
+    ///  SG_ Temp m0 : 8|8@1+ (1,-40) [-40|215] "degC" Vector__XXX
+    ///  SG_ Pressure m1 : 8|24@1+ (1,0) [0|16777215] "Pa" Vector__XXX
+    /// "#;
+    /// let mut parser = Parser::new();
+    /// parser.add_from_str(dbc)?;
+    ///
+    /// // Group 1 is sent in a 2-byte frame, but Pressure needs 4 bytes
+    /// let (decoded, errors) = parser.decode_msg_detailed(256, &[0x01, 0x10]).unwrap();
+    /// assert!(decoded.signals.contains_key("Mux"));
+    /// assert!(!decoded.signals.contains_key("Pressure"));
+    /// assert_eq!(
+    ///     errors,
+    ///     [SignalDecodeError::MuxSignalOutOfFrame {
+    ///         signal: "Pressure".to_string(),
+    ///         mux_value: 1,
+    ///         data_len: 2,
+    ///     }]
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn decode_msg_detailed(
+        &self,
+        msg_id: u32,
+        data: &[u8],
+    ) -> Option<(DecodedMessage, Vec<SignalDecodeError>)> {
+        let msg_entry = self.lookup(msg_id)?;
+        self.check_payload(msg_entry, data).ok()?;
+        let mut errors = Vec::new();
+        let decoded =
+            self.decode_msg_inner(msg_entry, data, None, Some(&mut errors), |_idx, _def| true)?;
+        Some((decoded, errors))
+    }
+
+    /// Decodes a raw CAN message and classifies each signal by health.
+    ///
+    /// Signals are decoded as by [`decode_msg_detailed`](Parser::decode_msg_detailed)
+    /// and grouped into three collections: signals within their DBC `[min|max]`
+    /// range (good), signals outside of it (suspicious), and signals that
+    /// couldn't be decoded (failed). Signals with an unspecified `[0|0]` range
+    /// and composite signals are always considered good.
+    ///
+    /// # Arguments
+    ///
+    /// * `msg_id` - The CAN message identifier
+    /// * `data` - The raw message data bytes
+    ///
+    /// # Returns
+    ///
+    /// The classified signals, or `None` if the message ID is not known.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use can_decode::Parser;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let dbc = r#"
+    /// VERSION ""
+    ///
+    /// BU_: ECU
+    ///
+    /// BO_ 256 Battery: 4 ECU
+    ///  SG_ SoC : 0|8@1+ (1,0) [0|100] "%" Vector__XXX
+    ///  SG_ Temp : 8|8@1+ (1,-40) [-40|85] "degC" Vector__XXX
+    ///  SG_ Current : 16|16@1- (0.1,0) [-500|500] "A" Vector__XXX
+    /// "#;
+    /// let mut parser = Parser::new();
+    /// parser.add_from_str(dbc)?;
+    ///
+    /// // SoC = 80 %, Temp = 200 degC, and Current is cut off
+    /// let triaged = parser.decode_msg_triaged(256, &[80, 240]).unwrap();
+    /// assert!(triaged.good.contains_key("SoC"));
+    /// assert!(triaged.suspicious.contains_key("Temp"));
+    /// assert_eq!(triaged.failed.len(), 1);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn decode_msg_triaged(&self, msg_id: u32, data: &[u8]) -> Option<TriagedDecode> {
+        let msg_def = &self.lookup(msg_id)?.msg_def;
+        let (decoded, failed) = self.decode_msg_detailed(msg_id, data)?;
+
+        let mut triaged = TriagedDecode {
+            failed,
+            ..Default::default()
+        };
+        for (name, signal) in decoded.signals {
+            let in_range = msg_def
+                .signals
+                .iter()
+                .find(|s| s.name == name)
+                .is_none_or(|signal_def| in_dbc_range(signal_def, signal.value.physical));
+            if in_range {
+                triaged.good.insert(name, signal);
+            } else {
+                triaged.suspicious.insert(name, signal);
+            }
+        }
+
+        Some(triaged)
+    }
+
+    /// Registers a custom conversion from a signal's raw value to its physical value.
+    ///
+    /// The transform replaces the linear `raw * factor + offset` scaling from the
+    /// DBC, which allows lookup tables, piecewise-linear curves, or polynomial
+    /// sensor corrections. It receives the sign-extended raw value as an `i128`,
+    /// so unsigned 64-bit signals keep their full range. Transforms don't apply
+    /// to IEEE float/double signals, and are not used for encoding. Signals
+    /// decoded with a transform have [`DecodedSignal::transformed`] set.
+    ///
+    /// # Arguments
+    ///
+    /// * `msg_id` - The CAN message identifier
+    /// * `signal_name` - The name of the signal as defined in the DBC file
+    /// * `transform` - The raw-to-physical conversion
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CanDecodeError::UnknownMessage`] or
+    /// [`CanDecodeError::UnknownSignal`] if the message or signal is unknown.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use can_decode::Parser;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let dbc = r#"
+    /// VERSION ""
+    ///
+    /// BU_: ECU
+    ///
+    /// BO_ 256 Sensors: 1 ECU
+    ///  SG_ Thermistor : 0|8@1+ (1,0) [0|255] "degC" Vector__XXX
+    ///
+    /// BO_ 257 Odometer: 8 ECU
+    ///  SG_ Distance : 0|64@1+ (1,0) [0|0] "mm" Vector__XXX
+    /// "#;
+    /// let mut parser = Parser::new();
+    /// parser.add_from_str(dbc)?;
+    ///
+    /// // Piecewise-linear calibration curve
+    /// let curve = [(0.0, -40.0), (128.0, 25.0), (255.0, 150.0)];
+    /// parser.set_transform(
+    ///     256,
+    ///     "Thermistor",
+    ///     Box::new(move |raw| {
+    ///         let x = raw as f64;
+    ///         let segment = curve.windows(2).find(|w| x <= w[1].0).unwrap_or(&curve[1..]);
+    ///         let ((x0, y0), (x1, y1)) = (segment[0], segment[1]);
+    ///         y0 + (x - x0) * (y1 - y0) / (x1 - x0)
+    ///     }),
+    /// )?;
+    ///
+    /// let decoded = parser.decode_msg(256, &[128]).unwrap();
+    /// assert_eq!(decoded.signals["Thermistor"].value.physical, 25.0);
+    ///
+    /// // Raw values above i64::MAX reach the transform unchanged
+    /// parser.set_transform(257, "Distance", Box::new(|raw| (raw / 1_000_000) as f64))?;
+    /// let decoded = parser.decode_msg(257, &[0xFF; 8]).unwrap();
+    /// assert_eq!(decoded.signals["Distance"].value.physical, 18_446_744_073_709.0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_transform(
+        &mut self,
+        msg_id: u32,
+        signal_name: &str,
+        transform: Box<dyn Fn(i128) -> f64 + Send + Sync>,
+    ) -> Result<(), CanDecodeError> {
+        self.signal_meta_mut(msg_id, signal_name)?.transform =
+            Some(SignalTransform(transform.into()));
+        Ok(())
+    }
+
+    /// Marks a signal as transmitted with its bits in reverse order.
+    ///
+    /// Some devices send a field with its bit order reversed, so the first bit
+    /// on the wire is the value's MSB instead of its LSB. For such signals the
+    /// `size` extracted bits are reversed before sign extension, enum lookup and
+    /// scaling are applied, and encoding reverses them again before packing.
+    ///
+    /// # Arguments
+    ///
+    /// * `msg_id` - The CAN message identifier
+    /// * `signal_name` - The name of the signal as defined in the DBC file
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CanDecodeError::UnknownMessage`] or
+    /// [`CanDecodeError::UnknownSignal`] if the message or signal is unknown.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use can_decode::Parser;
+    /// use std::collections::HashMap;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let dbc = r#"
+    /// VERSION ""
+    ///
+    /// BU_: ECU
+    ///
+    /// BO_ 256 Device: 1 ECU
+    ///  SG_ Level : 0|4@1+ (1,0) [0|15] "" Vector__XXX
+    /// "#;
+    /// let mut parser = Parser::new();
+    /// parser.add_from_str(dbc)?;
+    /// parser.set_bit_reverse(256, "Level")?;
+    ///
+    /// let decoded = parser.decode_msg(256, &[0b0001]).unwrap();
+    /// assert_eq!(decoded.signals["Level"].value.raw, Some(0b1000));
+    ///
+    /// // Encoding reverses the bits again, so values round-trip
+    /// let values = HashMap::from([("Level".to_string(), 3.0)]);
+    /// let data = parser.encode_msg(256, &values).unwrap();
+    /// assert_eq!(data, [0b1100]);
+    /// assert_eq!(parser.decode_msg(256, &data).unwrap().signals["Level"].value.physical, 3.0);
+    /// assert_eq!(parser.frame(256)?.set("Level", 3.0)?.build(), data);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_bit_reverse(
+        &mut self,
+        msg_id: u32,
+        signal_name: &str,
+    ) -> Result<(), CanDecodeError> {
+        self.signal_meta_mut(msg_id, signal_name)?.bit_reverse = true;
+        Ok(())
+    }
+
+    /// Sets the raw value that marks a signal as not available.
+    ///
+    /// Decoded signals whose raw bits equal `raw` have
+    /// [`DecodedSignal::is_sna`] set, whether or not
+    /// [`set_sna_detection`](Parser::set_sna_detection) is enabled. This
+    /// replaces the detection heuristics for the signal, e.g. for signals that
+    /// use their maximum value as a regular value and another value as the
+    /// sentinel.
+    ///
+    /// # Arguments
+    ///
+    /// * `msg_id` - The CAN message identifier
+    /// * `signal_name` - The name of the signal as defined in the DBC file
+    /// * `raw` - The unscaled sentinel bits, before sign extension
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CanDecodeError::UnknownMessage`] or
+    /// [`CanDecodeError::UnknownSignal`] if the message or signal is unknown.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use can_decode::Parser;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let dbc = r#"
+    /// VERSION ""
+    ///
+    /// BU_: ECU
+    ///
+    /// BO_ 256 Battery: 1 ECU
+    ///  SG_ SoC : 0|8@1+ (0.5,0) [0|100] "%" Vector__XXX
+    /// "#;
+    /// let mut parser = Parser::new();
+    /// parser.add_from_str(dbc)?;
+    /// parser.set_sna_value(256, "SoC", 0xFE)?;
+    ///
+    /// assert!(parser.decode_msg(256, &[0xFE]).unwrap().signals["SoC"].is_sna);
+    /// assert!(!parser.decode_msg(256, &[0xFF]).unwrap().signals["SoC"].is_sna);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_sna_value(
+        &mut self,
+        msg_id: u32,
+        signal_name: &str,
+        raw: u64,
+    ) -> Result<(), CanDecodeError> {
+        self.signal_meta_mut(msg_id, signal_name)?.sna_value = Some(raw);
+        Ok(())
+    }
+
+    /// Excludes a signal from decoding.
+    ///
+    /// Disabled signals are skipped entirely, as if they weren't defined. This
+    /// works around a broken signal definition at runtime without editing the
+    /// DBC or dropping the whole message. Use
+    /// [`enable_signal`](Parser::enable_signal) to decode the signal again.
+    ///
+    /// # Arguments
+    ///
+    /// * `msg_id` - The CAN message identifier
+    /// * `signal_name` - The name of the signal as defined in the DBC file
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CanDecodeError::UnknownMessage`] or
+    /// [`CanDecodeError::UnknownSignal`] if the message or signal is unknown.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use can_decode::{CanDecodeError, Parser};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let dbc = r#"
+    /// VERSION ""
+    ///
+    /// BU_: ECU
+    ///
+    /// BO_ 256 Motor: 2 ECU
+    ///  SG_ Speed : 0|8@1+ (1,0) [0|255] "" Vector__XXX
+    ///  SG_ Garbage : 8|8@1+ (1,0) [0|255] "" Vector__XXX
+    /// "#;
+    /// let mut parser = Parser::new();
+    /// parser.add_from_str(dbc)?;
+    ///
+    /// parser.disable_signal(256, "Garbage")?;
+    /// let decoded = parser.decode_msg(256, &[10, 20]).unwrap();
+    /// assert!(decoded.signals.contains_key("Speed"));
+    /// assert!(!decoded.signals.contains_key("Garbage"));
+    ///
+    /// parser.enable_signal(256, "Garbage")?;
+    /// let decoded = parser.decode_msg(256, &[10, 20]).unwrap();
+    /// assert!(decoded.signals.contains_key("Garbage"));
+    ///
+    /// let err = parser.disable_signal(256, "Typo").unwrap_err();
+    /// assert!(matches!(err, CanDecodeError::UnknownSignal { msg_id: 256, signal } if signal == "Typo"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn disable_signal(&mut self, msg_id: u32, signal_name: &str) -> Result<(), CanDecodeError> {
+        self.signal_meta_mut(msg_id, signal_name)?.disabled = true;
+        Ok(())
+    }
+
+    /// Re-enables decoding of a signal disabled with
+    /// [`disable_signal`](Parser::disable_signal).
+    ///
+    /// # Arguments
+    ///
+    /// * `msg_id` - The CAN message identifier
+    /// * `signal_name` - The name of the signal as defined in the DBC file
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CanDecodeError::UnknownMessage`] or
+    /// [`CanDecodeError::UnknownSignal`] if the message or signal is unknown.
+    pub fn enable_signal(&mut self, msg_id: u32, signal_name: &str) -> Result<(), CanDecodeError> {
+        self.signal_meta_mut(msg_id, signal_name)?.disabled = false;
+        Ok(())
+    }
+
+    /// Returns the metadata of a signal for modification, creating it if needed.
+    ///
+    /// Since the caller changes how the signal decodes, the decode cache is
+    /// emptied.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CanDecodeError::UnknownMessage`] or
+    /// [`CanDecodeError::UnknownSignal`] if the message or signal is unknown.
+    fn signal_meta_mut(
+        &mut self,
+        msg_id: u32,
+        signal_name: &str,
+    ) -> Result<&mut SignalMeta, CanDecodeError> {
+        self.decode_cache.invalidate();
+
+        let msg_entry = self
+            .msg_entries
+            .get_mut(&msg_id)
+            .ok_or(CanDecodeError::UnknownMessage(msg_id))?;
+
+        if !msg_entry
+            .msg_def
+            .signals
+            .iter()
+            .any(|s| s.name == signal_name)
+        {
+            return Err(CanDecodeError::UnknownSignal {
+                msg_id,
+                signal: signal_name.to_string(),
+            });
+        }
+
+        Ok(msg_entry
+            .signal_meta
+            .entry(signal_name.to_string())
+            .or_default())
+    }
+
+    /// Registers a derived signal assembled from the raw bits of other signals.
+    ///
+    /// Some DBCs split a logical value across several signals at non-adjacent
+    /// bit positions (e.g. separate high and low parts). After decoding a message,
+    /// the raw bits of each part are shifted left by the matching entry in
+    /// `shifts` and combined into one unscaled value, which is added to the
+    /// decoded signals under `name`. The composite is only produced when all of
+    /// its parts were decoded.
+    ///
+    /// # Arguments
+    ///
+    /// * `msg_id` - The CAN message identifier
+    /// * `name` - The name of the derived signal
+    /// * `parts` - The names of the source signals
+    /// * `shifts` - The left shift (in bits) applied to each part's raw value
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the message or any part is unknown, or if `parts` and
+    /// `shifts` have different lengths.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use can_decode::Parser;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let dbc = r#"
+    /// VERSION ""
+    ///
+    /// BU_: ECU
+    ///
+    /// BO_ 256 Odometer: 4 ECU
+    ///  SG_ OdoHigh : 0|8@1+ (1,0) [0|255] "" Vector__XXX
+    ///  SG_ Flags : 8|8@1+ (1,0) [0|255] "" Vector__XXX
+    ///  SG_ OdoLow : 16|16@1+ (1,0) [0|65535] "" Vector__XXX
+    /// "#;
+    /// let mut parser = Parser::new();
+    /// parser.add_from_str(dbc)?;
+    /// parser.add_composite_signal(256, "Odometer", &["OdoHigh", "OdoLow"], &[16, 0])?;
+    ///
+    /// let decoded = parser.decode_msg(256, &[0x01, 0xFF, 0x34, 0x12]).unwrap();
+    /// assert_eq!(decoded.signals["Odometer"].value.raw, Some(0x01_1234));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn add_composite_signal(
+        &mut self,
+        msg_id: u32,
+        name: &str,
+        parts: &[&str],
+        shifts: &[u32],
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        if parts.len() != shifts.len() {
+            return Err(format!(
+                "Composite signal '{}' has {} parts but {} shifts",
+                name,
+                parts.len(),
+                shifts.len()
+            )
+            .into());
+        }
+
+        let msg_entry = self
+            .msg_entries
+            .get_mut(&msg_id)
+            .ok_or(CanDecodeError::UnknownMessage(msg_id))?;
+
+        if let Some(missing) = parts
+            .iter()
+            .find(|part| !msg_entry.msg_def.signals.iter().any(|s| s.name == **part))
+        {
+            return Err(format!(
+                "Composite signal '{}' references unknown signal '{}' in message ID {:#X}",
+                name, missing, msg_id
+            )
+            .into());
+        }
+
+        msg_entry.composites.push(CompositeSignal {
+            name: name.to_string(),
+            parts: parts
+                .iter()
+                .zip(shifts)
+                .map(|(part, shift)| (part.to_string(), *shift))
+                .collect(),
+        });
+        self.decode_cache.invalidate();
+        Ok(())
+    }
+
+    /// Decodes a raw CAN message into signals keyed by their start bit.
+    ///
+    /// This is equivalent to [`decode_msg`](Parser::decode_msg), but addresses
+    /// signals by their DBC start bit instead of their name, which is useful when
+    /// signal names are unreliable or duplicated. Start bits are unique among the
+    /// signals present in a frame (after multiplexer selection). Composite
+    /// signals have no start bit and are not included.
+    ///
+    /// # Arguments
+    ///
+    /// * `msg_id` - The CAN message identifier
+    /// * `data` - The raw message data bytes
+    ///
+    /// # Returns
+    ///
+    /// Returns a map of start bit to decoded signal, or `None` under the same
+    /// conditions as [`decode_msg`](Parser::decode_msg).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use can_decode::Parser;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let dbc = r#"
+    /// VERSION ""
+    ///
+    /// BU_: ECU
+    ///
+    /// BO_ 256 Legacy: 2 ECU
+    ///  SG_ X : 0|8@1+ (1,0) [0|255] "" Vector__XXX
+    ///  SG_ X : 8|8@1+ (1,0) [0|255] "" Vector__XXX
+    /// "#;
+    /// let mut parser = Parser::new();
+    /// parser.add_from_str(dbc)?;
+    ///
+    /// // Both signals are named X, but their start bits tell them apart
+    /// let decoded = parser.decode_msg_by_bit(256, &[1, 2]).unwrap();
+    /// assert_eq!(decoded.len(), 2);
+    /// assert_eq!(decoded[&0].value.physical, 1.0);
+    /// assert_eq!(decoded[&8].value.physical, 2.0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn decode_msg_by_bit(
+        &self,
+        msg_id: u32,
+        data: &[u8],
+    ) -> Option<std::collections::HashMap<usize, DecodedSignal>> {
+        let msg_entry = self.lookup(msg_id)?;
+        self.check_payload(msg_entry, data).ok()?;
+
+        let msg_def = &msg_entry.msg_def;
+        let mux_value = self.decode_mux_value(msg_def, data);
+        if let Some((signal, mux_value)) = self.rejected_mux_value(msg_def, mux_value) {
+            log::error!(
+                "Multiplexor {} of message {} has undefined value {}",
+                signal,
+                msg_def.name,
+                mux_value
+            );
+            return None;
+        }
+
+        let mut decoded = std::collections::HashMap::with_capacity(msg_def.signals.len());
+        for signal_def in &msg_def.signals {
+            if !Self::is_signal_active(msg_entry, signal_def, mux_value) {
+                continue;
+            }
+
+            // Decode each definition on its own, since names may be duplicated
+            let Some(raw_value) = self.extract_signal_raw(msg_entry, signal_def, data) else {
+                if data.len() != msg_def.size as usize {
+                    continue;
+                }
+                log::error!(
+                    "Failed to decode signal {} from message {}",
+                    signal_def.name,
+                    msg_def.name
+                );
+                return None;
+            };
+            let signal = self.interpret_raw_value(msg_entry, signal_def, raw_value);
+            if !signal.value.physical.is_finite() {
+                match self.non_finite_policy {
+                    NonFinitePolicy::Propagate => {}
+                    NonFinitePolicy::Omit => continue,
+                    NonFinitePolicy::Error => {
+                        log::error!(
+                            "Non-finite value for signal {} from message {}",
+                            signal_def.name,
+                            msg_def.name
+                        );
+                        return None;
+                    }
+                }
+            }
+            decoded.insert(signal_def.start_bit as usize, signal);
+        }
+
+        Some(decoded)
+    }
+
+    /// Resolves a set of signal names into a reusable [`Allowlist`] for a message.
+    ///
+    /// The signal names are resolved to their definitions once, so that
+    /// [`decode_with_allowlist`](Parser::decode_with_allowlist) doesn't need to
+    /// look them up again for every frame. The allowlist is tied to the message
+    /// definition loaded at the time it was compiled; recompile it after loading
+    /// new definitions.
+    ///
+    /// # Arguments
+    ///
+    /// * `msg_id` - The CAN message identifier
+    /// * `signal_names` - The names of the signals to decode
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(Allowlist)`, or `None` if the message ID or any of the signal
+    /// names is not known.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use can_decode::Parser;
+    /// use std::path::Path;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let parser = Parser::from_dbc_file(Path::new("my_database.dbc"))?;
+    /// let allowlist = parser
+    ///     .compile_allowlist(0x123, &["EngineSpeed", "CoolantTemp"])
+    ///     .expect("unknown message or signal");
+    ///
+    /// let data = [0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC, 0xDE, 0xF0];
+    /// if let Some(decoded) = parser.decode_with_allowlist(&allowlist, &data) {
+    ///     println!("{:?}", decoded.signals);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn compile_allowlist(&self, msg_id: u32, signal_names: &[&str]) -> Option<Allowlist> {
+        let msg_def = &self.msg_entries.get(&msg_id)?.msg_def;
+
+        let mut allowed = vec![false; msg_def.signals.len()];
+        for name in signal_names {
+            let Some(idx) = msg_def.signals.iter().position(|s| s.name == *name) else {
+                log::warn!(
+                    "Allowlist signal '{}' not found in message ID {:#X}",
+                    name,
+                    msg_id
+                );
+                return None;
+            };
+            allowed[idx] = true;
+        }
+
+        Some(Allowlist { msg_id, allowed })
+    }
+
+    /// Decodes a raw CAN message, only including the signals in a compiled allowlist.
+    ///
+    /// # Arguments
+    ///
+    /// * `allowlist` - An allowlist from [`compile_allowlist`](Parser::compile_allowlist)
+    /// * `data` - The raw message data bytes
+    ///
+    /// # Returns
+    ///
+    /// Returns the decoded message containing only allowlisted signals that are
+    /// present in the frame, or `None` under the same conditions as
+    /// [`decode_msg`](Parser::decode_msg).
+    pub fn decode_with_allowlist(
+        &self,
+        allowlist: &Allowlist,
+        data: &[u8],
+    ) -> Option<DecodedMessage> {
+        self.decode_msg_where(allowlist.msg_id, data, |idx, _signal_def| {
+            allowlist.allowed.get(idx).copied().unwrap_or(false)
+        })
+    }
+
+    /// Decodes a single signal of a raw CAN message.
+    ///
+    /// Only the named signal's bits (and the multiplexor switch, for multiplexed
+    /// signals) are extracted, which is cheaper than
+    /// [`decode_msg`](Parser::decode_msg) when only one signal of a message
+    /// with many signals is needed. The result is the same as the signal's
+    /// entry in a full decode. Composite signals aren't supported, since they
+    /// depend on other signals.
+    ///
+    /// # Arguments
+    ///
+    /// * `msg_id` - The CAN message identifier
+    /// * `signal_name` - The name of the signal as defined in the DBC file
+    /// * `data` - The raw message data bytes
+    ///
+    /// # Returns
+    ///
+    /// The decoded signal, or `None` if the message or signal is unknown, the
+    /// signal isn't present in the frame (an inactive multiplexed or disabled
+    /// signal, or one beyond the end of `data`), or its value is non-finite and
+    /// the [`NonFinitePolicy`] isn't [`Propagate`](NonFinitePolicy::Propagate).
+    /// Like [`decode_msg`](Parser::decode_msg), it also returns `None` for
+    /// payloads rejected by [`set_max_data_len`](Parser::set_max_data_len) or
+    /// [`set_strict_dlc`](Parser::set_strict_dlc), and for undefined switch
+    /// values with [`UnknownMuxPolicy::Error`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use can_decode::{Parser, UnknownMuxPolicy};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let dbc = r#"
+    /// VERSION ""
+    ///
+    /// BU_: ECU
+    ///
+    /// BO_ 256 Vehicle: 8 ECU
+    ///  SG_ Mux M : 0|4@1+ (1,0) [0|15] "" Vector__XXX
+    ///  SG_ Speed : 8|16@1+ (0.01,0) [0|655.35] "km/h" Vector__XXX
+    ///  SG_ Temp m0 : 24|8@1+ (1,-40) [-40|215] "degC" Vector__XXX
+    ///  SG_ Voltage m1 : 24|16@1+ (0.001,0) [0|65.535] "V" Vector__XXX
+    /// "#;
+    /// let mut parser = Parser::new();
+    /// parser.add_from_str(dbc)?;
+    ///
+    /// let data = [0x00, 0x10, 0x27, 0x64, 0, 0, 0, 0];
+    /// let speed = parser.decode_signal_by_name(256, "Speed", &data).unwrap();
+    /// assert_eq!(speed.value.physical, 100.0);
+    /// assert_eq!(speed.unit, "km/h");
+    ///
+    /// // Mux = 0 selects Temp, not Voltage
+    /// assert_eq!(parser.decode_signal_by_name(256, "Temp", &data).unwrap().value.physical, 60.0);
+    /// assert!(parser.decode_signal_by_name(256, "Voltage", &data).is_none());
+    ///
+    /// // Frames that decode_msg rejects are rejected here too
+    /// parser.set_strict_dlc(true);
+    /// assert!(parser.decode_msg(256, &data[..4]).is_none());
+    /// assert!(parser.decode_signal_by_name(256, "Speed", &data[..4]).is_none());
+    /// parser.set_strict_dlc(false);
+    ///
+    /// parser.set_unknown_mux_policy(UnknownMuxPolicy::Error);
+    /// let undefined_mux = [0x07, 0x10, 0x27, 0x64, 0, 0, 0, 0];
+    /// assert!(parser.decode_msg(256, &undefined_mux).is_none());
+    /// assert!(parser.decode_signal_by_name(256, "Speed", &undefined_mux).is_none());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn decode_signal_by_name(
+        &self,
+        msg_id: u32,
+        signal_name: &str,
+        data: &[u8],
+    ) -> Option<DecodedSignal> {
+        let msg_entry = self.lookup(msg_id)?;
+        self.check_payload(msg_entry, data).ok()?;
+        let signal_def = msg_entry
+            .msg_def
+            .signals
+            .iter()
+            .find(|s| s.name == signal_name)?;
+
+        // The switch is only needed to select multiplexed signals, or to reject
+        // the whole frame for an undefined switch value like decode_msg does
+        let is_multiplexed = matches!(
+            signal_def.multiplexer_indicator,
+            can_dbc::MultiplexIndicator::MultiplexedSignal(_)
+                | can_dbc::MultiplexIndicator::MultiplexorAndMultiplexedSignal(_)
+        );
+        let mux_value = (is_multiplexed || self.unknown_mux_policy == UnknownMuxPolicy::Error)
+            .then(|| self.decode_mux_value(&msg_entry.msg_def, data))
+            .flatten();
+        if let Some((signal, mux_value)) = self.rejected_mux_value(&msg_entry.msg_def, mux_value) {
+            log::error!(
+                "Multiplexor {} of message {} has undefined value {}",
+                signal,
+                msg_entry.msg_def.name,
+                mux_value
+            );
+            return None;
+        }
+        if !Self::is_signal_active(msg_entry, signal_def, mux_value) {
+            return None;
+        }
+
+        let raw_value = self.extract_signal_raw(msg_entry, signal_def, data)?;
+        let decoded_signal = self.interpret_raw_value(msg_entry, signal_def, raw_value);
+        if !decoded_signal.value.physical.is_finite()
+            && self.non_finite_policy != NonFinitePolicy::Propagate
+        {
+            return None;
+        }
+        Some(decoded_signal)
+    }
+
+    /// Decodes a multiplexed message once for every multiplexer value defined in the DBC.
+    ///
+    /// This is a debugging aid for checking the multiplexer assignments of a
+    /// DBC: the payload is interpreted as if each multiplexed group were active,
+    /// regardless of the switch value actually present in the data. The
+    /// multiplexor switch itself is still decoded from the data.
+    ///
+    /// # Arguments
+    ///
+    /// * `msg_id` - The CAN message identifier
+    /// * `data` - The raw message data bytes
+    ///
+    /// # Returns
+    ///
+    /// A map from multiplexer value to the message decoded with that group
+    /// active, or `None` if the message ID is unknown. Groups that fail to
+    /// decode (e.g. because the payload is too short) are omitted. The map is
+    /// empty for messages without multiplexed signals.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use can_decode::Parser;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let dbc = r#"
+    /// VERSION ""
+    ///
+    /// BU_: ECU
+    ///
+    /// BO_ 256 Status: 2 ECU
+    ///  SG_ Mux M : 0|8@1+ (1,0) [0|255] "" Vector__XXX
+    ///  SG_ Temp m0 : 8|8@1+ (1,-40) [-40|215] "degC" Vector__XXX
+    ///  SG_ Speed m1 : 8|8@1+ (2,0) [0|510] "km/h" Vector__XXX
+    /// "#;
+    /// let mut parser = Parser::new();
+    /// parser.add_from_str(dbc)?;
+    ///
+    /// let groups = parser.decode_all_mux_groups(256, &[0x00, 50]).unwrap();
+    /// assert_eq!(groups[&0].signals["Temp"].value.physical, 10.0);
+    /// assert_eq!(groups[&1].signals["Speed"].value.physical, 100.0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn decode_all_mux_groups(
+        &self,
+        msg_id: u32,
+        data: &[u8],
+    ) -> Option<std::collections::HashMap<u64, DecodedMessage>> {
+        let msg_entry = self.lookup(msg_id)?;
+        self.check_payload(msg_entry, data).ok()?;
+
+        let mut mux_values: Vec<u64> = msg_entry
+            .msg_def
+            .signals
+            .iter()
+            .filter_map(|s| match s.multiplexer_indicator {
+                can_dbc::MultiplexIndicator::MultiplexedSignal(value)
+                | can_dbc::MultiplexIndicator::MultiplexorAndMultiplexedSignal(value) => {
+                    Some(value)
+                }
+                _ => None,
+            })
+            .collect();
+        mux_values.sort_unstable();
+        mux_values.dedup();
+
+        Some(
+            mux_values
+                .into_iter()
+                .filter_map(|mux_value| {
+                    let decoded = self.decode_msg_inner(
+                        msg_entry,
+                        data,
+                        Some(mux_value),
+                        None,
+                        |_idx, _def| true,
+                    )?;
+                    Some((mux_value, decoded))
+                })
+                .collect(),
+        )
+    }
+
+    /// Resolves a message ID to a handle for repeated decoding.
+    ///
+    /// # Arguments
+    ///
+    /// * `msg_id` - The CAN message identifier
+    ///
+    /// # Returns
+    ///
+    /// A handle to the message definition, or `None` if the message ID is not known.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use can_decode::Parser;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let dbc = r#"
+    /// VERSION ""
+    ///
+    /// BU_: ECU
+    ///
+    /// BO_ 256 Motor: 2 ECU
+    ///  SG_ Speed : 0|16@1+ (0.5,0) [0|32767.5] "rpm" Vector__XXX
+    /// "#;
+    /// let mut parser = Parser::new();
+    /// parser.add_from_str(dbc)?;
+    ///
+    /// let handle = parser.resolve(256).ok_or("unknown message")?;
+    /// assert_eq!(handle.msg_id(), 256);
+    /// assert!(parser.resolve(0x123).is_none());
+    ///
+    /// for data in [[0xE8, 0x03], [0xD0, 0x07]] {
+    ///     let decoded = parser.decode_with_handle(handle, &data).unwrap();
+    ///     let expected = parser.decode_msg(256, &data).unwrap();
+    ///     assert_eq!(format!("{decoded:?}"), format!("{expected:?}"));
+    /// }
+    /// let decoded = parser.decode_with_handle(handle, &[0xD0, 0x07]).unwrap();
+    /// assert_eq!(decoded.signals["Speed"].value.physical, 1000.0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn resolve(&self, msg_id: u32) -> Option<MsgHandle<'_>> {
+        self.msg_entries
+            .get(&msg_id)
+            .map(|entry| MsgHandle { entry })
+    }
+
+    /// Decodes a raw CAN message using a handle from [`resolve`](Parser::resolve).
+    ///
+    /// Behaves like [`decode_msg`](Parser::decode_msg), without looking up the
+    /// message definition.
+    ///
+    /// # Arguments
+    ///
+    /// * `handle` - The resolved message
+    /// * `data` - The raw message data bytes
+    ///
+    /// # Returns
+    ///
+    /// Returns the decoded message, or `None` if any signal failed to decode.
+    pub fn decode_with_handle(&self, handle: MsgHandle<'_>, data: &[u8]) -> Option<DecodedMessage> {
+        self.check_payload(handle.entry, data).ok()?;
+        self.decode_msg_inner(handle.entry, data, None, None, |_idx, _def| true)
+    }
+
+    /// Decodes a raw CAN message, only including active signals accepted by `keep`.
+    ///
+    /// `keep` is called with the signal's index in the message definition, in DBC
+    /// declaration order, for each signal that is present in the frame (after
+    /// multiplexer selection).
+    fn decode_msg_where(
+        &self,
+        msg_id: u32,
+        data: &[u8],
+        keep: impl FnMut(usize, &can_dbc::Signal) -> bool,
+    ) -> Option<DecodedMessage> {
+        let msg_entry = self.lookup(msg_id)?;
+        self.check_payload(msg_entry, data).ok()?;
+        self.decode_msg_inner(msg_entry, data, None, None, keep)
+    }
+
+    /// Checks a payload against the decode limits before any signal is extracted.
+    ///
+    /// Every decode method runs this check first: payloads longer than
+    /// [`set_max_data_len`](Parser::set_max_data_len) allows are rejected, and
+    /// with [`set_strict_dlc`](Parser::set_strict_dlc), so are payloads shorter
+    /// than the message size.
+    fn check_payload(&self, msg_entry: &MsgEntry, data: &[u8]) -> Result<(), FrameError> {
+        if data.len() > self.max_data_len {
+            log::warn!(
+                "Rejecting {}-byte payload for message {}: longer than {} bytes",
+                data.len(),
+                msg_entry.msg_def.name,
+                self.max_data_len
+            );
+            return Err(FrameError::PayloadTooLong {
+                max_len: self.max_data_len,
+                actual: data.len(),
+            });
+        }
+
+        let msg_size = msg_entry.msg_def.size as usize;
+        if self.strict_dlc && data.len() < msg_size {
+            log::warn!(
+                "Rejecting {}-byte payload for message {} of {} bytes",
+                data.len(),
+                msg_entry.msg_def.name,
+                msg_size
+            );
+            return Err(FrameError::LengthMismatch {
+                expected: msg_size,
+                actual: data.len(),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Decodes a raw CAN message of a known entry like [`decode_msg_where`](Parser::decode_msg_where).
+    ///
+    /// If `mux_override` is set, multiplexed signals are selected using that
+    /// value instead of the switch value decoded from the data. If `errors` is
+    /// set, signals that fail to decode are recorded there and skipped instead
+    /// of failing the whole message.
+    fn decode_msg_inner(
+        &self,
+        msg_entry: &MsgEntry,
+        data: &[u8],
+        mux_override: Option<u64>,
+        mut errors: Option<&mut Vec<SignalDecodeError>>,
+        mut keep: impl FnMut(usize, &can_dbc::Signal) -> bool,
+    ) -> Option<DecodedMessage> {
+        // For every signal in the message, decode it and add to the decoded message
+        let msg_id = msg_entry.msg_def.id.raw();
+        let is_extended = msg_entry.is_extended;
+        let tx_node = match &msg_entry.msg_def.transmitter {
+            can_dbc::Transmitter::NodeName(name) => name.clone(),
+            can_dbc::Transmitter::VectorXXX => "Unknown".to_string(),
+        };
+        let msg_size = msg_entry.msg_def.size as usize;
+        let dlc_mismatch = (data.len() != msg_size).then_some((msg_size, data.len()));
+
+        // Reserve space for every signal up front so the map never regrows mid-decode
+        let mut decoded_signals = SignalMap::with_capacity(msg_entry.msg_def.signals.len());
+        let mux_value = mux_override.or_else(|| self.decode_mux_value(&msg_entry.msg_def, data));
+
+        if let Some((signal, mux_value)) = self.rejected_mux_value(&msg_entry.msg_def, mux_value) {
+            let Some(errors) = errors.as_deref_mut() else {
+                log::error!(
+                    "Multiplexor {} of message {} has undefined value {}",
+                    signal,
+                    msg_entry.msg_def.name,
+                    mux_value
+                );
+                return None;
+            };
+            errors.push(SignalDecodeError::UnknownMuxValue { signal, mux_value });
+        }
+
+        for (idx, signal_def) in msg_entry.msg_def.signals.iter().enumerate() {
+            // Skip multiplexed signals that aren't selected by the current switch value,
+            // as well as any signals the caller filtered out
+            if !Self::is_signal_active(msg_entry, signal_def, mux_value) || !keep(idx, signal_def) {
+                continue;
+            }
+
+            let decoded_signal = self
+                .extract_signal_raw(msg_entry, signal_def, data)
+                .map(|raw_value| self.interpret_raw_value(msg_entry, signal_def, raw_value));
+            match decoded_signal {
+                Some(decoded_signal) if !decoded_signal.value.physical.is_finite() => {
+                    match self.non_finite_policy {
+                        NonFinitePolicy::Propagate => {
+                            decoded_signals.insert(decoded_signal.name.to_string(), decoded_signal);
+                        }
+                        NonFinitePolicy::Omit => {}
+                        NonFinitePolicy::Error => {
+                            let Some(errors) = errors.as_deref_mut() else {
+                                log::error!(
+                                    "Non-finite value for signal {} from message {}",
+                                    signal_def.name,
+                                    msg_entry.msg_def.name
+                                );
+                                return None;
+                            };
+                            errors.push(SignalDecodeError::NonFinite {
+                                signal: decoded_signal.name,
+                            });
+                        }
+                    }
+                }
+                Some(decoded_signal) => {
+                    decoded_signals.insert(decoded_signal.name.to_string(), decoded_signal);
+                }
+                None if errors.is_some() => {
+                    let signal = signal_def.name.clone();
+                    let data_len = data.len();
+                    let error = match (signal_def.multiplexer_indicator, mux_value) {
+                        (
+                            can_dbc::MultiplexIndicator::MultiplexedSignal(_)
+                            | can_dbc::MultiplexIndicator::MultiplexorAndMultiplexedSignal(_),
+                            Some(mux_value),
+                        ) => SignalDecodeError::MuxSignalOutOfFrame {
+                            signal,
+                            mux_value,
+                            data_len,
+                        },
+                        _ => SignalDecodeError::OutOfFrame { signal, data_len },
+                    };
+                    if let Some(errors) = errors.as_deref_mut() {
+                        errors.push(error);
+                    }
+                }
+                // With a length mismatch, skip signals that don't fit and decode the rest
+                None if dlc_mismatch.is_some() => {
+                    log::warn!(
+                        "Skipping signal {} from message {}: outside of {}-byte payload",
+                        signal_def.name,
+                        msg_entry.msg_def.name,
+                        data.len()
+                    );
+                }
+                None => {
+                    log::error!(
+                        "Failed to decode signal {} from message {}",
+                        signal_def.name,
+                        msg_entry.msg_def.name
+                    );
+                    return None;
+                }
+            }
+        }
+
+        for composite in &msg_entry.composites {
+            if let Some(signal) = Self::decode_composite(msg_entry, composite, &decoded_signals) {
+                decoded_signals.insert(composite.name.clone(), signal);
+            }
+        }
+
+        Some(DecodedMessage {
+            name: msg_entry.msg_def.name.clone(),
+            msg_id,
+            is_extended,
+            tx_node,
+            signals: decoded_signals,
+            dlc_mismatch,
+        })
+    }
+
+    /// Assembles a composite signal from its already-decoded parts.
+    ///
+    /// Each part contributes its raw bits (without sign extension), shifted left
+    /// by the part's shift amount. The result is unscaled and has no unit.
+    ///
+    /// # Returns
+    ///
+    /// The composite signal, or `None` if any part wasn't decoded (e.g. an
+    /// inactive multiplexed signal) or has no integer raw value.
+    fn decode_composite(
+        msg_entry: &MsgEntry,
+        composite: &CompositeSignal,
+        decoded_signals: &SignalMap,
+    ) -> Option<DecodedSignal> {
+        let mut raw_value: i128 = 0;
+
+        for (part_name, shift) in &composite.parts {
+            let part_raw = decoded_signals.get(part_name)?.value.raw?;
+            let part_size = msg_entry
+                .msg_def
+                .signals
+                .iter()
+                .find(|s| s.name == *part_name)?
+                .size;
+
+            let part_bits = part_raw & low_bits_mask!((part_size as usize).min(64), u64) as i128;
+            raw_value |= part_bits.checked_shl(*shift)?;
+        }
+
+        Some(DecodedSignal {
+            name: composite.name.clone(),
+            value: DecodedSignalValue::new_integer_backed_numeric(raw_value as f64, raw_value),
+            unit: String::new(),
+            kind: SignalKind::Unsigned,
+            transformed: false,
+            factor: 1.0,
+            offset: 0.0,
+            min: None,
+            max: None,
+            in_range: None,
+            is_sna: false,
+            start_bit: 0,
+            size: 0,
+            byte_order: can_dbc::ByteOrder::LittleEndian,
+        })
+    }
+
+    /// Decodes the raw value of a message's multiplexor switch.
+    ///
+    /// # Returns
+    ///
+    /// The unscaled switch value, or `None` if the message is not multiplexed or
+    /// the switch could not be extracted from `data`.
+    fn decode_mux_value(&self, msg_def: &can_dbc::Message, data: &[u8]) -> Option<u64> {
+        let mux_def = msg_def.signals.iter().find(|s| {
+            matches!(
+                s.multiplexer_indicator,
+                can_dbc::MultiplexIndicator::Multiplexor
+            )
+        })?;
+
+        self.extract_signal_value(
+            data,
+            mux_def.start_bit as usize,
+            mux_def.size as usize,
+            mux_def.byte_order,
+        )
+    }
+
+    /// Returns the switch name and value if [`UnknownMuxPolicy::Error`] rejects
+    /// a frame whose switch value selects no defined multiplexed group.
+    fn rejected_mux_value(
+        &self,
+        msg_def: &can_dbc::Message,
+        mux_value: Option<u64>,
+    ) -> Option<(String, u64)> {
+        let mux_value = mux_value?;
+        if self.unknown_mux_policy != UnknownMuxPolicy::Error
+            || Self::is_defined_mux_value(msg_def, mux_value)
+        {
+            return None;
+        }
+
+        let signal = msg_def
+            .signals
+            .iter()
+            .find(|s| s.multiplexer_indicator == can_dbc::MultiplexIndicator::Multiplexor)
+            .map_or_else(String::new, |s| s.name.clone());
+        Some((signal, mux_value))
+    }
+
+    /// Returns whether any multiplexed signal of a message belongs to the group
+    /// selected by `mux_value`.
+    fn is_defined_mux_value(msg_def: &can_dbc::Message, mux_value: u64) -> bool {
+        msg_def.signals.iter().any(|s| {
+            matches!(
+                s.multiplexer_indicator,
+                can_dbc::MultiplexIndicator::MultiplexedSignal(value)
+                | can_dbc::MultiplexIndicator::MultiplexorAndMultiplexedSignal(value)
+                    if value == mux_value
+            )
+        })
+    }
+
+    /// Returns whether a signal is present in a frame with the given switch value.
+    ///
+    /// Plain signals and multiplexor switches are always present. Multiplexed
+    /// signals are only present when their multiplexer value equals `mux_value`.
+    /// Signals disabled with [`disable_signal`](Parser::disable_signal) are
+    /// never present.
+    fn is_signal_active(
+        msg_entry: &MsgEntry,
+        signal_def: &can_dbc::Signal,
+        mux_value: Option<u64>,
+    ) -> bool {
+        if msg_entry
+            .signal_meta
+            .get(&signal_def.name)
+            .is_some_and(|meta| meta.disabled)
+        {
+            return false;
+        }
+
+        match signal_def.multiplexer_indicator {
+            can_dbc::MultiplexIndicator::Plain | can_dbc::MultiplexIndicator::Multiplexor => true,
+            can_dbc::MultiplexIndicator::MultiplexedSignal(value)
+            | can_dbc::MultiplexIndicator::MultiplexorAndMultiplexedSignal(value) => {
+                mux_value == Some(value)
+            }
+        }
+    }
+
+    /// Applies sign extension and scaling to a pre-extracted raw signal value.
+    ///
+    /// This is useful when the bits of a signal have already been extracted
+    /// (e.g. by hardware that presents right-justified signal values) and only
+    /// the interpretation (signedness, enum label, IEEE float, factor and offset)
+    /// still needs to be applied. Bits above the signal's size are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `msg_id` - The CAN message identifier
+    /// * `signal_name` - The name of the signal as defined in the DBC file
+    /// * `raw` - The right-justified raw bits of the signal
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(DecodedSignal)` if the message and signal are known, or `None` otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use can_decode::Parser;
+    /// use std::path::Path;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let parser = Parser::from_dbc_file(Path::new("my_database.dbc"))?;
+    ///
+    /// if let Some(signal) = parser.scale_raw(0x123, "EngineSpeed", 0x1F40) {
+    ///     println!("{}: {} {}", signal.name, signal.value.physical, signal.unit);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn scale_raw(&self, msg_id: u32, signal_name: &str, raw: u64) -> Option<DecodedSignal> {
+        let msg_entry = self.msg_entries.get(&msg_id)?;
+        let signal_def = msg_entry
+            .msg_def
+            .signals
+            .iter()
+            .find(|s| s.name == signal_name)?;
+
+        let raw_value = raw & low_bits_mask!((signal_def.size as usize).min(64), u64);
+        Some(self.interpret_raw_value(msg_entry, signal_def, raw_value))
+    }
+
+    /// Extracts the raw bits of a signal, honoring [`set_bit_reverse`](Parser::set_bit_reverse).
+    fn extract_signal_raw(
+        &self,
+        msg_entry: &MsgEntry,
+        signal_def: &can_dbc::Signal,
+        data: &[u8],
+    ) -> Option<u64> {
+        // Extract raw value based on byte order and signal properties
+        let raw_value = self.extract_signal_value(
+            data,
+            signal_def.start_bit as usize,
+            signal_def.size as usize,
+            signal_def.byte_order,
+        )?;
+
+        let bit_reverse = msg_entry
+            .signal_meta
+            .get(&signal_def.name)
+            .is_some_and(|meta| meta.bit_reverse);
+        if bit_reverse && signal_def.size > 0 {
+            return Some(raw_value.reverse_bits() >> (64 - signal_def.size.min(64)));
+        }
+
+        Some(raw_value)
+    }
+
+    /// Interprets the extracted raw bits of a signal.
+    ///
+    /// Converts the raw bits to signed/unsigned as needed, and then either resolves
+    /// a DBC enum label or applies scaling/offset to produce a numeric physical value.
+    /// If `SIG_VALTYPE_` marks the signal as IEEE float/double, raw bits are
+    /// interpreted directly as `f32`/`f64`.
+    fn interpret_raw_value(
+        &self,
+        msg_entry: &MsgEntry,
+        signal_def: &can_dbc::Signal,
+        raw_value: u64,
+    ) -> DecodedSignal {
+        let raw_value_with_sign = Self::sign_extend(signal_def, raw_value);
+
+        let signal_meta = msg_entry.signal_meta.get(&signal_def.name);
+        let float_format = signal_meta.and_then(|meta| meta.float_format);
+
+        // Check if this signal has an enum definition
+        let enum_label = signal_meta.and_then(|meta| {
+            if meta.enum_map.is_empty() {
+                return None;
+            }
+            let label = meta.enum_map.get(&raw_value_with_sign);
+            if label.is_none() {
+                log::warn!(
+                    "Raw value {} for signal '{}' in message ID {:#X} does not have a corresponding enum label. \
+                    Returning raw value as numeric.",
+                    raw_value_with_sign,
+                    signal_def.name,
+                    msg_entry.msg_def.id.raw()
+                );
+            }
+            label
+        });
+
+        let physical = Self::physical_value(signal_def, signal_meta, raw_value);
+        let value = if let Some(enum_str) = enum_label {
+            DecodedSignalValue::new_enum(physical, raw_value_with_sign, enum_str.clone())
+        } else if float_format.is_some() {
+            DecodedSignalValue::new_float_backed_numeric(physical)
+        } else {
+            // Not enum or float, signed/unsigned integer
+            DecodedSignalValue::new_integer_backed_numeric(physical, raw_value_with_sign)
+        };
+
+        let is_sna = self.is_sna(signal_def, signal_meta, raw_value, enum_label);
+
+        let range = dbc_range(signal_def);
+        let in_range = range.map(|(min, max)| (min..=max).contains(&physical));
+        if self.warn_out_of_range && in_range == Some(false) && !is_sna {
+            log::warn!(
+                "Value {} of signal {} in message {} is outside of its DBC range",
+                physical,
+                signal_def.name,
+                msg_entry.msg_def.name
+            );
+        }
+
+        let transformed =
+            float_format.is_none() && signal_meta.is_some_and(|meta| meta.transform.is_some());
+        DecodedSignal {
+            name: signal_def.name.clone(),
+            value,
+            unit: signal_def.unit.clone(),
+            kind: if transformed {
+                SignalKind::Float
+            } else {
+                SignalKind::from_signal_def(signal_def, float_format)
+            },
+            transformed,
+            factor: signal_def.factor,
+            offset: signal_def.offset,
+            min: range.map(|(min, _)| min),
+            max: range.map(|(_, max)| max),
+            in_range,
+            is_sna,
+            start_bit: signal_def.start_bit as u16,
+            size: signal_def.size as u16,
+            byte_order: signal_def.byte_order,
+        }
+    }
+
+    /// Returns whether a signal's raw bits are its "signal not available" sentinel.
+    ///
+    /// A sentinel set with [`set_sna_value`](Parser::set_sna_value) always
+    /// applies. Otherwise, with [`set_sna_detection`](Parser::set_sna_detection)
+    /// enabled, integer signals of at least 2 bits are not available when their
+    /// raw value is the largest the signal can hold (all bits set for unsigned
+    /// signals, all bits but the sign bit for signed ones) or their value
+    /// description reads "SNA" or "not available".
+    fn is_sna(
+        &self,
+        signal_def: &can_dbc::Signal,
+        signal_meta: Option<&SignalMeta>,
+        raw_value: u64,
+        enum_label: Option<&String>,
+    ) -> bool {
+        if let Some(sna_value) = signal_meta.and_then(|meta| meta.sna_value) {
+            return raw_value == sna_value;
+        }
+        if !self.sna_detection || signal_meta.is_some_and(|meta| meta.float_format.is_some()) {
+            return false;
+        }
+
+        // For signed signals all ones is -1, so the sentinel is the largest positive value
+        let size = (signal_def.size as usize).min(64);
+        let max_raw = match signal_def.value_type {
+            can_dbc::ValueType::Signed => low_bits_mask!(size.saturating_sub(1), u64),
+            can_dbc::ValueType::Unsigned => low_bits_mask!(size, u64),
+        };
+        let sna_label = enum_label.is_some_and(|label| {
+            let label = label.trim().to_ascii_lowercase();
+            label == "sna" || label.contains("not available")
+        });
+        (size >= 2 && raw_value == max_raw) || sna_label
+    }
+
+    /// Computes the scaled physical value of a signal from its extracted raw bits.
+    ///
+    /// IEEE float/double signals reinterpret the bits as `f32`/`f64` before
+    /// scaling, while integer signals are sign extended first. Integer signals
+    /// with a [`SignalTransform`] use it instead of the factor and offset.
+    fn physical_value(
+        signal_def: &can_dbc::Signal,
+        signal_meta: Option<&SignalMeta>,
+        raw_value: u64,
+    ) -> f64 {
+        let float_format = signal_meta.and_then(|meta| meta.float_format);
+        if let (Some(transform), None) = (
+            signal_meta.and_then(|meta| meta.transform.as_ref()),
+            float_format,
+        ) {
+            return transform.apply(Self::sign_extend(signal_def, raw_value));
+        }
+
+        let unscaled = match float_format {
+            // Note: signal sizes are validated when loading the DBC, so we can assume 32 bits for f32 and 64 bits for f64
+            Some(FloatFormat::F32) => f32::from_bits(raw_value as u32) as f64,
+            Some(FloatFormat::F64) => f64::from_bits(raw_value),
+            None => Self::sign_extend(signal_def, raw_value) as f64,
+        };
+        unscaled * signal_def.factor + signal_def.offset
+    }
+
+    /// Converts the raw bits of a signal to a signed value if the signal is signed.
+    ///
+    /// The sign bit is bit `size - 1` of the extracted value, which for big-endian
+    /// signals is the bit at `start_bit` in the frame.
+    fn sign_extend(signal_def: &can_dbc::Signal, raw_value: u64) -> i128 {
+        if signal_def.value_type == can_dbc::ValueType::Signed {
+            let shift = 128u32.saturating_sub(signal_def.size as u32);
+            ((raw_value as i128) << shift) >> shift
+        } else {
+            raw_value as i128
+        }
+    }
+
+    /// Extracts raw signal bits from CAN data.
+    ///
+    /// This function reads the raw bits for a signal from the CAN message data,
+    /// handling both little-endian and big-endian byte ordering according to DBC specifications.
+    ///
+    /// ## Little-Endian Extraction
+    ///
+    /// For little-endian signals, bits are read starting from `start_bit` and proceeding
+    /// through sequential bytes, with results accumulated in the LSB-first order.
+    /// Byte-aligned signals that are exactly 8, 16, 32, or 64 bits wide take a fast
+    /// path that reads the bytes directly with `from_le_bytes`.
+    ///
+    /// ## Big-Endian Extraction
+    ///
+    /// For big-endian signals, `start_bit` specifies the MSB position using DBC's sawtooth numbering.
+    /// The bits are extracted MSB-first and accumulated into the result.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - The raw CAN message bytes
+    /// * `start_bit` - Starting bit position (DBC-style)
+    /// * `size` - Number of bits to extract
+    /// * `byte_order` - Byte order (little-endian or big-endian)
+    ///
+    /// # Returns
+    ///
+    /// The extracted bits as a `u64`, or `None` if data is empty or out of bounds.
+    fn extract_signal_value(
+        &self,
+        data: &[u8],
+        start_bit: usize,
+        size: usize,
+        byte_order: can_dbc::ByteOrder,
+    ) -> Option<u64> {
+        if data.is_empty() || size == 0 {
+            return None;
+        }
+
+        let mut result = 0u64;
+
+        match byte_order {
+            can_dbc::ByteOrder::LittleEndian
+                if start_bit.is_multiple_of(8) && matches!(size, 8 | 16 | 32 | 64) =>
+            {
+                // Fast path: byte-aligned little-endian signals of a native integer width
+                // can be read directly without walking individual bits
+                let start_byte = start_bit / 8;
+                let bytes = data.get(start_byte..start_byte + size / 8)?;
+
+                result = match size {
+                    8 => bytes[0] as u64,
+                    16 => u16::from_le_bytes(bytes.try_into().ok()?) as u64,
+                    32 => u32::from_le_bytes(bytes.try_into().ok()?) as u64,
+                    _ => u64::from_le_bytes(bytes.try_into().ok()?),
+                };
+            }
+            can_dbc::ByteOrder::LittleEndian => {
+                // For little-endian, start_bit gives us the LSB position
+                let start_byte = start_bit / 8;
+                let start_bit_in_byte = start_bit % 8;
+
+                let mut remaining_bits = size;
+                let mut current_byte = start_byte;
+                let mut bit_offset = start_bit_in_byte;
+
+                // Read bits sequentially across bytes
+                while remaining_bits > 0 {
+                    if current_byte >= data.len() {
+                        // Out of bounds: cannot extract signal
+                        return None;
+                    }
+
+                    // Determine how many bits we can read from this byte
+                    let bits_in_this_byte = std::cmp::min(remaining_bits, 8 - bit_offset);
+                    // Create mask for the bits we want from this byte
+                    let mask = low_bits_mask!(bits_in_this_byte, u64) << bit_offset;
+                    // Extract and shift the bits to the LSB position
+                    let byte_value = ((data[current_byte] as u64) & mask) >> bit_offset;
+
+                    // Place the extracted bits in the result, with higher-order bits on the left
+                    result |= byte_value << (size - remaining_bits);
+
+                    remaining_bits -= bits_in_this_byte;
+                    current_byte += 1;
+                    bit_offset = 0;
+                }
+            }
+            can_dbc::ByteOrder::BigEndian => {
+                // For big-endian (Motorola), start_bit specifies the MSB position.
+                // The DBC "sawtooth" numbering:
+                //   - byte_idx = start_bit / 8
+                //   - bit_in_byte = start_bit % 8 (0=LSB, 7=MSB of the byte)
+
+                let start_byte = start_bit / 8;
+                let start_bit_in_byte = start_bit % 8; // Physical bit index (0-7)
+
+                let mut byte_idx = start_byte;
+                let mut bit_in_byte = start_bit_in_byte as i32; // Current bit position, counts downward
+
+                // Extract bits from MSB to LSB
+                for _i in 0..size {
+                    if byte_idx >= data.len() {
+                        // Out of bounds: cannot extract signal
+                        return None;
+                    }
+
+                    // Extract one bit at the current position
+                    let bit_val = (data[byte_idx] >> bit_in_byte) & 1;
+                    // Shift result left and add the extracted bit
+                    result = (result << 1) | (bit_val as u64);
+
+                    // Move to the next bit (downward within the byte)
+                    bit_in_byte -= 1;
+                    // If we've gone past bit 0, move to the next byte
+                    if bit_in_byte < 0 {
+                        bit_in_byte = 7;
+                        byte_idx += 1;
+                    }
+                }
+            }
+        }
+
+        Some(result)
+    }
+
+    /// Encodes a CAN message from signal values into raw bytes.
+    ///
+    /// Takes a message ID and a map of signal names to their physical values,
+    /// then encodes them according to the DBC definitions into raw CAN data bytes.
+    /// Applies inverse scaling (offset and factor) and packs bits according to
+    /// the signal's byte order and position.
+    ///
+    /// # Arguments
+    ///
+    /// * `msg_id` - The CAN message identifier
+    /// * `signal_values` - Map of signal names to their physical values
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(Vec<u8>)` containing the encoded message data, or `None` if
+    /// the message ID is not found in the loaded DBC definitions or encoding fails.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use can_decode::Parser;
+    /// use std::path::Path;
+    /// use std::collections::HashMap;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let parser = Parser::from_dbc_file(Path::new("my_database.dbc"))?;
+    ///
+    /// let signal_values = HashMap::from([
+    ///     ("EngineSpeed".to_string(), 2500.0),
+    ///     ("ThrottlePosition".to_string(), 45.5),
+    /// ]);
+    ///
+    /// if let Some(data) = parser.encode_msg(0x123, &signal_values) {
+    ///     println!("Encoded data: {:02X?}", data);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Encoding is the inverse of [`decode_msg`](Parser::decode_msg), with
+    /// out-of-range values clamped to what the signal can represent:
+    ///
+    /// ```
+    /// use can_decode::Parser;
+    /// use std::collections::HashMap;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let dbc = r#"
+    /// VERSION ""
+    ///
+    /// BU_: ECU
+    ///
+    /// BO_ 256 Motor: 4 ECU
+    ///  SG_ Speed : 7|16@0+ (0.5,0) [0|32767.5] "rpm" Vector__XXX
+    ///  SG_ Torque : 16|8@1- (1,-10) [-138|117] "Nm" Vector__XXX
+    ///  SG_ Enabled : 24|1@1+ (1,0) [0|1] "" Vector__XXX
+    /// "#;
+    /// let mut parser = Parser::new();
+    /// parser.add_from_str(dbc)?;
+    ///
+    /// let signal_values = HashMap::from([
+    ///     ("Speed".to_string(), 1500.0),
+    ///     ("Torque".to_string(), -25.0),
+    ///     ("Enabled".to_string(), 1.0),
+    /// ]);
+    /// let data = parser.encode_msg(256, &signal_values).unwrap();
+    /// assert_eq!(data, [0x0B, 0xB8, 0xF1, 0x01]);
+    ///
+    /// let decoded = parser.decode_msg(256, &data).unwrap();
+    /// assert_eq!(decoded.signals["Speed"].value.physical, 1500.0);
+    /// assert_eq!(decoded.signals["Torque"].value.physical, -25.0);
+    ///
+    /// // Torque saturates at the most negative raw value, -128
+    /// let signal_values = HashMap::from([
+    ///     ("Speed".to_string(), 0.0),
+    ///     ("Torque".to_string(), -500.0),
+    ///     ("Enabled".to_string(), 0.0),
+    /// ]);
+    /// let data = parser.encode_msg(256, &signal_values).unwrap();
+    /// assert_eq!(data[2], 0x80);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn encode_msg(
+        &self,
+        msg_id: u32,
+        signal_values: &std::collections::HashMap<String, f64>,
+    ) -> Option<Vec<u8>> {
+        let msg_entry = self.msg_entries.get(&msg_id)?;
+
+        let msg_size = msg_entry.msg_def.size as usize;
+        let mut data = vec![0u8; msg_size];
+
+        for signal_def in &msg_entry.msg_def.signals {
+            let physical_value = match signal_values.get(&signal_def.name) {
+                Some(&v) => v,
+                _ => {
+                    log::error!(
+                        "Signal {} not provided for message {} during encoding",
+                        signal_def.name,
+                        msg_entry.msg_def.name
+                    );
+                    return None;
+                }
+            };
+
+            // encode_signal() modifies the data buffer in place
+            if self
+                .encode_signal(msg_id, signal_def, physical_value, &mut data)
+                .is_none()
+            {
+                log::error!(
+                    "Failed to encode signal {} for message {}",
+                    signal_def.name,
+                    msg_entry.msg_def.name
+                );
+                return None;
+            }
+        }
+
+        Some(data)
+    }
+
+    /// Encodes a CAN message by message name instead of ID.
+    ///
+    /// Looks up the message with [`msg_id_for_name`](Parser::msg_id_for_name)
+    /// and then encodes it.
+    ///
+    /// # Arguments
+    ///
+    /// * `msg_name` - The name of the message as defined in the DBC file
+    /// * `signal_values` - Map of signal names to their physical values
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some((msg_id, data))` containing the message ID and encoded data,
+    /// or `None` if the message name is not found or encoding fails.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use can_decode::Parser;
+    /// use std::path::Path;
+    /// use std::collections::HashMap;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let parser = Parser::from_dbc_file(Path::new("my_database.dbc"))?;
+    ///
+    /// let signal_values = HashMap::from([
+    ///     ("EngineSpeed".to_string(), 2500.0),
+    ///     ("ThrottlePosition".to_string(), 45.5),
+    /// ]);
+    ///
+    /// if let Some((msg_id, data)) = parser.encode_msg_by_name("EngineData", &signal_values) {
+    ///     println!("Message ID: {:#X}, Data: {:02X?}", msg_id, data);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn encode_msg_by_name(
+        &self,
+        msg_name: &str,
+        signal_values: &std::collections::HashMap<String, f64>,
+    ) -> Option<(u32, Vec<u8>)> {
+        let msg_id = self.msg_id_for_name(msg_name)?;
+        let data = self.encode_msg(msg_id, signal_values)?;
+        Some((msg_id, data))
+    }
+
+    /// Computes the raw value that encodes a target physical value.
+    ///
+    /// Applies the inverse scaling `(physical - offset) / factor`, rounds to the
+    /// nearest integer, and clamps the result to the range representable by the
+    /// signal's size and signedness. This is the same conversion used by
+    /// [`encode_msg`](Parser::encode_msg). For signals marked with
+    /// [`set_bit_reverse`](Parser::set_bit_reverse), it's the value before its
+    /// bits are reversed for transmission, which is what decoding reports as
+    /// the raw value.
+    ///
+    /// # Arguments
+    ///
+    /// * `msg_id` - The CAN message identifier
+    /// * `signal_name` - The name of the signal as defined in the DBC file
+    /// * `physical` - The target physical value
+    ///
+    /// # Returns
+    ///
+    /// The raw value, or `None` if the message or signal is unknown, the signal
+    /// is an IEEE float/double, or the raw value doesn't fit in an `i64` (only
+    /// possible for unsigned 64-bit signals).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use can_decode::Parser;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let dbc = r#"
+    /// VERSION ""
+    ///
+    /// BU_: ECU
+    ///
+    /// BO_ 256 Setpoints: 2 ECU
+    ///  SG_ TargetTemp : 0|8@1- (0.5,-10) [-74|53.5] "degC" Vector__XXX
+    /// "#;
+    /// let mut parser = Parser::new();
+    /// parser.add_from_str(dbc)?;
+    ///
+    /// assert_eq!(parser.raw_for_physical(256, "TargetTemp", 25.0), Some(70));
+    /// // Clamped to the most negative 8-bit value
+    /// assert_eq!(parser.raw_for_physical(256, "TargetTemp", -200.0), Some(-128));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn raw_for_physical(&self, msg_id: u32, signal_name: &str, physical: f64) -> Option<i64> {
+        let msg_entry = self.msg_entries.get(&msg_id)?;
+        let signal_def = msg_entry
+            .msg_def
+            .signals
+            .iter()
+            .find(|s| s.name == signal_name)?;
+
+        let is_float = msg_entry
+            .signal_meta
+            .get(signal_name)
+            .is_some_and(|meta| meta.float_format.is_some());
+        if is_float {
+            return None;
+        }
+
+        let scaled_value = (physical - signal_def.offset) / signal_def.factor;
+        i64::try_from(Self::clamp_raw(signal_def, scaled_value)).ok()
+    }
+
+    /// Starts building the payload of a message, signal by signal.
+    ///
+    /// Signals that are never set keep their start value from the DBC
+    /// (`GenSigStartValue`, interpreted as a raw value), or raw 0 if none is
+    /// defined. See [`FrameBuilder`] for an example.
+    ///
+    /// # Arguments
+    ///
+    /// * `msg_id` - The CAN message ID to build
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EncodeError::UnknownMessage`] if the message isn't defined, or
+    /// [`EncodeError::EncodingFailed`] if a start value doesn't fit in the payload.
+    pub fn frame(&self, msg_id: u32) -> Result<FrameBuilder<'_>, EncodeError> {
+        let msg_entry = self
+            .msg_entries
+            .get(&msg_id)
+            .ok_or(EncodeError::UnknownMessage(msg_id))?;
+
+        let mut data = vec![0u8; msg_entry.msg_def.size as usize];
+        for signal_def in &msg_entry.msg_def.signals {
+            let Some(start_value) = msg_entry
+                .signal_meta
+                .get(&signal_def.name)
+                .and_then(SignalMeta::start_value)
+            else {
+                continue;
+            };
+
+            let raw = (start_value.round() as i64 as u64)
+                & low_bits_mask!((signal_def.size as usize).min(64), u64);
+            self.insert_signal_raw(msg_entry, signal_def, &mut data, raw)
+                .ok_or_else(|| EncodeError::EncodingFailed(signal_def.name.clone()))?;
+        }
+
+        Ok(FrameBuilder {
+            parser: self,
+            msg_id,
+            data,
+        })
+    }
+
+    /// Encodes a single signal into raw CAN data.
+    ///
+    /// Converts a physical signal value back to its raw representation by:
+    /// 1. Applying inverse scaling: `raw_value = (physical_value - offset) / factor`
+    /// 2. Converting to the appropriate integer representation (signed/unsigned)
+    /// 3. Clamping to the valid range for the signal's bit width
+    /// 4. Packing the bits into the data buffer at the signal's bit position
+    ///
+    /// For IEEE float/double signals, the physical value is converted directly
+    /// to f32 or f64 and the bit pattern is extracted.
+    ///
+    /// # Arguments
+    ///
+    /// * `msg_id` - The CAN message ID (used to look up format definitions)
+    /// * `signal_def` - The DBC signal definition
+    /// * `physical_value` - The physical value to encode
+    /// * `data` - Mutable buffer to write the encoded bits into
+    ///
+    /// # Returns
+    ///
+    /// `Some(())` on success, `None` if encoding fails (e.g., bounds check)
+    fn encode_signal(
+        &self,
+        msg_id: u32,
+        signal_def: &can_dbc::Signal,
+        physical_value: f64,
+        data: &mut [u8],
+    ) -> Option<()> {
+        // Apply inverse scaling to convert physical value back to raw value
+        let scaled_value = (physical_value - signal_def.offset) / signal_def.factor;
+
+        // Check if this is an IEEE float/double signal
+        let msg_entry = self.msg_entries.get(&msg_id)?;
+        let float_def = msg_entry
+            .signal_meta
+            .get(&signal_def.name)
+            .and_then(|format_def| format_def.float_format);
+        if let Some(float_format) = float_def {
+            // For float signals, convert to the appropriate float type and extract bit pattern
+            let float_data = match float_format {
+                FloatFormat::F32 => (scaled_value as f32).to_bits() as u64,
+                FloatFormat::F64 => scaled_value.to_bits(),
+            };
+            return self.insert_signal_raw(msg_entry, signal_def, data, float_data);
+        }
+
+        // For integer signals, convert and handle signed/unsigned representation.
+        // Two's complement: negative values cast to u64 already give the correct bit pattern
+        let mask = low_bits_mask!(signal_def.size as usize, u64);
+        let raw_int = (Self::clamp_raw(signal_def, scaled_value) as u64) & mask;
+
+        // Insert the encoded bits into the data buffer
+        self.insert_signal_raw(msg_entry, signal_def, data, raw_int)
+    }
+
+    /// Inserts the raw bits of a signal, honoring [`set_bit_reverse`](Parser::set_bit_reverse).
+    ///
+    /// This is the inverse of `extract_signal_raw`: for bit-reversed signals the
+    /// `size` low bits of `raw` are reversed before they are packed.
+    fn insert_signal_raw(
+        &self,
+        msg_entry: &MsgEntry,
+        signal_def: &can_dbc::Signal,
+        data: &mut [u8],
+        raw: u64,
+    ) -> Option<()> {
+        let bit_reverse = msg_entry
+            .signal_meta
+            .get(&signal_def.name)
+            .is_some_and(|meta| meta.bit_reverse);
+        let raw = if bit_reverse && signal_def.size > 0 {
+            raw.reverse_bits() >> (64 - signal_def.size.min(64))
+        } else {
+            raw
+        };
+
+        self.insert_signal_value(
+            data,
+            signal_def.start_bit as usize,
+            signal_def.size as usize,
+            signal_def.byte_order,
+            raw,
+        )
+    }
+
+    /// Rounds an unscaled value and clamps it to the range of an integer signal.
+    ///
+    /// The range is `[-2^(size-1), 2^(size-1) - 1]` for signed signals and
+    /// `[0, 2^size - 1]` for unsigned signals.
+    fn clamp_raw(signal_def: &can_dbc::Signal, scaled_value: f64) -> i128 {
+        let size = signal_def.size.clamp(1, 64) as u32;
+        // Use i128 to avoid overflow when size == 64
+        let (min_value, max_value) = if signal_def.value_type == can_dbc::ValueType::Signed {
+            (-(1i128 << (size - 1)), (1i128 << (size - 1)) - 1)
+        } else {
+            (0, (1i128 << size) - 1)
+        };
+
+        (scaled_value.round() as i128).clamp(min_value, max_value)
+    }
+
+    /// Inserts raw signal bits into CAN data.
+    ///
+    /// This function packs raw bits (typically from encoding) into the CAN message buffer,
+    /// handling both little-endian and big-endian byte ordering.
+    ///
+    /// ## Little-Endian Insertion
+    ///
+    /// For little-endian signals, bits are packed starting from `start_bit` across sequential
+    /// bytes, with the LSBs of the value written first.
+    ///
+    /// ## Big-Endian Insertion
+    ///
+    /// For big-endian signals, `start_bit` specifies the MSB position using DBC's sawtooth numbering.
+    /// Bits are packed from MSB to LSB starting at that position.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - Mutable buffer of CAN message bytes to modify
+    /// * `start_bit` - Starting bit position (DBC-style)
+    /// * `size` - Number of bits to insert
+    /// * `byte_order` - Byte order (little-endian or big-endian)
+    /// * `value` - The raw value to insert
+    ///
+    /// # Returns
+    ///
+    /// `Some(())` on success, or `None` if the signal extends beyond the data bounds.
+    fn insert_signal_value(
+        &self,
+        data: &mut [u8],
+        start_bit: usize,
+        size: usize,
+        byte_order: can_dbc::ByteOrder,
+        value: u64,
+    ) -> Option<()> {
+        if data.is_empty() || size == 0 {
+            return None;
+        }
+
+        let total_bits = data.len() * 8;
+        if start_bit + size > total_bits {
+            // Signal extends beyond the data buffer
+            return None;
+        }
+
+        match byte_order {
+            can_dbc::ByteOrder::LittleEndian => {
+                let start_byte = start_bit / 8;
+                let start_bit_in_byte = start_bit % 8;
+
+                let mut remaining_bits = size;
+                let mut current_byte = start_byte;
+                let mut bit_offset = start_bit_in_byte;
+                let mut value_offset = 0;
+
+                // Write bits sequentially across bytes
+                while remaining_bits > 0 && current_byte < data.len() {
+                    // Determine how many bits we can write to this byte
+                    let bits_in_this_byte = std::cmp::min(remaining_bits, 8 - bit_offset);
+                    // Create a mask for the bits we're about to write
+                    let mask = low_bits_mask!(bits_in_this_byte, u8) << bit_offset;
+
+                    // Extract the bits we want from the value
+                    let value_mask = low_bits_mask!(bits_in_this_byte, u64);
+                    let value_bits = ((value >> value_offset) & value_mask) as u8;
+
+                    // Clear the target bits in the data byte and set new bits
+                    data[current_byte] =
+                        (data[current_byte] & !mask) | ((value_bits << bit_offset) & mask);
+
+                    remaining_bits -= bits_in_this_byte;
+                    value_offset += bits_in_this_byte;
+                    current_byte += 1;
+                    bit_offset = 0;
+                }
+            }
+            can_dbc::ByteOrder::BigEndian => {
+                let start_byte = start_bit / 8;
+                let start_bit_in_byte = start_bit % 8;
+
+                let mut byte_idx = start_byte;
+                let mut bit_in_byte = start_bit_in_byte as i32;
+
+                // Write bits from MSB to LSB
+                for i in 0..size {
+                    if byte_idx >= data.len() {
+                        return None;
+                    }
+
+                    // Extract the i-th bit from the value (starting from MSB)
+                    let bit_val = ((value >> (size - 1 - i)) & 1) as u8;
+                    // Create a mask for the target bit position
+                    let mask = 1u8 << bit_in_byte;
+                    // Clear the bit and set the new value
+                    data[byte_idx] = (data[byte_idx] & !mask) | (bit_val << bit_in_byte);
+
+                    // Move to the next bit (downward within the byte)
+                    bit_in_byte -= 1;
+                    // If we've gone past bit 0, move to the next byte
+                    if bit_in_byte < 0 {
+                        bit_in_byte = 7;
+                        byte_idx += 1;
+                    }
+                }
+            }
+        }
+
+        Some(())
+    }
+
+    /// Checks the loaded definitions for malformed messages and signals.
+    ///
+    /// Reports messages larger than the 64-byte CAN FD maximum, signals that
+    /// are 0 or more than 64 bits wide, signals whose bits extend beyond the
+    /// message size, and pairs of signals that share bits while being present
+    /// in the same frame. Multiplexed signals of different groups may share
+    /// bits, but not with plain signals or the multiplexor switch.
+    ///
+    /// # Returns
+    ///
+    /// The issues found, ordered by message ID, or an empty vector if all
+    /// definitions are well-formed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use can_decode::{DefinitionIssue, Parser};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut parser = Parser::new();
+    /// parser.add_from_str(
+    ///     r#"
+    /// BO_ 256 Mixed: 2 ECU
+    ///  SG_ Mux M : 0|4@1+ (1,0) [0|15] "" Vector__XXX
+    ///  SG_ Temp m0 : 8|8@1+ (1,-40) [0|0] "degC" Vector__XXX
+    ///  SG_ Speed m1 : 8|8@1+ (1,0) [0|0] "km/h" Vector__XXX
+    ///  SG_ Flags : 12|8@1+ (1,0) [0|0] "" Vector__XXX
+    /// "#,
+    /// )?;
+    ///
+    /// assert_eq!(
+    ///     parser.validate_definitions(),
+    ///     [
+    ///         DefinitionIssue::SignalOutOfBounds {
+    ///             msg_id: 256,
+    ///             signal: "Flags".to_string(),
+    ///         },
+    ///         DefinitionIssue::OverlappingSignals {
+    ///             msg_id: 256,
+    ///             first: "Temp".to_string(),
+    ///             second: "Flags".to_string(),
+    ///         },
+    ///         DefinitionIssue::OverlappingSignals {
+    ///             msg_id: 256,
+    ///             first: "Speed".to_string(),
+    ///             second: "Flags".to_string(),
+    ///         },
+    ///     ]
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn validate_definitions(&self) -> Vec<DefinitionIssue> {
+        // The multiplexed group a signal belongs to, or `None` if it is always present
+        let mux_group = |signal_def: &can_dbc::Signal| match signal_def.multiplexer_indicator {
+            can_dbc::MultiplexIndicator::MultiplexedSignal(value)
+            | can_dbc::MultiplexIndicator::MultiplexorAndMultiplexedSignal(value) => Some(value),
+            _ => None,
+        };
+
+        let mut entries: Vec<&MsgEntry> = self.msg_entries.values().collect();
+        entries.sort_by_key(|entry| entry.msg_def.id.raw());
+
+        let mut issues = Vec::new();
+        for entry in entries {
+            let msg_def = &entry.msg_def;
+            let msg_id = msg_def.id.raw();
+            if msg_def.size > 64 {
+                issues.push(DefinitionIssue::MessageTooLarge {
+                    msg_id,
+                    size: msg_def.size,
+                });
+            }
+
+            let mut layouts: Vec<(&can_dbc::Signal, std::collections::HashSet<usize>)> =
+                Vec::with_capacity(msg_def.signals.len());
+            for signal_def in &msg_def.signals {
+                if !(1..=64).contains(&signal_def.size) {
+                    issues.push(DefinitionIssue::InvalidSignalSize {
+                        msg_id,
+                        signal: signal_def.name.clone(),
+                        size: signal_def.size,
+                    });
+                    continue;
+                }
+
+                let positions: std::collections::HashSet<usize> =
+                    Self::signal_bit_positions(signal_def).into_iter().collect();
+                if positions
+                    .iter()
+                    .any(|&pos| pos >= msg_def.size as usize * 8)
+                {
+                    issues.push(DefinitionIssue::SignalOutOfBounds {
+                        msg_id,
+                        signal: signal_def.name.clone(),
+                    });
+                }
+                layouts.push((signal_def, positions));
+            }
+
+            for (idx, (first, first_bits)) in layouts.iter().enumerate() {
+                for (second, second_bits) in &layouts[idx + 1..] {
+                    let coexist = match (mux_group(first), mux_group(second)) {
+                        (Some(a), Some(b)) => a == b,
+                        _ => true,
+                    };
+                    if coexist && !first_bits.is_disjoint(second_bits) {
+                        issues.push(DefinitionIssue::OverlappingSignals {
+                            msg_id,
+                            first: first.name.clone(),
+                            second: second.name.clone(),
+                        });
+                    }
+                }
+            }
+        }
+        issues
+    }
+
+    /// Computes the absolute bit positions occupied by a signal.
+    ///
+    /// Positions are numbered `byte * 8 + bit_in_byte` (bit 0 being the LSB of a
+    /// byte). Little-endian signals occupy consecutive positions upward from
+    /// `start_bit`, while big-endian signals follow DBC's sawtooth numbering
+    /// downward from the MSB at `start_bit`.
+    ///
+    /// # Returns
+    ///
+    /// The bit positions, ordered from the signal's LSB (little-endian) or MSB
+    /// (big-endian).
+    fn signal_bit_positions(signal_def: &can_dbc::Signal) -> Vec<usize> {
+        let start_bit = signal_def.start_bit as usize;
+        let size = signal_def.size as usize;
+
+        match signal_def.byte_order {
+            can_dbc::ByteOrder::LittleEndian => (start_bit..start_bit + size).collect(),
+            can_dbc::ByteOrder::BigEndian => {
+                let mut positions = Vec::with_capacity(size);
+                let mut byte_idx = start_bit / 8;
+                let mut bit_in_byte = (start_bit % 8) as i32;
+
+                for _i in 0..size {
+                    positions.push(byte_idx * 8 + bit_in_byte as usize);
+
+                    // Move to the next bit (downward within the byte)
+                    bit_in_byte -= 1;
+                    // If we've gone past bit 0, move to the next byte
+                    if bit_in_byte < 0 {
+                        bit_in_byte = 7;
+                        byte_idx += 1;
+                    }
+                }
+                positions
+            }
+        }
+    }
+
+    /// Returns all signal definitions for a given message ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `msg_id` - The CAN message identifier
+    ///
+    /// # Returns
+    ///
+    /// Returns the message's signals, borrowed from the parser, if the message
+    /// ID is known, or `None` otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use can_decode::Parser;
+    /// use std::path::Path;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let parser = Parser::from_dbc_file(Path::new("my_database.dbc"))?;
+    ///
+    /// if let Some(signals) = parser.signal_defs(0x123) {
+    ///     for signal in signals {
+    ///         println!("Signal: {}", signal.name);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn signal_defs(&self, msg_id: u32) -> Option<&[can_dbc::Signal]> {
+        let msg_entry = self.msg_entries.get(&msg_id)?;
+        Some(&msg_entry.msg_def.signals)
+    }
+
+    /// Returns the DBC comment (`CM_ BO_`) of a message.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the message comment if present, or `None` if the message
+    /// is unknown or has no DBC comment.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use can_decode::Parser;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let dbc = r#"
+    /// VERSION ""
+    ///
+    /// BU_: ECU
+    ///
+    /// BO_ 256 Motor: 2 ECU
+    ///  SG_ Speed : 0|16@1+ (1,0) [0|0] "rpm" Vector__XXX
+    ///
+    /// BO_ 512 Battery: 1 ECU
+    ///  SG_ SoC : 0|8@1+ (1,0) [0|100] "%" Vector__XXX
+    ///
+    /// CM_ BO_ 256 "Motor controller status, sent every 10 ms";
+    /// "#;
+    /// let mut parser = Parser::new();
+    /// parser.add_from_str(dbc)?;
+    ///
+    /// assert_eq!(parser.msg_comment(256), Some("Motor controller status, sent every 10 ms"));
+    /// assert_eq!(parser.msg_comment(512), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn msg_comment(&self, msg_id: u32) -> Option<&str> {
+        self.msg_entries
+            .get(&msg_id)
+            .and_then(|entry| entry.msg_desc.as_deref())
+    }
+
+    /// Returns the message-level description/comment for a message ID.
+    #[deprecated(note = "use `msg_comment` instead")]
+    pub fn msg_desc(&self, msg_id: u32) -> Option<&str> {
+        self.msg_comment(msg_id)
+    }
+
+    /// Returns the cycle time of a message in milliseconds.
+    ///
+    /// The cycle time comes from the message's `GenMsgCycleTime` attribute
+    /// (`BA_`), or from the attribute's default (`BA_DEF_DEF_`) for messages
+    /// without an explicit value.
+    ///
+    /// # Returns
+    ///
+    /// The cycle time, or `None` if the message is unknown or neither an
+    /// explicit value nor a default is defined.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use can_decode::Parser;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let dbc = r#"
+    /// VERSION ""
+    ///
+    /// BU_: ECU
+    ///
+    /// BO_ 256 Fast: 1 ECU
+    ///  SG_ A : 0|8@1+ (1,0) [0|0] "" Vector__XXX
+    ///
+    /// BO_ 512 Default: 1 ECU
+    ///  SG_ B : 0|8@1+ (1,0) [0|0] "" Vector__XXX
+    ///
+    /// BA_DEF_ BO_ "GenMsgCycleTime" INT 0 10000;
+    /// BA_DEF_DEF_ "GenMsgCycleTime" 100;
+    /// BA_ "GenMsgCycleTime" BO_ 256 10;
+    /// "#;
+    /// let mut parser = Parser::new();
+    /// parser.add_from_str(dbc)?;
+    ///
+    /// assert_eq!(parser.cycle_time_ms(256), Some(10));
+    /// assert_eq!(parser.cycle_time_ms(512), Some(100));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn cycle_time_ms(&self, msg_id: u32) -> Option<u64> {
+        self.msg_entries
+            .get(&msg_id)
+            .and_then(MsgEntry::cycle_time_ms)
+    }
+
+    /// Returns the value of a message attribute (`BA_ ... BO_`).
+    ///
+    /// Values are typed by the attribute's definition (`BA_DEF_ BO_`), and
+    /// messages without an explicit value get the attribute's default
+    /// (`BA_DEF_DEF_`), if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `msg_id` - The CAN message identifier
+    /// * `attr` - The attribute name
+    ///
+    /// # Returns
+    ///
+    /// The attribute value, or `None` if the message is unknown or has no
+    /// value for the attribute.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use can_decode::{AttributeValue, Parser};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let dbc = r#"
+    /// VERSION ""
+    ///
+    /// BU_: ECU
+    ///
+    /// BO_ 256 Motor: 2 ECU
+    ///  SG_ Speed : 0|16@1+ (1,0) [0|8000] "rpm" Vector__XXX
+    ///
+    /// BA_DEF_ BO_ "GenMsgCycleTime" INT 0 10000;
+    /// BA_DEF_ BO_ "GenMsgSendType" ENUM "Cyclic","OnChange","IfActive";
+    /// BA_DEF_ BO_ "Owner" STRING ;
+    /// BA_DEF_ SG_ "SafetyLevel" FLOAT 0 4;
+    /// BA_DEF_DEF_ "GenMsgCycleTime" 100;
+    /// BA_DEF_DEF_ "GenMsgSendType" "Cyclic";
+    /// BA_DEF_DEF_ "Owner" "";
+    /// BA_DEF_DEF_ "SafetyLevel" 0;
+    /// BA_ "GenMsgSendType" BO_ 256 1;
+    /// BA_ "Owner" BO_ 256 "Powertrain";
+    /// BA_ "SafetyLevel" SG_ 256 Speed 2.5;
+    /// "#;
+    /// let mut parser = Parser::new();
+    /// parser.add_from_str(dbc)?;
+    ///
+    /// assert_eq!(parser.message_attribute(256, "GenMsgCycleTime"), Some(AttributeValue::Int(100)));
+    /// assert_eq!(
+    ///     parser.message_attribute(256, "GenMsgSendType"),
+    ///     Some(AttributeValue::Enum("OnChange".to_string()))
+    /// );
+    /// assert_eq!(
+    ///     parser.message_attribute(256, "Owner").as_ref().and_then(AttributeValue::as_str),
+    ///     Some("Powertrain")
+    /// );
+    /// assert_eq!(parser.signal_attribute(256, "Speed", "SafetyLevel"), Some(AttributeValue::Float(2.5)));
+    /// assert_eq!(parser.message_attribute(256, "Missing"), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn message_attribute(&self, msg_id: u32, attr: &str) -> Option<AttributeValue> {
+        self.msg_entries
+            .get(&msg_id)
+            .and_then(|entry| entry.attributes.get(attr))
+            .cloned()
+    }
+
+    /// Returns the value of a signal attribute (`BA_ ... SG_`).
+    ///
+    /// Like [`message_attribute`](Parser::message_attribute), values are typed
+    /// by the attribute's definition and fall back to its default.
+    ///
+    /// # Arguments
+    ///
+    /// * `msg_id` - The CAN message identifier
+    /// * `signal_name` - The name of the signal as defined in the DBC file
+    /// * `attr` - The attribute name
+    ///
+    /// # Returns
+    ///
+    /// The attribute value, or `None` if the message or signal is unknown or
+    /// has no value for the attribute.
+    pub fn signal_attribute(
+        &self,
+        msg_id: u32,
+        signal_name: &str,
+        attr: &str,
+    ) -> Option<AttributeValue> {
+        self.msg_entries
+            .get(&msg_id)
+            .and_then(|entry| entry.signal_meta.get(signal_name))
+            .and_then(|meta| meta.attributes.get(attr))
+            .cloned()
+    }
+
+    /// Returns the DBC comment (`CM_ SG_`) of a signal.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the signal comment if present, or `None` if the message,
+    /// signal, or comment is not available.
+    ///
+    /// # Example
+    ///
+    /// Comments may span several lines and contain semicolons and escaped quotes:
+    ///
+    /// ```
+    /// use can_decode::Parser;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let dbc = r#"
+    /// VERSION ""
+    ///
+    /// BU_: ECU
+    ///
+    /// BO_ 256 Motor: 2 ECU
+    ///  SG_ Speed : 0|16@1+ (1,0) [0|0] "rpm" Vector__XXX
+    ///
+    /// CM_ SG_ 256 Speed "Shaft speed; measured at the
+    /// \"output\" side";
+    /// "#;
+    /// let mut parser = Parser::new();
+    /// parser.add_from_str(dbc)?;
+    ///
+    /// assert_eq!(
+    ///     parser.signal_comment(256, "Speed"),
+    ///     Some("Shaft speed; measured at the\n\"output\" side")
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn signal_comment(&self, msg_id: u32, signal_name: &str) -> Option<&str> {
+        self.msg_entries
+            .get(&msg_id)
+            .and_then(|entry| entry.signal_meta.get(signal_name))
+            .and_then(|meta| meta.sig_comment.as_deref())
+    }
 
-        Ok(())
+    /// Returns the signal-level description/comment for a signal within a message.
+    #[deprecated(note = "use `signal_comment` instead")]
+    pub fn signal_desc(&self, msg_id: u32, signal_name: &str) -> Option<&str> {
+        self.signal_comment(msg_id, signal_name)
     }
 
-    /// Adds message definitions from a DBC file.
+    /// Returns the physical step per raw LSB of a signal, i.e. its absolute factor.
     ///
-    /// Reads and parses a DBC file from disk, adding all message definitions
-    /// to the parser. Multiple DBC files can be loaded to combine definitions
-    /// from different sources.
+    /// This is the smallest physical change an integer signal can represent.
+    /// IEEE float signals have no fixed step, so their factor is returned as
+    /// is (in absolute value).
     ///
     /// # Arguments
     ///
-    /// * `path` - Path to the DBC file
+    /// * `msg_id` - The CAN message identifier
+    /// * `signal_name` - The name of the signal
     ///
-    /// # Errors
+    /// # Returns
     ///
-    /// Returns an error if the file cannot be read or parsed.
+    /// The resolution, or `None` if the message or signal is not defined.
     ///
     /// # Example
     ///
-    /// ```no_run
+    /// ```
     /// use can_decode::Parser;
-    /// use std::path::Path;
     ///
     /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// let mut parser = Parser::new();
-    /// parser.add_from_dbc_file(Path::new("vehicle.dbc"))?;
-    /// parser.add_from_dbc_file(Path::new("diagnostics.dbc"))?;
+    /// parser.add_from_str(
+    ///     r#"
+    /// BO_ 256 Motor: 2 ECU
+    ///  SG_ Torque : 0|16@1- (-0.25,0) [0|0] "Nm" Vector__XXX
+    /// "#,
+    /// )?;
+    ///
+    /// assert_eq!(parser.signal_resolution(256, "Torque"), Some(0.25));
+    /// assert_eq!(parser.signal_resolution(256, "Speed"), None);
     /// # Ok(())
     /// # }
     /// ```
-    pub fn add_from_dbc_file(
-        &mut self,
-        path: &std::path::Path,
-    ) -> Result<(), Box<dyn std::error::Error>> {
-        let buffer = std::fs::read(path)?;
-        let s = String::from_utf8(buffer)?;
-        self.add_from_str(&s)?;
-        Ok(())
+    pub fn signal_resolution(&self, msg_id: u32, signal_name: &str) -> Option<f64> {
+        self.msg_entries
+            .get(&msg_id)?
+            .msg_def
+            .signals
+            .iter()
+            .find(|s| s.name == signal_name)
+            .map(|signal_def| signal_def.factor.abs())
     }
 
-    /// Decodes a raw CAN message into structured data.
+    /// Renders the bit layout of a message as an ASCII diagram.
     ///
-    /// Takes a CAN message ID and raw data bytes, then decodes all signals
-    /// according to the DBC definitions. Each signal is extracted, scaled,
-    /// and converted to its physical value or enum label (if defined).
+    /// Each row is one byte of the message, with bit 7 on the left and bit 0 on
+    /// the right. Bits are labelled with a character identifying the signal that
+    /// occupies them (see the legend below the grid), `.` for unused bits, and `*`
+    /// for bits claimed by more than one signal (e.g. alternative multiplexed groups).
     ///
     /// # Arguments
     ///
     /// * `msg_id` - The CAN message identifier
-    /// * `data` - The raw message data bytes (typically 0-8 bytes for standard CAN)
     ///
     /// # Returns
     ///
-    /// Returns `Some(DecodedMessage)` if the message ID is known, or `None` if
-    /// the message ID is not found in the loaded DBC definitions.
+    /// The rendered diagram, or `None` if the message ID is not known.
     ///
     /// # Example
     ///
-    /// ```no_run
+    /// ```
     /// use can_decode::Parser;
-    /// use std::path::Path;
     ///
     /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
-    /// let parser = Parser::from_dbc_file(Path::new("my_database.dbc"))?;
+    /// let dbc = r#"
+    /// VERSION ""
     ///
-    /// let msg_id = 0x123;
-    /// let data = [0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC, 0xDE, 0xF0];
+    /// BU_: ECU
     ///
-    /// if let Some(decoded) = parser.decode_msg(msg_id, &data) {
-    ///     println!("Message: {} (ID: {:#X})", decoded.name, decoded.msg_id);
-    ///     for (name, signal) in &decoded.signals {
-    ///         println!("  {}: {:?} {}", name, signal.value, signal.unit);
-    ///     }
-    /// } else {
-    ///     println!("Unknown message ID: {:#X}", msg_id);
-    /// }
+    /// BO_ 256 Status: 2 ECU
+    ///  SG_ Mode : 8|4@1+ (1,0) [0|15] "" Vector__XXX
+    ///  SG_ Level : 3|6@0+ (1,0) [0|63] "" Vector__XXX
+    /// "#;
+    /// let mut parser = Parser::new();
+    /// parser.add_from_str(dbc)?;
+    ///
+    /// // Level is big-endian, so it continues from bit 0 of byte 0 into bit 7 of byte 1
+    /// let diagram = parser.message_layout_diagram(256).unwrap();
+    /// assert!(diagram.contains("Byte 0: . . . . B B B B"));
+    /// assert!(diagram.contains("Byte 1: B B . . A A A A"));
     /// # Ok(())
     /// # }
     /// ```
-    pub fn decode_msg(&self, msg_id: u32, data: &[u8]) -> Option<DecodedMessage> {
-        // Grab msg metadata and then for every signal in the message, decode it and add
-        // to the decoded message
+    pub fn message_layout_diagram(&self, msg_id: u32) -> Option<String> {
+        const LABELS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789";
 
-        let msg_entry = self.msg_entries.get(&msg_id)?;
-
-        let is_extended = matches!(msg_entry.msg_def.id, can_dbc::MessageId::Extended(_));
-        let tx_node = match &msg_entry.msg_def.transmitter {
-            can_dbc::Transmitter::NodeName(name) => name.clone(),
-            can_dbc::Transmitter::VectorXXX => "Unknown".to_string(),
-        };
-        let mut decoded_signals = SignalMap::new();
+        let msg_def = &self.msg_entries.get(&msg_id)?.msg_def;
+        let msg_size = msg_def.size as usize;
 
-        for signal_def in &msg_entry.msg_def.signals {
-            match self.decode_signal(msg_id, signal_def, data) {
-                Some(decoded_signal) => {
-                    decoded_signals.insert(decoded_signal.name.to_string(), decoded_signal);
-                }
-                _ => {
-                    log::error!(
-                        "Failed to decode signal {} from message {}",
-                        signal_def.name,
-                        msg_entry.msg_def.name
-                    );
-                    return None;
-                }
+        // Label each bit with the signal occupying it
+        let mut grid = vec![b'.'; msg_size * 8];
+        for (idx, signal_def) in msg_def.signals.iter().enumerate() {
+            let label = LABELS.get(idx).copied().unwrap_or(b'?');
+            for bit in Self::signal_bit_positions(signal_def) {
+                let Some(cell) = grid.get_mut(bit) else {
+                    continue;
+                };
+                *cell = if *cell == b'.' { label } else { b'*' };
             }
         }
 
-        Some(DecodedMessage {
-            name: msg_entry.msg_def.name.clone(),
-            msg_id,
-            is_extended,
-            tx_node,
-            signals: decoded_signals,
-        })
-    }
-
-    /// Decodes a single signal from raw CAN data.
-    ///
-    /// Extracts the raw bits for a signal, converts to signed/unsigned as needed,
-    /// and then either resolves a DBC enum label or applies scaling/offset to
-    /// produce a numeric physical value. If `SIG_VALTYPE_` marks the signal as
-    /// IEEE float/double, raw bits are interpreted directly as `f32`/`f64`.
-    fn decode_signal(
-        &self,
-        msg_id: u32,
-        signal_def: &can_dbc::Signal,
-        data: &[u8],
-    ) -> Option<DecodedSignal> {
-        // Extract raw value based on byte order and signal properties
-        let raw_value = self.extract_signal_value(
-            data,
-            signal_def.start_bit as usize,
-            signal_def.size as usize,
-            signal_def.byte_order,
-        )?;
-
-        // Convert to signed if needed
-        let raw_value_with_sign: i128 = if signal_def.value_type == can_dbc::ValueType::Signed {
-            let shift = 128u32.saturating_sub(signal_def.size as u32);
-            ((raw_value as i128) << shift) >> shift
-        } else {
-            raw_value as i128
-        };
-
-        // Check if this signal has an enum definition
-        let format_def = self
-            .msg_entries
-            .get(&msg_id)
-            .and_then(|entry| entry.signal_meta.get(&signal_def.name));
-        if let Some(format_def) = format_def {
-            if let Some(enum_str) = format_def.enum_map.get(&raw_value_with_sign) {
-                let physical = raw_value_with_sign as f64 * signal_def.factor + signal_def.offset;
-                return Some(DecodedSignal {
-                    name: signal_def.name.clone(),
-                    value: DecodedSignalValue::new_enum(
-                        physical,
-                        raw_value_with_sign,
-                        enum_str.clone(),
-                    ),
-                    unit: signal_def.unit.clone(),
-                });
-            } else {
-                log::warn!(
-                    "Raw value {} for signal '{}' in message ID {:#X} does not have a corresponding enum label. \
-                    Returning raw value as numeric.",
-                    raw_value_with_sign,
-                    signal_def.name,
-                    msg_id
-                );
-            }
+        let mut diagram = format!(
+            "{} ({:#X}), {} bytes\n        7 6 5 4 3 2 1 0\n",
+            msg_def.name, msg_id, msg_size
+        );
+        for byte_idx in 0..msg_size {
+            let row = (0..8)
+                .rev()
+                .map(|bit| (grid[byte_idx * 8 + bit] as char).to_string())
+                .collect::<Vec<_>>()
+                .join(" ");
+            diagram.push_str(&format!("Byte {byte_idx}: {row}\n"));
         }
 
-        // Check for float definition
-        let float_def = self
-            .msg_entries
-            .get(&msg_id)
-            .and_then(|entry| entry.signal_meta.get(&signal_def.name))
-            .and_then(|format_def| format_def.float_format);
-        if let Some(float_format) = float_def {
-            // Note: signal sizes are validated when loading the DBC, so we can assume 32 bits for f32 and 64 bits for f64
-            let float_value = match float_format {
-                FloatFormat::F32 => f32::from_bits(raw_value as u32) as f64,
-                FloatFormat::F64 => f64::from_bits(raw_value),
-            };
-            let scaled_value = float_value * signal_def.factor + signal_def.offset;
-            return Some(DecodedSignal {
-                name: signal_def.name.clone(),
-                value: DecodedSignalValue::new_float_backed_numeric(scaled_value),
-                unit: signal_def.unit.clone(),
-            });
+        diagram.push_str("Legend:\n");
+        for (idx, signal_def) in msg_def.signals.iter().enumerate() {
+            let label = LABELS.get(idx).copied().unwrap_or(b'?') as char;
+            diagram.push_str(&format!("  {} = {}\n", label, signal_def.name));
         }
 
-        // Not enum or float, signed/unsigned integer
-        let scaled_value = raw_value_with_sign as f64 * signal_def.factor + signal_def.offset;
-        Some(DecodedSignal {
-            name: signal_def.name.clone(),
-            value: DecodedSignalValue::new_integer_backed_numeric(
-                scaled_value,
-                raw_value_with_sign,
-            ),
-            unit: signal_def.unit.clone(),
-        })
+        Some(diagram)
     }
 
-    /// Extracts raw signal bits from CAN data.
-    ///
-    /// This function reads the raw bits for a signal from the CAN message data,
-    /// handling both little-endian and big-endian byte ordering according to DBC specifications.
+    /// Returns all loaded can_dbc message definitions.
     ///
-    /// ## Little-Endian Extraction
+    /// This clones every definition into a new vector on each call. Use
+    /// [`iter_msg_defs`](Parser::iter_msg_defs) to iterate without allocating.
     ///
-    /// For little-endian signals, bits are read starting from `start_bit` and proceeding
-    /// through sequential bytes, with results accumulated in the LSB-first order.
+    /// # Returns
     ///
-    /// ## Big-Endian Extraction
+    /// A vector containing all message definitions that have been loaded
+    /// from DBC files. O(n) to convert from internal map.
+    pub fn msg_defs(&self) -> Vec<can_dbc::Message> {
+        self.iter_msg_defs().cloned().collect()
+    }
+
+    /// Iterates over all loaded can_dbc message definitions without cloning them.
     ///
-    /// For big-endian signals, `start_bit` specifies the MSB position using DBC's sawtooth numbering.
-    /// The bits are extracted MSB-first and accumulated into the result.
+    /// Definitions are yielded in no particular order.
     ///
-    /// # Arguments
+    /// # Example
     ///
-    /// * `data` - The raw CAN message bytes
-    /// * `start_bit` - Starting bit position (DBC-style)
-    /// * `size` - Number of bits to extract
-    /// * `byte_order` - Byte order (little-endian or big-endian)
+    /// ```
+    /// use can_decode::Parser;
     ///
-    /// # Returns
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut parser = Parser::new();
+    /// parser.add_from_str(
+    ///     r#"
+    /// BO_ 256 Motor: 2 ECU
+    ///  SG_ Speed : 0|16@1+ (1,0) [0|0] "rpm" Vector__XXX
+    /// BO_ 512 Battery: 1 ECU
+    ///  SG_ SoC : 0|8@1+ (1,0) [0|100] "%" Vector__XXX
+    /// "#,
+    /// )?;
     ///
-    /// The extracted bits as a `u64`, or `None` if data is empty or out of bounds.
-    fn extract_signal_value(
-        &self,
-        data: &[u8],
-        start_bit: usize,
-        size: usize,
-        byte_order: can_dbc::ByteOrder,
-    ) -> Option<u64> {
-        if data.is_empty() || size == 0 {
-            return None;
-        }
-
-        let mut result = 0u64;
-
-        match byte_order {
-            can_dbc::ByteOrder::LittleEndian => {
-                // For little-endian, start_bit gives us the LSB position
-                let start_byte = start_bit / 8;
-                let start_bit_in_byte = start_bit % 8;
-
-                let mut remaining_bits = size;
-                let mut current_byte = start_byte;
-                let mut bit_offset = start_bit_in_byte;
-
-                // Read bits sequentially across bytes
-                while remaining_bits > 0 {
-                    if current_byte >= data.len() {
-                        // Out of bounds: cannot extract signal
-                        return None;
-                    }
-
-                    // Determine how many bits we can read from this byte
-                    let bits_in_this_byte = std::cmp::min(remaining_bits, 8 - bit_offset);
-                    // Create mask for the bits we want from this byte
-                    let mask = low_bits_mask!(bits_in_this_byte, u64) << bit_offset;
-                    // Extract and shift the bits to the LSB position
-                    let byte_value = ((data[current_byte] as u64) & mask) >> bit_offset;
-
-                    // Place the extracted bits in the result, with higher-order bits on the left
-                    result |= byte_value << (size - remaining_bits);
-
-                    remaining_bits -= bits_in_this_byte;
-                    current_byte += 1;
-                    bit_offset = 0;
-                }
-            }
-            can_dbc::ByteOrder::BigEndian => {
-                // For big-endian (Motorola), start_bit specifies the MSB position.
-                // The DBC "sawtooth" numbering:
-                //   - byte_idx = start_bit / 8
-                //   - bit_in_byte = start_bit % 8 (0=LSB, 7=MSB of the byte)
-
-                let start_byte = start_bit / 8;
-                let start_bit_in_byte = start_bit % 8; // Physical bit index (0-7)
-
-                let mut byte_idx = start_byte;
-                let mut bit_in_byte = start_bit_in_byte as i32; // Current bit position, counts downward
-
-                // Extract bits from MSB to LSB
-                for _i in 0..size {
-                    if byte_idx >= data.len() {
-                        // Out of bounds: cannot extract signal
-                        return None;
-                    }
-
-                    // Extract one bit at the current position
-                    let bit_val = (data[byte_idx] >> bit_in_byte) & 1;
-                    // Shift result left and add the extracted bit
-                    result = (result << 1) | (bit_val as u64);
-
-                    // Move to the next bit (downward within the byte)
-                    bit_in_byte -= 1;
-                    // If we've gone past bit 0, move to the next byte
-                    if bit_in_byte < 0 {
-                        bit_in_byte = 7;
-                        byte_idx += 1;
-                    }
-                }
-            }
-        }
-
-        Some(result)
+    /// let signal_count: usize = parser.iter_msg_defs().map(|m| m.signals.len()).sum();
+    /// assert_eq!(signal_count, 2);
+    /// assert_eq!(parser.signal_defs(256).unwrap()[0].name, "Speed");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn iter_msg_defs(&self) -> impl Iterator<Item = &can_dbc::Message> {
+        self.msg_entries.values().map(|entry| &entry.msg_def)
     }
 
-    /// Encodes a CAN message from signal values into raw bytes.
+    /// Returns the metadata of every loaded signal as a flat table.
     ///
-    /// Takes a message ID and a map of signal names to their physical values,
-    /// then encodes them according to the DBC definitions into raw CAN data bytes.
-    /// Applies inverse scaling (offset and factor) and packs bits according to
-    /// the signal's byte order and position.
+    /// Rows are ordered by message ID, and by DBC declaration order within a
+    /// message. This is intended for generating documentation of a database in
+    /// one pass.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use can_decode::Parser;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let dbc = r#"
+    /// VERSION ""
+    ///
+    /// BU_: ECU
+    ///
+    /// BO_ 512 Battery: 2 ECU
+    ///  SG_ Current : 0|16@1- (0.1,0) [-3276.8|3276.7] "A" Vector__XXX
+    ///
+    /// BO_ 256 Motor: 2 ECU
+    ///  SG_ Speed : 7|16@0+ (1,0) [0|8000] "rpm" Vector__XXX
+    /// "#;
+    /// let mut parser = Parser::new();
+    /// parser.add_from_str(dbc)?;
+    ///
+    /// let catalog = parser.signal_catalog();
+    /// assert_eq!(catalog.len(), 2);
+    /// assert_eq!(catalog[0].msg_name, "Motor");
+    /// assert_eq!(catalog[0].signal_name, "Speed");
+    /// assert_eq!(catalog[0].max, 8000.0);
+    /// assert_eq!(catalog[1].signal_name, "Current");
+    /// assert!(catalog[1].is_signed);
+    /// assert_eq!(catalog[1].unit, "A");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn signal_catalog(&self) -> Vec<SignalCatalogEntry> {
+        let mut entries: Vec<&MsgEntry> = self.msg_entries.values().collect();
+        entries.sort_by_key(|entry| entry.msg_def.id.raw());
+
+        entries
+            .into_iter()
+            .flat_map(|entry| {
+                let msg_def = &entry.msg_def;
+                msg_def
+                    .signals
+                    .iter()
+                    .map(move |signal_def| SignalCatalogEntry::new(msg_def, signal_def))
+            })
+            .collect()
+    }
+
+    /// Returns every signal name a decoded message can contain, for a CSV header.
+    ///
+    /// The names are the message's signals in DBC declaration order, including
+    /// all multiplexed groups, followed by its composite signals. Pass the
+    /// result to [`DecodedMessage::csv_row`] to get rows with matching columns.
     ///
     /// # Arguments
     ///
     /// * `msg_id` - The CAN message identifier
-    /// * `signal_values` - Map of signal names to their physical values
     ///
     /// # Returns
     ///
-    /// Returns `Some(Vec<u8>)` containing the encoded message data, or `None` if
-    /// the message ID is not found in the loaded DBC definitions or encoding fails.
+    /// The column names, or `None` if the message is not defined.
+    pub fn csv_header(&self, msg_id: u32) -> Option<Vec<String>> {
+        let msg_entry = self.msg_entries.get(&msg_id)?;
+        let signals = msg_entry.msg_def.signals.iter().map(|s| &s.name);
+        let composites = msg_entry.composites.iter().map(|c| &c.name);
+        Some(signals.chain(composites).cloned().collect())
+    }
+
+    /// Lists signals whose bit layout changed between two databases.
+    ///
+    /// `self` is the older database and `other` the newer one. Signals are
+    /// matched by message ID and signal name, and reported when their start
+    /// bit, size, or byte order differs. Changes that keep the bits in place,
+    /// such as a new factor, offset, or unit, are not reported, and neither are
+    /// signals or messages that only exist in one of the databases. Changes are
+    /// ordered by message ID, and by declaration order in `other` within a
+    /// message.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The newer database
     ///
     /// # Example
     ///
-    /// ```no_run
+    /// ```
     /// use can_decode::Parser;
-    /// use std::path::Path;
-    /// use std::collections::HashMap;
     ///
     /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
-    /// let parser = Parser::from_dbc_file(Path::new("my_database.dbc"))?;
+    /// let mut v1 = Parser::new();
+    /// v1.add_from_str(
+    ///     r#"
+    /// BO_ 256 Motor: 4 ECU
+    ///  SG_ Speed : 0|16@1+ (1,0) [0|0] "rpm" Vector__XXX
+    ///  SG_ Temp : 16|8@1+ (1,-40) [0|0] "degC" Vector__XXX
+    /// "#,
+    /// )?;
     ///
-    /// let signal_values = HashMap::from([
-    ///     ("EngineSpeed".to_string(), 2500.0),
-    ///     ("ThrottlePosition".to_string(), 45.5),
-    /// ]);
+    /// let mut v2 = Parser::new();
+    /// v2.add_from_str(
+    ///     r#"
+    /// BO_ 256 Motor: 4 ECU
+    ///  SG_ Speed : 0|16@1+ (0.5,0) [0|0] "rpm" Vector__XXX
+    ///  SG_ Temp : 16|12@1+ (1,-40) [0|0] "degC" Vector__XXX
+    /// "#,
+    /// )?;
     ///
-    /// if let Some(data) = parser.encode_msg(0x123, &signal_values) {
-    ///     println!("Encoded data: {:02X?}", data);
-    /// }
+    /// // The factor change of Speed keeps its bits in place
+    /// let changes = v1.layout_changes(&v2);
+    /// assert_eq!(changes.len(), 1);
+    /// assert_eq!(changes[0].signal_name, "Temp");
+    /// assert_eq!((changes[0].old.size, changes[0].new.size), (8, 12));
     /// # Ok(())
     /// # }
     /// ```
-    pub fn encode_msg(
-        &self,
-        msg_id: u32,
-        signal_values: &std::collections::HashMap<String, f64>,
-    ) -> Option<Vec<u8>> {
-        let msg_entry = self.msg_entries.get(&msg_id)?;
+    pub fn layout_changes(&self, other: &Parser) -> Vec<LayoutChange> {
+        let layout = |signal_def: &can_dbc::Signal| SignalLayout {
+            start_bit: signal_def.start_bit,
+            size: signal_def.size,
+            byte_order: signal_def.byte_order,
+        };
 
-        let msg_size = msg_entry.msg_def.size as usize;
-        let mut data = vec![0u8; msg_size];
+        let mut entries: Vec<&MsgEntry> = other.msg_entries.values().collect();
+        entries.sort_by_key(|entry| entry.msg_def.id.raw());
 
-        for signal_def in &msg_entry.msg_def.signals {
-            let physical_value = match signal_values.get(&signal_def.name) {
-                Some(&v) => v,
-                _ => {
-                    log::error!(
-                        "Signal {} not provided for message {} during encoding",
-                        signal_def.name,
-                        msg_entry.msg_def.name
-                    );
-                    return None;
-                }
+        let mut changes = Vec::new();
+        for entry in entries {
+            let msg_id = entry.msg_def.id.raw();
+            let Some(old_entry) = self.msg_entries.get(&msg_id) else {
+                continue;
             };
 
-            // encode_signal() modifies the data buffer in place
-            if self
-                .encode_signal(msg_id, signal_def, physical_value, &mut data)
-                .is_none()
-            {
-                log::error!(
-                    "Failed to encode signal {} for message {}",
-                    signal_def.name,
-                    msg_entry.msg_def.name
-                );
-                return None;
+            for signal_def in &entry.msg_def.signals {
+                let Some(old_def) = old_entry
+                    .msg_def
+                    .signals
+                    .iter()
+                    .find(|s| s.name == signal_def.name)
+                else {
+                    continue;
+                };
+
+                let (old, new) = (layout(old_def), layout(signal_def));
+                if old != new {
+                    changes.push(LayoutChange {
+                        msg_id,
+                        msg_name: entry.msg_def.name.clone(),
+                        signal_name: signal_def.name.clone(),
+                        old,
+                        new,
+                    });
+                }
             }
         }
-
-        Some(data)
+        changes
     }
 
-    /// Encodes a CAN message by message name instead of ID.
+    /// Returns the metadata of a message's signals without decoding a frame.
     ///
-    /// Looks up the message by name and then encodes it. This is slower as it
-    /// requires searching through all loaded messages.
+    /// This is the per-message counterpart of
+    /// [`signal_catalog`](Parser::signal_catalog), e.g. for building a UI
+    /// before any frames arrive.
     ///
     /// # Arguments
     ///
-    /// * `msg_name` - The name of the message as defined in the DBC file
-    /// * `signal_values` - Map of signal names to their physical values
+    /// * `msg_id` - The CAN message identifier
     ///
     /// # Returns
     ///
-    /// Returns `Some((msg_id, data))` containing the message ID and encoded data,
-    /// or `None` if the message name is not found or encoding fails.
+    /// One entry per signal in DBC declaration order, or `None` if the message
+    /// is not defined.
     ///
     /// # Example
     ///
-    /// ```no_run
+    /// ```
     /// use can_decode::Parser;
-    /// use std::path::Path;
-    /// use std::collections::HashMap;
     ///
     /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
-    /// let parser = Parser::from_dbc_file(Path::new("my_database.dbc"))?;
-    ///
-    /// let signal_values = HashMap::from([
-    ///     ("EngineSpeed".to_string(), 2500.0),
-    ///     ("ThrottlePosition".to_string(), 45.5),
-    /// ]);
+    /// let mut parser = Parser::new();
+    /// parser.add_from_str(
+    ///     r#"
+    /// BO_ 256 Motor: 3 ECU
+    ///  SG_ Speed : 0|16@1+ (0.5,0) [0|8000] "rpm" Vector__XXX
+    ///  SG_ Temp : 16|8@1- (1,-40) [-40|125] "degC" Vector__XXX
+    /// "#,
+    /// )?;
     ///
-    /// if let Some((msg_id, data)) = parser.encode_msg_by_name("EngineData", &signal_values) {
-    ///     println!("Message ID: {:#X}, Data: {:02X?}", msg_id, data);
-    /// }
+    /// let metadata = parser.signal_metadata(256).unwrap();
+    /// assert_eq!(metadata.len(), 2);
+    /// assert_eq!(metadata[0].signal_name, "Speed");
+    /// assert_eq!((metadata[0].factor, metadata[0].max), (0.5, 8000.0));
+    /// assert_eq!(metadata[1].unit, "degC");
+    /// assert!(metadata[1].is_signed);
+    /// assert!(parser.signal_metadata(512).is_none());
     /// # Ok(())
     /// # }
     /// ```
-    pub fn encode_msg_by_name(
-        &self,
-        msg_name: &str,
-        signal_values: &std::collections::HashMap<String, f64>,
-    ) -> Option<(u32, Vec<u8>)> {
-        let (msg_id, _msg_entry) = self
-            .msg_entries
-            .iter()
-            .find(|(_id, entry)| entry.msg_def.name == msg_name)?;
-
-        let data = self.encode_msg(*msg_id, signal_values)?;
-        Some((*msg_id, data))
+    pub fn signal_metadata(&self, msg_id: u32) -> Option<Vec<SignalCatalogEntry>> {
+        let msg_def = &self.msg_entries.get(&msg_id)?.msg_def;
+        Some(
+            msg_def
+                .signals
+                .iter()
+                .map(|signal_def| SignalCatalogEntry::new(msg_def, signal_def))
+                .collect(),
+        )
     }
 
-    /// Encodes a single signal into raw CAN data.
-    ///
-    /// Converts a physical signal value back to its raw representation by:
-    /// 1. Applying inverse scaling: `raw_value = (physical_value - offset) / factor`
-    /// 2. Converting to the appropriate integer representation (signed/unsigned)
-    /// 3. Clamping to the valid range for the signal's bit width
-    /// 4. Packing the bits into the data buffer at the signal's bit position
-    ///
-    /// For IEEE float/double signals, the physical value is converted directly
-    /// to f32 or f64 and the bit pattern is extracted.
+    /// Returns the can_dbc message definition for a given message ID. O(1) lookup.
     ///
     /// # Arguments
     ///
-    /// * `msg_id` - The CAN message ID (used to look up format definitions)
-    /// * `signal_def` - The DBC signal definition
-    /// * `physical_value` - The physical value to encode
-    /// * `data` - Mutable buffer to write the encoded bits into
+    /// * `msg_id` - The CAN message identifier
     ///
     /// # Returns
     ///
-    /// `Some(())` on success, `None` if encoding fails (e.g., bounds check)
-    fn encode_signal(
-        &self,
-        msg_id: u32,
-        signal_def: &can_dbc::Signal,
-        physical_value: f64,
-        data: &mut [u8],
-    ) -> Option<()> {
-        // Apply inverse scaling to convert physical value back to raw value
-        let scaled_value = (physical_value - signal_def.offset) / signal_def.factor;
-
-        // Check if this is an IEEE float/double signal
-        let float_def = self
-            .msg_entries
-            .get(&msg_id)
-            .and_then(|entry| entry.signal_meta.get(&signal_def.name))
-            .and_then(|format_def| format_def.float_format);
-        if let Some(float_format) = float_def {
-            // For float signals, convert to the appropriate float type and extract bit pattern
-            let float_data = match float_format {
-                FloatFormat::F32 => (scaled_value as f32).to_bits() as u64,
-                FloatFormat::F64 => scaled_value.to_bits(),
-            };
-            return self.insert_signal_value(
-                data,
-                signal_def.start_bit as usize,
-                signal_def.size as usize,
-                signal_def.byte_order,
-                float_data,
-            );
-        }
-
-        // For integer signals, convert and handle signed/unsigned representation
-        let raw_int = if signal_def.value_type == can_dbc::ValueType::Signed {
-            let signed_val = scaled_value.round() as i64;
-            // Use i128 to avoid overflow when size==64
-            let max_value = ((1i128 << (signal_def.size - 1)) - 1) as i64;
-            let min_value = -(1i128 << (signal_def.size - 1)) as i64;
-
-            let clamped = signed_val.max(min_value).min(max_value);
+    /// Returns a reference to the message definition if found, or `None` if
+    /// the message ID is not known.
+    pub fn msg_def(&self, msg_id: u32) -> Option<&can_dbc::Message> {
+        self.msg_entries.get(&msg_id).map(|entry| &entry.msg_def)
+    }
 
-            // Two's complement: negative values cast to u64 already gives correct bit pattern
-            let mask = low_bits_mask!(signal_def.size as usize, u64);
-            (clamped as u64) & mask
-        } else {
-            let unsigned_val = scaled_value.round() as u64;
-            let max_value = low_bits_mask!(signal_def.size as usize, u64);
-            unsigned_val.min(max_value)
-        };
+    /// Returns the IDs of all loaded messages that use standard (11-bit) CAN IDs.
+    ///
+    /// # Returns
+    ///
+    /// A sorted vector of message IDs as accepted by [`decode_msg`](Parser::decode_msg).
+    pub fn standard_ids(&self) -> Vec<u32> {
+        self.ids_matching(|entry| !entry.is_extended)
+    }
 
-        // Insert the encoded bits into the data buffer
-        self.insert_signal_value(
-            data,
-            signal_def.start_bit as usize,
-            signal_def.size as usize,
-            signal_def.byte_order,
-            raw_int,
-        )
+    /// Returns the IDs of all loaded messages that use extended (29-bit) CAN IDs.
+    ///
+    /// # Returns
+    ///
+    /// A sorted vector of message IDs as accepted by [`decode_msg`](Parser::decode_msg).
+    /// These are the raw DBC IDs, so bit 31 (the extended flag) is set; mask with
+    /// `0x1FFF_FFFF` to compare against the standard address space.
+    pub fn extended_ids(&self) -> Vec<u32> {
+        self.ids_matching(|entry| entry.is_extended)
     }
 
-    /// Inserts raw signal bits into CAN data.
+    /// Reports how many of the observed message IDs have a loaded definition.
     ///
-    /// This function packs raw bits (typically from encoding) into the CAN message buffer,
-    /// handling both little-endian and big-endian byte ordering.
+    /// Every entry of `ids` is counted, so passing the IDs of all frames in a
+    /// log weights the result by traffic, while passing distinct IDs counts
+    /// messages.
     ///
-    /// ## Little-Endian Insertion
+    /// # Arguments
     ///
-    /// For little-endian signals, bits are packed starting from `start_bit` across sequential
-    /// bytes, with the LSBs of the value written first.
+    /// * `ids` - The observed CAN message IDs
     ///
-    /// ## Big-Endian Insertion
+    /// # Example
     ///
-    /// For big-endian signals, `start_bit` specifies the MSB position using DBC's sawtooth numbering.
-    /// Bits are packed from MSB to LSB starting at that position.
+    /// ```
+    /// use can_decode::{Coverage, Parser};
     ///
-    /// # Arguments
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let dbc = r#"
+    /// VERSION ""
     ///
-    /// * `data` - Mutable buffer of CAN message bytes to modify
-    /// * `start_bit` - Starting bit position (DBC-style)
-    /// * `size` - Number of bits to insert
-    /// * `byte_order` - Byte order (little-endian or big-endian)
-    /// * `value` - The raw value to insert
+    /// BU_: ECU
     ///
-    /// # Returns
+    /// BO_ 256 Motor: 1 ECU
+    ///  SG_ Speed : 0|8@1+ (1,0) [0|255] "" Vector__XXX
     ///
-    /// `Some(())` on success, or `None` if the signal extends beyond the data bounds.
-    fn insert_signal_value(
-        &self,
-        data: &mut [u8],
-        start_bit: usize,
-        size: usize,
-        byte_order: can_dbc::ByteOrder,
-        value: u64,
-    ) -> Option<()> {
-        if data.is_empty() || size == 0 {
-            return None;
-        }
-
-        let total_bits = data.len() * 8;
-        if start_bit + size > total_bits {
-            // Signal extends beyond the data buffer
-            return None;
-        }
-
-        match byte_order {
-            can_dbc::ByteOrder::LittleEndian => {
-                let start_byte = start_bit / 8;
-                let start_bit_in_byte = start_bit % 8;
-
-                let mut remaining_bits = size;
-                let mut current_byte = start_byte;
-                let mut bit_offset = start_bit_in_byte;
-                let mut value_offset = 0;
-
-                // Write bits sequentially across bytes
-                while remaining_bits > 0 && current_byte < data.len() {
-                    // Determine how many bits we can write to this byte
-                    let bits_in_this_byte = std::cmp::min(remaining_bits, 8 - bit_offset);
-                    // Create a mask for the bits we're about to write
-                    let mask = low_bits_mask!(bits_in_this_byte, u8) << bit_offset;
-
-                    // Extract the bits we want from the value
-                    let value_mask = low_bits_mask!(bits_in_this_byte, u64);
-                    let value_bits = ((value >> value_offset) & value_mask) as u8;
-
-                    // Clear the target bits in the data byte and set new bits
-                    data[current_byte] =
-                        (data[current_byte] & !mask) | ((value_bits << bit_offset) & mask);
-
-                    remaining_bits -= bits_in_this_byte;
-                    value_offset += bits_in_this_byte;
-                    current_byte += 1;
-                    bit_offset = 0;
-                }
-            }
-            can_dbc::ByteOrder::BigEndian => {
-                let start_byte = start_bit / 8;
-                let start_bit_in_byte = start_bit % 8;
-
-                let mut byte_idx = start_byte;
-                let mut bit_in_byte = start_bit_in_byte as i32;
-
-                // Write bits from MSB to LSB
-                for i in 0..size {
-                    if byte_idx >= data.len() {
-                        return None;
-                    }
-
-                    // Extract the i-th bit from the value (starting from MSB)
-                    let bit_val = ((value >> (size - 1 - i)) & 1) as u8;
-                    // Create a mask for the target bit position
-                    let mask = 1u8 << bit_in_byte;
-                    // Clear the bit and set the new value
-                    data[byte_idx] = (data[byte_idx] & !mask) | (bit_val << bit_in_byte);
-
-                    // Move to the next bit (downward within the byte)
-                    bit_in_byte -= 1;
-                    // If we've gone past bit 0, move to the next byte
-                    if bit_in_byte < 0 {
-                        bit_in_byte = 7;
-                        byte_idx += 1;
-                    }
-                }
+    /// BO_ 512 Battery: 1 ECU
+    ///  SG_ SoC : 0|8@1+ (1,0) [0|100] "%" Vector__XXX
+    /// "#;
+    /// let mut parser = Parser::new();
+    /// parser.add_from_str(dbc)?;
+    ///
+    /// // IDs of every frame in a log, so repeated IDs are counted each time
+    /// let coverage = parser.decode_coverage(&[256, 256, 0x700, 512, 0x123, 0x700]);
+    /// assert_eq!(
+    ///     coverage,
+    ///     Coverage {
+    ///         known: 3,
+    ///         unknown: 3,
+    ///         unknown_ids: vec![0x123, 0x700],
+    ///     }
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn decode_coverage(&self, ids: &[u32]) -> Coverage {
+        let mut coverage = Coverage::default();
+
+        for id in ids {
+            if self.msg_entries.contains_key(id) {
+                coverage.known += 1;
+            } else {
+                coverage.unknown += 1;
+                coverage.unknown_ids.push(*id);
             }
         }
 
-        Some(())
+        coverage.unknown_ids.sort_unstable();
+        coverage.unknown_ids.dedup();
+        coverage
     }
 
-    /// Returns all signal definitions for a given message ID.
+    /// Returns whether a message uses an extended (29-bit) CAN ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `msg_id` - The CAN message identifier
+    ///
+    /// # Returns
+    ///
+    /// `Some(true)` for extended IDs, `Some(false)` for standard (11-bit) IDs,
+    /// or `None` if the message ID is not known.
+    pub fn is_extended(&self, msg_id: u32) -> Option<bool> {
+        self.msg_entries.get(&msg_id).map(|entry| entry.is_extended)
+    }
+
+    /// Collects the sorted IDs of all messages whose entry matches `pred`.
+    fn ids_matching(&self, pred: impl Fn(&MsgEntry) -> bool) -> Vec<u32> {
+        let mut ids: Vec<u32> = self
+            .msg_entries
+            .iter()
+            .filter(|(_id, entry)| pred(entry))
+            .map(|(id, _entry)| *id)
+            .collect();
+        ids.sort_unstable();
+        ids
+    }
+
+    /// Returns the IDs of all messages received by a node.
+    ///
+    /// A node receives a message if it is listed as a receiver of any of the
+    /// message's signals.
+    ///
+    /// # Arguments
+    ///
+    /// * `node` - The node name as defined in the DBC file
+    ///
+    /// # Returns
+    ///
+    /// A sorted vector of message IDs.
+    pub fn messages_received_by(&self, node: &str) -> Vec<u32> {
+        let mut ids: Vec<u32> = self
+            .msg_entries
+            .iter()
+            .filter(|(_id, entry)| {
+                entry
+                    .msg_def
+                    .signals
+                    .iter()
+                    .any(|signal_def| signal_def.receivers.iter().any(|r| r == node))
+            })
+            .map(|(id, _entry)| *id)
+            .collect();
+        ids.sort_unstable();
+        ids
+    }
+
+    /// Returns the node that transmits a message.
     ///
     /// # Arguments
     ///
@@ -1213,80 +7900,165 @@ impl Parser {
     ///
     /// # Returns
     ///
-    /// Returns `Some(Vec<Signal>)` if the message ID is known, or `None` otherwise.
+    /// The transmitter's node name, or `None` if the message is unknown or has
+    /// no transmitter (`Vector__XXX`).
     ///
     /// # Example
     ///
-    /// ```no_run
+    /// ```
     /// use can_decode::Parser;
-    /// use std::path::Path;
     ///
     /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
-    /// let parser = Parser::from_dbc_file(Path::new("my_database.dbc"))?;
+    /// let dbc = r#"
+    /// VERSION ""
     ///
-    /// if let Some(signals) = parser.signal_defs(0x123) {
-    ///     for signal in signals {
-    ///         println!("Signal: {}", signal.name);
-    ///     }
-    /// }
+    /// BU_: VCU BMS Dash
+    ///
+    /// BO_ 256 Limits: 2 BMS
+    ///  SG_ MaxCurrent : 0|8@1+ (1,0) [0|255] "A" VCU,Dash
+    ///  SG_ MaxTemp : 8|8@1+ (1,-40) [-40|215] "degC" Vector__XXX
+    ///
+    /// BO_ 512 Debug: 1 Vector__XXX
+    ///  SG_ Value : 0|8@1+ (1,0) [0|255] "" Vector__XXX
+    /// "#;
+    /// let mut parser = Parser::new();
+    /// parser.add_from_str(dbc)?;
+    ///
+    /// assert_eq!(parser.message_transmitter(256), Some("BMS"));
+    /// assert_eq!(parser.message_transmitter(512), None);
+    ///
+    /// assert_eq!(
+    ///     parser.signal_receivers(256, "MaxCurrent"),
+    ///     Some(vec!["VCU".to_string(), "Dash".to_string()])
+    /// );
+    /// assert_eq!(parser.signal_receivers(256, "MaxTemp"), Some(vec![]));
+    /// assert_eq!(parser.signal_receivers(256, "Missing"), None);
     /// # Ok(())
     /// # }
     /// ```
-    pub fn signal_defs(&self, msg_id: u32) -> Option<Vec<can_dbc::Signal>> {
-        let msg_entry = self.msg_entries.get(&msg_id)?;
-        Some(msg_entry.msg_def.signals.clone())
+    pub fn message_transmitter(&self, msg_id: u32) -> Option<&str> {
+        match &self.msg_entries.get(&msg_id)?.msg_def.transmitter {
+            can_dbc::Transmitter::NodeName(name) => Some(name),
+            can_dbc::Transmitter::VectorXXX => None,
+        }
     }
 
-    /// Returns the message-level description/comment for a message ID.
+    /// Returns the nodes that receive a signal.
     ///
-    /// # Returns
+    /// # Arguments
     ///
-    /// A reference to the message comment if present, or `None` if the message
-    /// is unknown or has no DBC comment.
-    pub fn msg_desc(&self, msg_id: u32) -> Option<&str> {
-        self.msg_entries
-            .get(&msg_id)
-            .and_then(|entry| entry.msg_desc.as_deref())
-    }
-
-    /// Returns the signal-level description/comment for a signal within a message.
+    /// * `msg_id` - The CAN message identifier
+    /// * `signal_name` - The name of the signal as defined in the DBC file
     ///
     /// # Returns
     ///
-    /// A reference to the signal comment if present, or `None` if the message,
-    /// signal, or comment is not available.
-    pub fn signal_desc(&self, msg_id: u32, signal_name: &str) -> Option<&str> {
-        self.msg_entries
-            .get(&msg_id)
-            .and_then(|entry| entry.signal_meta.get(signal_name))
-            .and_then(|meta| meta.sig_comment.as_deref())
+    /// The receiving node names in DBC order, empty if the signal has no
+    /// receivers (`Vector__XXX`), or `None` if the message or signal is unknown.
+    pub fn signal_receivers(&self, msg_id: u32, signal_name: &str) -> Option<Vec<String>> {
+        let signal_def = self
+            .msg_entries
+            .get(&msg_id)?
+            .msg_def
+            .signals
+            .iter()
+            .find(|s| s.name == signal_name)?;
+
+        Some(
+            signal_def
+                .receivers
+                .iter()
+                .filter(|receiver| *receiver != "Vector__XXX")
+                .cloned()
+                .collect(),
+        )
     }
 
-    /// Returns all loaded can_dbc message definitions.
+    /// Returns the IDs of all messages whose signals don't share one byte order.
+    ///
+    /// A message mixing little-endian and big-endian signals is almost always a
+    /// DBC authoring mistake, so this is useful as a lint when validating a
+    /// database.
     ///
     /// # Returns
     ///
-    /// A vector containing all message definitions that have been loaded
-    /// from DBC files. O(n) to convert from internal map.
-    pub fn msg_defs(&self) -> Vec<can_dbc::Message> {
-        self.msg_entries
-            .values()
-            .map(|entry| entry.msg_def.clone())
-            .collect()
+    /// A sorted vector of message IDs.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use can_decode::Parser;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let dbc = r#"
+    /// VERSION ""
+    ///
+    /// BU_: ECU
+    ///
+    /// BO_ 256 Consistent: 4 ECU
+    ///  SG_ A : 0|16@1+ (1,0) [0|65535] "" Vector__XXX
+    ///  SG_ B : 16|16@1+ (1,0) [0|65535] "" Vector__XXX
+    ///
+    /// BO_ 257 Mixed: 4 ECU
+    ///  SG_ A : 0|16@1+ (1,0) [0|65535] "" Vector__XXX
+    ///  SG_ B : 23|16@0+ (1,0) [0|65535] "" Vector__XXX
+    /// "#;
+    /// let mut parser = Parser::new();
+    /// parser.add_from_str(dbc)?;
+    ///
+    /// assert_eq!(parser.mixed_byte_order_messages(), [257]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn mixed_byte_order_messages(&self) -> Vec<u32> {
+        self.ids_matching(|entry| {
+            let mut byte_orders = entry.msg_def.signals.iter().map(|s| s.byte_order);
+            byte_orders
+                .next()
+                .is_some_and(|first| byte_orders.any(|order| order != first))
+        })
     }
 
-    /// Returns the can_dbc message definition for a given message ID. O(1) lookup.
-    ///
-    /// # Arguments
+    /// Returns all integer signals whose raw values can't be exactly represented as `f64`.
     ///
-    /// * `msg_id` - The CAN message identifier
+    /// An `f64` can only represent integers exactly up to 2^53 in magnitude, so
+    /// unsigned signals wider than 53 bits and signed signals wider than 54 bits
+    /// may lose precision in [`DecodedSignalValue::physical`]. For these signals,
+    /// use the exact [`DecodedSignalValue::raw`] value instead. IEEE float/double
+    /// signals are never flagged.
     ///
     /// # Returns
     ///
-    /// Returns a reference to the message definition if found, or `None` if
-    /// the message ID is not known.
-    pub fn msg_def(&self, msg_id: u32) -> Option<&can_dbc::Message> {
-        self.msg_entries.get(&msg_id).map(|entry| &entry.msg_def)
+    /// A vector of `(msg_id, signal_name)` pairs, sorted by message ID and then
+    /// by signal name.
+    pub fn precision_lossy_signals(&self) -> Vec<(u32, String)> {
+        const F64_EXACT_INT_BITS: u64 = f64::MANTISSA_DIGITS as u64;
+
+        let mut lossy: Vec<(u32, String)> = self
+            .msg_entries
+            .iter()
+            .flat_map(|(msg_id, entry)| {
+                entry
+                    .msg_def
+                    .signals
+                    .iter()
+                    .filter(|signal_def| {
+                        let is_float = entry
+                            .signal_meta
+                            .get(&signal_def.name)
+                            .is_some_and(|meta| meta.float_format.is_some());
+                        // The sign bit doesn't count towards the magnitude
+                        let magnitude_bits = match signal_def.value_type {
+                            can_dbc::ValueType::Signed => signal_def.size.saturating_sub(1),
+                            can_dbc::ValueType::Unsigned => signal_def.size,
+                        };
+                        !is_float && magnitude_bits > F64_EXACT_INT_BITS
+                    })
+                    .map(|signal_def| (*msg_id, signal_def.name.clone()))
+            })
+            .collect();
+
+        lossy.sort_unstable();
+        lossy
     }
 
     /// Exposes the internal message entries map.
@@ -1321,6 +8093,263 @@ impl Parser {
         self.msg_entries.get(&msg_id)
     }
 
+    /// Sets how signals with a NaN or infinite physical value are handled.
+    ///
+    /// Non-finite values can come from IEEE float signals or from pathological
+    /// DBC scaling. The default, [`NonFinitePolicy::Propagate`], keeps them.
+    ///
+    /// # Arguments
+    ///
+    /// * `policy` - The policy to apply to subsequent decodes
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use can_decode::{NonFinitePolicy, Parser};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let dbc = r#"
+    /// VERSION ""
+    ///
+    /// BU_: ECU
+    ///
+    /// BO_ 256 Sensor: 5 ECU
+    ///  SG_ Pressure : 0|32@1- (1,0) [0|0] "bar" Vector__XXX
+    ///  SG_ Status : 32|8@1+ (1,0) [0|255] "" Vector__XXX
+    ///
+    /// SIG_VALTYPE_ 256 Pressure : 1;
+    /// "#;
+    /// let mut parser = Parser::new();
+    /// parser.add_from_str(dbc)?;
+    ///
+    /// let mut data = [0u8; 5];
+    /// data[..4].copy_from_slice(&f32::NAN.to_le_bytes());
+    /// data[4] = 7;
+    ///
+    /// // By default the NaN is kept
+    /// let decoded = parser.decode_msg(256, &data).unwrap();
+    /// assert!(decoded.signals["Pressure"].value.physical.is_nan());
+    ///
+    /// // Omit drops the signal but keeps the rest of the message
+    /// parser.set_non_finite_policy(NonFinitePolicy::Omit);
+    /// let decoded = parser.decode_msg(256, &data).unwrap();
+    /// assert!(!decoded.signals.contains_key("Pressure"));
+    /// assert_eq!(decoded.signals["Status"].value.physical, 7.0);
+    ///
+    /// // Infinities are non-finite too
+    /// data[..4].copy_from_slice(&f32::INFINITY.to_le_bytes());
+    /// let decoded = parser.decode_msg(256, &data).unwrap();
+    /// assert!(!decoded.signals.contains_key("Pressure"));
+    ///
+    /// // Error fails the whole decode
+    /// parser.set_non_finite_policy(NonFinitePolicy::Error);
+    /// assert!(parser.decode_msg(256, &data).is_none());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_non_finite_policy(&mut self, policy: NonFinitePolicy) {
+        self.non_finite_policy = policy;
+        self.decode_cache.invalidate();
+    }
+
+    /// Sets whether decoding logs a warning for out-of-range signal values.
+    ///
+    /// Every decoded signal reports whether its physical value lies within the
+    /// DBC `[min|max]` range in [`DecodedSignal::in_range`]. With warnings
+    /// enabled, out-of-range values are also logged, which helps spot corrupt
+    /// frames or a wrong DBC during replay. Disabled by default.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether to log out-of-range values
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use can_decode::Parser;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut parser = Parser::new();
+    /// parser.add_from_str(
+    ///     r#"
+    /// BO_ 256 Battery: 2 ECU
+    ///  SG_ SoC : 0|8@1+ (0.5,0) [0|100] "%" Vector__XXX
+    ///  SG_ Counter : 8|8@1+ (1,0) [0|0] "" Vector__XXX
+    /// "#,
+    /// )?;
+    /// parser.set_range_warnings(true);
+    ///
+    /// let decoded = parser.decode_msg(256, &[250, 3]).unwrap();
+    /// let soc = &decoded.signals["SoC"];
+    /// assert_eq!((soc.min, soc.max), (Some(0.0), Some(100.0)));
+    /// assert_eq!(soc.in_range, Some(false));
+    /// assert_eq!(decoded.signals["Counter"].in_range, None);
+    ///
+    /// let decoded = parser.decode_msg(256, &[100, 3]).unwrap();
+    /// assert_eq!(decoded.signals["SoC"].in_range, Some(true));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_range_warnings(&mut self, enabled: bool) {
+        self.warn_out_of_range = enabled;
+        self.decode_cache.invalidate();
+    }
+
+    /// Sets whether decoding detects "signal not available" (SNA) sentinels.
+    ///
+    /// Automotive DBCs commonly reserve the all-ones raw value of a signal to
+    /// mean that the sender has no valid value, which would otherwise decode to
+    /// a plausible-looking maximum. With detection enabled,
+    /// [`DecodedSignal::is_sna`] is set for integer signals of at least 2 bits
+    /// whose raw value is the largest they can hold, and for values whose value
+    /// description (`VAL_`) reads "SNA" or "not available". The largest raw
+    /// value has all bits set for unsigned signals, and all bits but the sign
+    /// bit for signed signals, where all ones is -1. Single-bit signals and IEEE
+    /// floats are never detected this way. Disabled by default; sentinels set with
+    /// [`set_sna_value`](Parser::set_sna_value) apply either way.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether to detect SNA values
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use can_decode::Parser;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let dbc = r#"
+    /// VERSION ""
+    ///
+    /// BU_: ECU
+    ///
+    /// BO_ 256 Vehicle: 5 ECU
+    ///  SG_ Speed : 0|12@1+ (0.1,0) [0|300] "km/h" Vector__XXX
+    ///  SG_ Gear : 12|4@1+ (1,0) [0|15] "" Vector__XXX
+    ///  SG_ Brake : 16|1@1+ (1,0) [0|1] "" Vector__XXX
+    ///  SG_ Current : 24|16@1- (0.1,0) [-3000|3000] "A" Vector__XXX
+    ///
+    /// VAL_ 256 Gear 0 "P" 1 "R" 2 "N" 3 "D" 14 "SNA";
+    /// "#;
+    /// let mut parser = Parser::new();
+    /// parser.add_from_str(dbc)?;
+    /// parser.set_sna_detection(true);
+    ///
+    /// // Speed is all ones (409.5 km/h), Gear is labeled "SNA", Brake is a plain
+    /// // flag, and the signed Current is at its largest value (0x7FFF)
+    /// let decoded = parser.decode_msg(256, &[0xFF, 0xEF, 0x01, 0xFF, 0x7F]).unwrap();
+    /// assert!(decoded.signals["Speed"].is_sna);
+    /// assert!(decoded.signals["Gear"].is_sna);
+    /// assert!(!decoded.signals["Brake"].is_sna);
+    /// assert!(decoded.signals["Current"].is_sna);
+    ///
+    /// // All ones in a signed signal is -1 (-0.1 A), a valid value
+    /// let decoded = parser.decode_msg(256, &[0xE8, 0x33, 0x00, 0xFF, 0xFF]).unwrap();
+    /// assert!(!decoded.signals["Speed"].is_sna);
+    /// assert!(!decoded.signals["Gear"].is_sna);
+    /// assert_eq!(decoded.signals["Current"].value.physical, -0.1);
+    /// assert!(!decoded.signals["Current"].is_sna);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_sna_detection(&mut self, enabled: bool) {
+        self.sna_detection = enabled;
+        self.decode_cache.invalidate();
+    }
+
+    /// Sets how decoding handles multiplexor values that select no defined group.
+    ///
+    /// The default, [`UnknownMuxPolicy::Ignore`], decodes such frames without
+    /// any multiplexed signals.
+    ///
+    /// # Arguments
+    ///
+    /// * `policy` - The policy to apply to subsequent decodes
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use can_decode::{Parser, SignalDecodeError, UnknownMuxPolicy};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut parser = Parser::new();
+    /// parser.add_from_str(
+    ///     r#"
+    /// BO_ 256 Mixed: 2 ECU
+    ///  SG_ Mux M : 0|4@1+ (1,0) [0|15] "" Vector__XXX
+    ///  SG_ Temp m0 : 8|8@1+ (1,-40) [-40|215] "degC" Vector__XXX
+    ///  SG_ Speed m1 : 8|8@1+ (1,0) [0|250] "km/h" Vector__XXX
+    /// "#,
+    /// )?;
+    ///
+    /// // Switch value 7 selects no group
+    /// let decoded = parser.decode_msg(256, &[0x07, 0x2A]).unwrap();
+    /// assert_eq!(decoded.signals.len(), 1);
+    ///
+    /// parser.set_unknown_mux_policy(UnknownMuxPolicy::Error);
+    /// assert!(parser.decode_msg(256, &[0x07, 0x2A]).is_none());
+    /// assert!(parser.decode_msg(256, &[0x01, 0x2A]).is_some());
+    ///
+    /// let (_, errors) = parser.decode_msg_detailed(256, &[0x07, 0x2A]).unwrap();
+    /// assert_eq!(
+    ///     errors,
+    ///     [SignalDecodeError::UnknownMuxValue {
+    ///         signal: "Mux".to_string(),
+    ///         mux_value: 7,
+    ///     }]
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_unknown_mux_policy(&mut self, policy: UnknownMuxPolicy) {
+        self.unknown_mux_policy = policy;
+        self.decode_cache.invalidate();
+    }
+
+    /// Sets how message IDs passed to the decode methods are mapped to definitions.
+    ///
+    /// The default, [`IdFlagMode::Raw`], looks IDs up exactly as given. Use
+    /// [`IdFlagMode::SocketCan`] to pass `can_id` values straight from SocketCAN
+    /// frames, flag bits included.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - The mode to apply to subsequent decodes
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use can_decode::{IdFlagMode, Parser};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut parser = Parser::new();
+    /// parser.add_from_str(
+    ///     r#"
+    /// BO_ 2566844926 Extended: 1 ECU
+    ///  SG_ Value : 0|8@1+ (1,0) [0|0] "" Vector__XXX
+    /// "#,
+    /// )?;
+    ///
+    /// // SocketCAN ID of an extended RTR frame: EFF and RTR flags set
+    /// let can_id = 0x18FEF1FE | 0x8000_0000 | 0x4000_0000;
+    /// assert!(parser.decode_msg(can_id, &[0x2A]).is_none());
+    ///
+    /// parser.set_id_flag_handling(IdFlagMode::SocketCan);
+    /// let decoded = parser.decode_msg(can_id, &[0x2A]).unwrap();
+    /// assert_eq!(decoded.signals["Value"].value.physical, 42.0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_id_flag_handling(&mut self, mode: IdFlagMode) {
+        self.id_flag_mode = mode;
+        self.decode_cache.invalidate();
+    }
+
+    /// Looks up the definition for a message ID passed to a decode method.
+    fn lookup(&self, msg_id: u32) -> Option<&MsgEntry> {
+        self.msg_entries.get(&self.id_flag_mode.apply(msg_id))
+    }
+
     /// Clears all loaded message definitions.
     ///
     /// After calling this method, the parser will have no message definitions
@@ -1337,6 +8366,11 @@ impl Parser {
     /// ```
     pub fn clear(&mut self) {
         self.msg_entries.clear();
+        self.msg_ids_by_name.clear();
+        #[cfg(feature = "j1939")]
+        self.msg_ids_by_pgn.clear();
+        self.buses.clear();
+        self.decode_cache.invalidate();
     }
 }
 
@@ -1345,3 +8379,232 @@ impl Default for Parser {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Creates a parser with the definitions in `dbc` loaded.
+    fn parser(dbc: &str) -> Parser {
+        let mut parser = Parser::new();
+        parser.add_from_str(dbc).unwrap();
+        parser
+    }
+
+    #[test]
+    fn sign_extends_big_endian_signals() {
+        let parser = parser(
+            r#"
+BO_ 256 Motorola: 8 ECU
+ SG_ Nibble : 7|4@0- (1,0) [-8|7] "" Vector__XXX
+ SG_ Twelve : 3|12@0- (1,0) [-2048|2047] "" Vector__XXX
+ SG_ Twenty : 23|20@0- (1,0) [-524288|524287] "" Vector__XXX
+"#,
+        );
+
+        // Nibble = -3 (0xD), Twelve = -1000 (0xC18) spanning bytes 0-1,
+        // Twenty = -123457 (0xE1DBF) spanning bytes 2-4
+        let data = [0xDC, 0x18, 0xE1, 0xDB, 0xF0, 0x00, 0x00, 0x00];
+        let decoded = parser.decode_msg(256, &data).unwrap();
+        assert_eq!(decoded.signals["Nibble"].value.raw, Some(-3));
+        assert_eq!(decoded.signals["Twelve"].value.raw, Some(-1000));
+        assert_eq!(decoded.signals["Twenty"].value.raw, Some(-123457));
+
+        // The most negative values only have the sign bit set
+        let data = [0x88, 0x00, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00];
+        let decoded = parser.decode_msg(256, &data).unwrap();
+        assert_eq!(decoded.signals["Nibble"].value.raw, Some(-8));
+        assert_eq!(decoded.signals["Twelve"].value.raw, Some(-2048));
+        assert_eq!(decoded.signals["Twenty"].value.raw, Some(-524288));
+
+        // The sign bit is bit `size - 1` of the extracted value
+        let twelve = &parser.lookup(256).unwrap().msg_def.signals[1];
+        assert_eq!(Parser::sign_extend(twelve, 0x800), -2048);
+        assert_eq!(Parser::sign_extend(twelve, 0x7FF), 2047);
+    }
+
+    #[test]
+    fn extracts_big_endian_signals_straddling_bytes() {
+        let parser = parser(
+            r#"
+BO_ 256 Nibble: 3 ECU
+ SG_ Value : 11|12@0+ (1,0) [0|4095] "" Vector__XXX
+
+BO_ 257 Offset: 3 ECU
+ SG_ Value : 13|12@0+ (1,0) [0|4095] "" Vector__XXX
+"#,
+        );
+        let big_endian = can_dbc::ByteOrder::BigEndian;
+
+        // MSB at byte 1 bit 3: 0xA from byte 1's low nibble, then 0xBC from byte 2
+        let data = [0xF0, 0xFA, 0xBC];
+        assert_eq!(
+            parser.extract_signal_value(&data, 11, 12, big_endian),
+            Some(0xABC)
+        );
+        let decoded = parser.decode_msg(256, &data).unwrap();
+        assert_eq!(decoded.signals["Value"].value.raw, Some(0xABC));
+
+        // MSB at byte 1 bit 5: six bits from byte 1, then bits 7-2 of byte 2
+        let data = [0xFF, 0xEA, 0xF3];
+        assert_eq!(
+            parser.extract_signal_value(&data, 13, 12, big_endian),
+            Some(0xABC)
+        );
+        let decoded = parser.decode_msg(257, &data).unwrap();
+        assert_eq!(decoded.signals["Value"].value.raw, Some(0xABC));
+    }
+
+    #[test]
+    fn selects_4_bit_multiplexed_layouts() {
+        // Two alternative layouts selected by a 4-bit switch
+        let parser = parser(
+            r#"
+BO_ 768 CellData: 8 BMS
+ SG_ Page M : 0|4@1+ (1,0) [0|15] "" Vector__XXX
+ SG_ Alive : 4|4@1+ (1,0) [0|15] "" Vector__XXX
+ SG_ Voltage1 m3 : 8|16@1+ (0.001,0) [0|65.535] "V" Vector__XXX
+ SG_ Voltage2 m3 : 24|16@1+ (0.001,0) [0|65.535] "V" Vector__XXX
+ SG_ Temp1 m15 : 8|8@1- (1,0) [-128|127] "degC" Vector__XXX
+ SG_ Temp2 m15 : 16|8@1- (1,0) [-128|127] "degC" Vector__XXX
+"#,
+        );
+        let names = |data: &[u8]| -> Vec<String> {
+            parser
+                .decode_msg(768, data)
+                .unwrap()
+                .signals
+                .into_keys()
+                .collect()
+        };
+
+        let voltages = [0x53, 0x10, 0x0E, 0x7C, 0x0D, 0, 0, 0];
+        assert_eq!(names(&voltages), ["Page", "Alive", "Voltage1", "Voltage2"]);
+
+        let temps = [0x5F, 25, 0xFB, 0, 0, 0, 0, 0];
+        assert_eq!(names(&temps), ["Page", "Alive", "Temp1", "Temp2"]);
+        let decoded = parser.decode_msg(768, &temps).unwrap();
+        assert_eq!(decoded.signals["Temp2"].value.physical, -5.0);
+
+        // Switch values that select neither layout leave only the plain signals
+        let unused_page = [0x57, 0, 0, 0, 0, 0, 0, 0];
+        assert_eq!(names(&unused_page), ["Page", "Alive"]);
+
+        let msg_entry = parser.lookup(768).unwrap();
+        let active = |mux_value| -> Vec<&str> {
+            msg_entry
+                .msg_def
+                .signals
+                .iter()
+                .filter(|s| Parser::is_signal_active(msg_entry, s, mux_value))
+                .map(|s| s.name.as_str())
+                .collect()
+        };
+        assert_eq!(active(Some(3)), ["Page", "Alive", "Voltage1", "Voltage2"]);
+        assert_eq!(active(Some(15)), ["Page", "Alive", "Temp1", "Temp2"]);
+        assert_eq!(active(Some(7)), ["Page", "Alive"]);
+        assert_eq!(active(None), ["Page", "Alive"]);
+    }
+
+    /// Results never depend on the host's byte order: the fast path names the
+    /// payload's byte order explicitly (`from_le_bytes`), and the other paths
+    /// work on single bytes. Both agree with values assembled by shifting bytes,
+    /// which means the same on little- and big-endian hosts.
+    #[test]
+    fn extraction_is_independent_of_host_byte_order() {
+        let parser = parser(
+            r#"
+BO_ 256 Mixed: 8 ECU
+ SG_ Aligned16 : 0|16@1+ (1,0) [0|0] "" Vector__XXX
+ SG_ Unaligned : 20|12@1+ (1,0) [0|0] "" Vector__XXX
+ SG_ Motorola : 39|16@0+ (1,0) [0|0] "" Vector__XXX
+ SG_ Float : 32|32@1- (1,0) [0|0] "" Vector__XXX
+SIG_VALTYPE_ 256 Float : 1;
+"#,
+        );
+
+        let data = [0x34, 0x12, 0xC0, 0xAB, 0x00, 0x00, 0x28, 0x42];
+        let decoded = parser.decode_msg(256, &data).unwrap();
+        let raw = |name: &str| decoded.signals[name].value.raw;
+
+        let le16 = data[0] as i128 | (data[1] as i128) << 8;
+        assert_eq!(raw("Aligned16"), Some(le16));
+        assert_eq!(
+            raw("Unaligned"),
+            Some((data[2] as i128 >> 4) | (data[3] as i128) << 4)
+        );
+        assert_eq!(
+            raw("Motorola"),
+            Some((data[4] as i128) << 8 | data[5] as i128)
+        );
+
+        let le32 = (4..8).rev().fold(0u32, |acc, i| acc << 8 | data[i] as u32);
+        assert_eq!(
+            decoded.signals["Float"].value.physical,
+            f32::from_bits(le32) as f64
+        );
+        assert_eq!(decoded.signals["Float"].value.physical, 42.0);
+
+        // The 64-bit fast path
+        let le64 = (0..8).rev().fold(0u64, |acc, i| acc << 8 | data[i] as u64);
+        let little_endian = can_dbc::ByteOrder::LittleEndian;
+        assert_eq!(
+            parser.extract_signal_value(&data, 0, 64, little_endian),
+            Some(le64)
+        );
+    }
+
+    #[test]
+    fn decodes_ieee_float_and_double_signals() {
+        let parser = parser(
+            r#"
+BO_ 256 Single: 4 ECU
+ SG_ Pressure : 7|32@0- (1,0) [0|0] "bar" Vector__XXX
+
+BO_ 257 Double: 8 ECU
+ SG_ Position : 0|64@1- (2,1) [0|0] "m" Vector__XXX
+
+SIG_VALTYPE_ 256 Pressure : 1;
+SIG_VALTYPE_ 257 Position : 2;
+"#,
+        );
+
+        // Big-endian f32 -2.5 (0xC0200000), which as an integer would be negative
+        let decoded = parser.decode_msg(256, &(-2.5f32).to_be_bytes()).unwrap();
+        assert_eq!(decoded.signals["Pressure"].value.physical, -2.5);
+        assert_eq!(decoded.signals["Pressure"].value.raw, None);
+
+        // Little-endian f64 1.5 (0x3FF8000000000000), scaled by 2 with offset 1
+        let decoded = parser.decode_msg(257, &1.5f64.to_le_bytes()).unwrap();
+        assert_eq!(decoded.signals["Position"].value.physical, 4.0);
+
+        let msg_entry = parser.lookup(257).unwrap();
+        let position = &msg_entry.msg_def.signals[0];
+        let meta = msg_entry.signal_meta.get("Position");
+        let physical = Parser::physical_value(position, meta, (-0.25f64).to_bits());
+        assert_eq!(physical, 0.5);
+    }
+
+    #[test]
+    fn quotes_csv_fields_and_strips_control_chars() {
+        assert_eq!(csv_field("12.5"), "12.5");
+        assert_eq!(csv_field("m3,h"), "\"m3,h\"");
+        assert_eq!(csv_field("l;min"), "\"l;min\"");
+        assert_eq!(csv_field("in\"Hg"), "\"in\"\"Hg\"");
+        assert_eq!(csv_field("a\nb"), "\"a\nb\"");
+        assert_eq!(csv_field(&strip_control_chars("deg\tC\r\n")), "degC");
+        assert_eq!(escape_line_protocol("Mo tor\n", &[' ']), "Mo\\ tor");
+    }
+
+    #[test]
+    fn describes_dbc_syntax_errors() {
+        let dbc =
+            "VERSION \"\"\n\nBO_ 256 M: 2 ECU\n SG_ S : 0|8@1+ (1,0) [0|0] \"\" Vector__XXX oops\n";
+        let err = can_dbc::Dbc::try_from(dbc).unwrap_err();
+        assert_eq!(
+            describe_dbc_error(&err),
+            "syntax error at line 4, column 44: unexpected token \
+             near `SG_ S : 0|8@1+ (1,0) [0|0] \"\" Vector__XXX oops`"
+        );
+    }
+}
@@ -0,0 +1,346 @@
+//! Minimal ASAM MDF4 (`.mf4`) writer for decoded messages.
+//!
+//! [`Mdf4Writer`] collects `(timestamp, DecodedMessage)` records and writes them
+//! as a finalized, sorted MDF 4.10 file that common tools (e.g. asammdf,
+//! CANape, Vector MDF Validator) can open.
+//!
+//! ## Supported MDF4 subset
+//!
+//! - One identification block (`IDBLOCK`), header (`##HD`), and file history
+//!   entry (`##FH` with an XML `##MD` comment)
+//! - One data group (`##DG`) per CAN message ID, each holding a single channel
+//!   group (`##CG`) named after the message (sorted data, no record IDs)
+//! - Per channel group, a master time channel (`time`, seconds) followed by one
+//!   channel (`##CN`) per signal, all stored as little-endian IEEE-754 `f64`
+//!   physical values, with signal names and units as `##TX` blocks
+//! - One contiguous data block (`##DT`) per data group
+//!
+//! Not supported: conversion rules, invalidation bits, compressed or split data
+//! blocks, attachments, events, or source information. Signals missing from a
+//! record (e.g. inactive multiplexed signals) are written as `NaN`.
+
+use crate::DecodedMessage;
+
+/// MDF version written to the identification block.
+const MDF_VERSION: u16 = 410;
+
+/// Size of the identification block at the start of every MDF file.
+const ID_BLOCK_SIZE: u64 = 64;
+
+/// A single MDF4 block prior to serialization.
+///
+/// Links are stored as indices into the list of blocks being written and are
+/// resolved to file addresses once all block sizes are known.
+struct Block {
+    id: &'static [u8; 4],
+    links: Vec<Option<usize>>,
+    data: Vec<u8>,
+}
+
+impl Block {
+    fn new(id: &'static [u8; 4], links: Vec<Option<usize>>, data: Vec<u8>) -> Self {
+        Self { id, links, data }
+    }
+
+    /// Creates a text block (`##TX`) or XML metadata block (`##MD`).
+    ///
+    /// The text is zero-terminated and padded to an 8-byte boundary.
+    fn text(id: &'static [u8; 4], text: &str) -> Self {
+        let mut data = text.as_bytes().to_vec();
+        data.push(0);
+        data.resize(data.len().next_multiple_of(8), 0);
+        Self::new(id, Vec::new(), data)
+    }
+
+    /// Total length of the block in bytes, including its header.
+    fn len(&self) -> u64 {
+        24 + 8 * self.links.len() as u64 + self.data.len() as u64
+    }
+}
+
+/// All records collected for a single CAN message ID.
+struct Group {
+    name: String,
+    /// Signal names and units, in first-seen order
+    channels: indexmap::IndexMap<String, String>,
+    records: Vec<(f64, DecodedMessage)>,
+}
+
+/// Collects decoded messages and writes them as an MDF4 file.
+///
+/// Records are buffered in memory, since the channel layout of each message is
+/// only known once all of its records have been seen.
+///
+/// # Example
+///
+/// ```no_run
+/// use can_decode::Parser;
+/// use can_decode::mdf4::Mdf4Writer;
+/// use std::path::Path;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let parser = Parser::from_dbc_file(Path::new("my_database.dbc"))?;
+/// let mut writer = Mdf4Writer::new();
+///
+/// let data = [0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC, 0xDE, 0xF0];
+/// if let Some(decoded) = parser.decode_msg(0x123, &data) {
+///     writer.push(0.001, decoded);
+/// }
+///
+/// let file = std::fs::File::create("recording.mf4")?;
+/// writer.write_to(std::io::BufWriter::new(file))?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Default)]
+pub struct Mdf4Writer {
+    groups: indexmap::IndexMap<u32, Group>,
+}
+
+impl Mdf4Writer {
+    /// Creates a new writer with no records.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a decoded message recorded at `timestamp` (in seconds).
+    ///
+    /// Records are grouped by message ID; within a group they should be pushed
+    /// in increasing timestamp order.
+    pub fn push(&mut self, timestamp: f64, msg: DecodedMessage) {
+        let group = self.groups.entry(msg.msg_id).or_insert_with(|| Group {
+            name: msg.name.clone(),
+            channels: indexmap::IndexMap::new(),
+            records: Vec::new(),
+        });
+
+        for (name, signal) in &msg.signals {
+            if !group.channels.contains_key(name) {
+                group
+                    .channels
+                    .insert(name.clone(), crate::strip_control_chars(&signal.unit));
+            }
+        }
+        group.records.push((timestamp, msg));
+    }
+
+    /// Writes all collected records as an MDF4 file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to `out` fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use can_decode::Parser;
+    /// use can_decode::mdf4::Mdf4Writer;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut parser = Parser::new();
+    /// parser.add_from_str(
+    ///     r#"
+    /// BO_ 256 Motor: 2 ECU
+    ///  SG_ Speed : 0|8@1+ (10,0) [0|2550] "rpm" Vector__XXX
+    ///  SG_ Temp : 8|8@1+ (1,-40) [-40|215] "degC" Vector__XXX
+    /// "#,
+    /// )?;
+    ///
+    /// let mut writer = Mdf4Writer::new();
+    /// writer.push(0.0, parser.decode_msg(256, &[10, 60]).unwrap());
+    /// writer.push(0.1, parser.decode_msg(256, &[12, 61]).unwrap());
+    /// let mut file = Vec::new();
+    /// writer.write_to(&mut file)?;
+    ///
+    /// let u64_at = |at: u64| {
+    ///     let at = at as usize;
+    ///     u64::from_le_bytes(file[at..at + 8].try_into().unwrap())
+    /// };
+    /// let id_at = |at: u64| &file[at as usize..at as usize + 4];
+    /// // Block header: ID, reserved, length, link count, then the links
+    /// let link = |block: u64, idx: u64| u64_at(block + 24 + 8 * idx);
+    ///
+    /// assert_eq!(&file[..8], b"MDF     ");
+    /// let hd = 64;
+    /// assert_eq!(id_at(hd), b"##HD");
+    /// assert_eq!(id_at(link(hd, 1)), b"##FH");
+    ///
+    /// let dg = link(hd, 0);
+    /// assert_eq!(id_at(dg), b"##DG");
+    /// let cg = link(dg, 1);
+    /// assert_eq!(id_at(cg), b"##CG");
+    /// assert_eq!(id_at(link(cg, 2)), b"##TX");
+    ///
+    /// // Master time channel, then Speed and Temp
+    /// let time = link(cg, 1);
+    /// let speed = link(time, 0);
+    /// let temp = link(speed, 0);
+    /// assert_eq!(id_at(time), b"##CN");
+    /// assert_eq!(id_at(temp), b"##CN");
+    /// assert_eq!(link(temp, 0), 0);
+    /// assert_eq!(&file[link(speed, 2) as usize + 24..][..6], b"Speed\0");
+    ///
+    /// // Two records of three f64 values each
+    /// let dt = link(dg, 2);
+    /// assert_eq!(id_at(dt), b"##DT");
+    /// assert_eq!(u64_at(dt + 8), 24 + 2 * 3 * 8);
+    /// let values: Vec<f64> = (0..6)
+    ///     .map(|i| f64::from_bits(u64_at(dt + 24 + 8 * i)))
+    ///     .collect();
+    /// assert_eq!(values, [0.0, 100.0, 20.0, 0.1, 120.0, 21.0]);
+    /// assert_eq!(dt + u64_at(dt + 8), file.len() as u64);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn write_to<W: std::io::Write>(&self, mut out: W) -> std::io::Result<()> {
+        let blocks = self.build_blocks();
+
+        // Blocks are laid out back to back after the identification block
+        let mut addresses = Vec::with_capacity(blocks.len());
+        let mut next_address = ID_BLOCK_SIZE;
+        for block in &blocks {
+            addresses.push(next_address);
+            next_address += block.len();
+        }
+
+        out.write_all(&Self::id_block())?;
+        for block in &blocks {
+            out.write_all(block.id)?;
+            out.write_all(&[0u8; 4])?;
+            out.write_all(&block.len().to_le_bytes())?;
+            out.write_all(&(block.links.len() as u64).to_le_bytes())?;
+            for link in &block.links {
+                let address = link.map_or(0, |idx| addresses[idx]);
+                out.write_all(&address.to_le_bytes())?;
+            }
+            out.write_all(&block.data)?;
+        }
+        out.flush()
+    }
+
+    /// Builds the 64-byte identification block.
+    fn id_block() -> [u8; ID_BLOCK_SIZE as usize] {
+        let mut id = [0u8; ID_BLOCK_SIZE as usize];
+        id[0..8].copy_from_slice(b"MDF     ");
+        id[8..16].copy_from_slice(b"4.10    ");
+        id[16..24].copy_from_slice(b"candecod");
+        id[28..30].copy_from_slice(&MDF_VERSION.to_le_bytes());
+        id
+    }
+
+    /// Builds every block after the identification block, in file order.
+    ///
+    /// The header block is always first, so its address is `ID_BLOCK_SIZE`.
+    fn build_blocks(&self) -> Vec<Block> {
+        let mut blocks = Vec::new();
+
+        // Header, with links to the first data group and file history filled in below
+        blocks.push(Block::new(b"##HD", vec![None; 6], vec![0u8; 32]));
+
+        let fh_comment = format!(
+            "<FHcomment><TX>Created by can_decode</TX><tool_id>can_decode</tool_id>\
+            <tool_vendor>Purdue Electric Racing</tool_vendor>\
+            <tool_version>{}</tool_version></FHcomment>",
+            env!("CARGO_PKG_VERSION")
+        );
+        blocks.push(Block::new(b"##FH", vec![None, Some(2)], vec![0u8; 16]));
+        blocks.push(Block::text(b"##MD", &fh_comment));
+        blocks[0].links[1] = Some(1);
+
+        let mut prev_dg: Option<usize> = None;
+        for group in self.groups.values() {
+            let dg_idx = blocks.len();
+            match prev_dg {
+                Some(prev) => blocks[prev].links[0] = Some(dg_idx),
+                None => blocks[0].links[0] = Some(dg_idx),
+            }
+            prev_dg = Some(dg_idx);
+
+            // Data group: next DG, first CG, data block, comment
+            blocks.push(Block::new(
+                b"##DG",
+                vec![None, Some(dg_idx + 1), None, None],
+                vec![0u8; 8],
+            ));
+
+            // Channel group: next CG, first CN, acquisition name, source, sample reduction, comment
+            let channel_count = group.channels.len() + 1;
+            let mut cg_data = Vec::with_capacity(32);
+            cg_data.extend_from_slice(&0u64.to_le_bytes()); // record ID
+            cg_data.extend_from_slice(&(group.records.len() as u64).to_le_bytes());
+            cg_data.extend_from_slice(&0u16.to_le_bytes()); // flags
+            cg_data.extend_from_slice(&0u16.to_le_bytes()); // path separator
+            cg_data.extend_from_slice(&[0u8; 4]);
+            cg_data.extend_from_slice(&(channel_count as u32 * 8).to_le_bytes());
+            cg_data.extend_from_slice(&0u32.to_le_bytes()); // invalidation bytes
+            blocks.push(Block::new(
+                b"##CG",
+                vec![None, Some(dg_idx + 3), Some(dg_idx + 2), None, None, None],
+                cg_data,
+            ));
+            blocks.push(Block::text(b"##TX", &group.name));
+
+            // Master time channel followed by one channel per signal
+            let channels = std::iter::once(("time", "s"))
+                .chain(group.channels.iter().map(|(n, u)| (n.as_str(), u.as_str())));
+            let mut prev_cn: Option<usize> = None;
+            for (ch_idx, (name, unit)) in channels.enumerate() {
+                let cn_idx = blocks.len();
+                if let Some(prev) = prev_cn {
+                    blocks[prev].links[0] = Some(cn_idx);
+                }
+                prev_cn = Some(cn_idx);
+
+                let is_master = ch_idx == 0;
+                let mut cn_data = Vec::with_capacity(72);
+                cn_data.push(if is_master { 2 } else { 0 }); // channel type
+                cn_data.push(if is_master { 1 } else { 0 }); // sync type (time)
+                cn_data.push(4); // data type: IEEE-754 little-endian float
+                cn_data.push(0); // bit offset
+                cn_data.extend_from_slice(&(ch_idx as u32 * 8).to_le_bytes());
+                cn_data.extend_from_slice(&64u32.to_le_bytes()); // bit count
+                cn_data.extend_from_slice(&0u32.to_le_bytes()); // flags
+                cn_data.extend_from_slice(&0u32.to_le_bytes()); // invalidation bit position
+                cn_data.push(0); // precision
+                cn_data.push(0);
+                cn_data.extend_from_slice(&0u16.to_le_bytes()); // attachment count
+                cn_data.extend_from_slice(&[0u8; 48]); // value range and limits
+
+                let unit_link = (!unit.is_empty()).then_some(cn_idx + 2);
+                // Next CN, composition, name, source, conversion, data, unit, comment
+                blocks.push(Block::new(
+                    b"##CN",
+                    vec![
+                        None,
+                        None,
+                        Some(cn_idx + 1),
+                        None,
+                        None,
+                        None,
+                        unit_link,
+                        None,
+                    ],
+                    cn_data,
+                ));
+                blocks.push(Block::text(b"##TX", name));
+                if !unit.is_empty() {
+                    blocks.push(Block::text(b"##TX", unit));
+                }
+            }
+
+            // Records: timestamp followed by each signal's physical value
+            let mut dt_data = Vec::with_capacity(group.records.len() * channel_count * 8);
+            for (timestamp, msg) in &group.records {
+                dt_data.extend_from_slice(&timestamp.to_le_bytes());
+                for name in group.channels.keys() {
+                    let value = msg.signals.get(name).map_or(f64::NAN, |s| s.value.physical);
+                    dt_data.extend_from_slice(&value.to_le_bytes());
+                }
+            }
+            blocks[dg_idx].links[2] = Some(blocks.len());
+            blocks.push(Block::new(b"##DT", Vec::new(), dt_data));
+        }
+
+        blocks
+    }
+}
@@ -0,0 +1,155 @@
+//! Timeout detection based on DBC cycle times.
+
+use crate::Parser;
+use std::collections::HashMap;
+
+/// Tracks when cyclic messages were last received, to detect ones that stopped
+/// arriving.
+///
+/// Every message with a cycle time (see [`Parser::cycle_time_ms`]) is tracked.
+/// A message is stale once it hasn't been received for more than
+/// `max_missed_cycles` cycle times. Messages that haven't been received at all
+/// count from the first [`update`](StalenessTracker::update), so a message
+/// that never shows up is reported as well. Messages without a cycle time, or
+/// with a cycle time of 0 (event-driven), are never stale.
+///
+/// Timestamps are in seconds and only need a common reference, so both log
+/// timestamps and a monotonic clock work.
+///
+/// # Example
+///
+/// ```
+/// use can_decode::{Parser, StalenessTracker};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let dbc = r#"
+/// VERSION ""
+///
+/// BU_: ECU
+///
+/// BO_ 256 Fast: 1 ECU
+///  SG_ A : 0|8@1+ (1,0) [0|0] "" Vector__XXX
+///
+/// BO_ 512 Slow: 1 ECU
+///  SG_ B : 0|8@1+ (1,0) [0|0] "" Vector__XXX
+///
+/// BO_ 768 Event: 1 ECU
+///  SG_ C : 0|8@1+ (1,0) [0|0] "" Vector__XXX
+///
+/// BA_DEF_ BO_ "GenMsgCycleTime" INT 0 10000;
+/// BA_DEF_DEF_ "GenMsgCycleTime" 0;
+/// BA_ "GenMsgCycleTime" BO_ 256 10;
+/// BA_ "GenMsgCycleTime" BO_ 512 100;
+/// "#;
+/// let mut parser = Parser::new();
+/// parser.add_from_str(dbc)?;
+///
+/// // Stale after missing 3 cycles: 30 ms for Fast, 300 ms for Slow
+/// let mut tracker = StalenessTracker::new(&parser, 3);
+/// tracker.update(256, 0.000);
+/// tracker.update(512, 0.005);
+/// tracker.update(256, 0.010);
+/// assert!(tracker.stale(0.020).is_empty());
+///
+/// // Fast stopped after 10 ms
+/// assert_eq!(tracker.stale(0.050), [256]);
+///
+/// // Slow is only stale after 300 ms without a frame, and Event never is
+/// assert_eq!(tracker.stale(0.400), [256, 512]);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct StalenessTracker {
+    /// Cycle times in seconds keyed by message ID, for tracked messages
+    cycle_times: HashMap<u32, f64>,
+    max_missed_cycles: u32,
+    /// Last receive time in seconds keyed by message ID
+    last_seen: HashMap<u32, f64>,
+    /// Time of the first update, which never-received messages count from
+    started: Option<f64>,
+}
+
+impl StalenessTracker {
+    /// Creates a tracker for the cyclic messages of a parser.
+    ///
+    /// The cycle times are copied, so later changes to the parser don't affect
+    /// the tracker.
+    ///
+    /// # Arguments
+    ///
+    /// * `parser` - The parser whose message cycle times to use
+    /// * `max_missed_cycles` - How many cycle times a message may be missing
+    ///   before it's reported as stale
+    pub fn new(parser: &Parser, max_missed_cycles: u32) -> Self {
+        let cycle_times = parser
+            .msg_entries
+            .iter()
+            .filter_map(|(&msg_id, entry)| {
+                let cycle_time_ms = entry.cycle_time_ms().filter(|&ms| ms > 0)?;
+                Some((msg_id, cycle_time_ms as f64 / 1000.0))
+            })
+            .collect();
+
+        Self {
+            cycle_times,
+            max_missed_cycles,
+            last_seen: HashMap::new(),
+            started: None,
+        }
+    }
+
+    /// Records that a message was received.
+    ///
+    /// Messages that aren't tracked are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `msg_id` - The CAN message identifier, as passed to
+    ///   [`Parser::decode_msg`]
+    /// * `timestamp` - The receive time in seconds
+    pub fn update(&mut self, msg_id: u32, timestamp: f64) {
+        self.started.get_or_insert(timestamp);
+        if self.cycle_times.contains_key(&msg_id) {
+            self.last_seen.insert(msg_id, timestamp);
+        }
+    }
+
+    /// Returns the tracked messages that are overdue at a given time.
+    ///
+    /// # Arguments
+    ///
+    /// * `now` - The current time in seconds
+    ///
+    /// # Returns
+    ///
+    /// The sorted IDs of stale messages. Before the first
+    /// [`update`](StalenessTracker::update), nothing is stale.
+    pub fn stale(&self, now: f64) -> Vec<u32> {
+        let Some(started) = self.started else {
+            return Vec::new();
+        };
+
+        let mut ids: Vec<u32> = self
+            .cycle_times
+            .iter()
+            .filter(|&(msg_id, cycle_time)| {
+                let since = self.last_seen.get(msg_id).copied().unwrap_or(started);
+                now - since > cycle_time * self.max_missed_cycles as f64
+            })
+            .map(|(&msg_id, _)| msg_id)
+            .collect();
+        ids.sort_unstable();
+        ids
+    }
+
+    /// Returns when a message was last received.
+    ///
+    /// # Returns
+    ///
+    /// The timestamp of the last update for the message, or `None` if it
+    /// hasn't been received or isn't tracked.
+    pub fn last_seen(&self, msg_id: u32) -> Option<f64> {
+        self.last_seen.get(&msg_id).copied()
+    }
+}
@@ -0,0 +1,235 @@
+//! Decoding into user-defined types.
+//!
+//! [`FromSignal`] converts a single decoded signal into a Rust value, and
+//! [`CanDecode`] populates a whole struct from a [`DecodedMessage`]. With the
+//! `derive` feature, both can be derived: `#[derive(CanDecode)]` for structs
+//! whose fields name their signals, and `#[derive(SignalEnum)]` for enums that
+//! mirror a signal's value descriptions.
+
+use crate::{DecodedMessage, DecodedSignal, SignalValue};
+
+/// Conversion of a decoded signal into a Rust value.
+///
+/// Implemented for `f64` and `f32` (the physical value), the integer types
+/// (the physical value, if it's a whole number within the type's range),
+/// `bool` (whether the physical value is nonzero), `String` (the value
+/// description label), and [`SignalValue`].
+pub trait FromSignal: Sized {
+    /// Converts a decoded signal.
+    ///
+    /// # Returns
+    ///
+    /// The converted value, or `None` if the signal's value can't be
+    /// represented by `Self`.
+    fn from_signal(signal: &DecodedSignal) -> Option<Self>;
+}
+
+impl FromSignal for f64 {
+    fn from_signal(signal: &DecodedSignal) -> Option<Self> {
+        Some(signal.value.physical)
+    }
+}
+
+impl FromSignal for f32 {
+    fn from_signal(signal: &DecodedSignal) -> Option<Self> {
+        Some(signal.value.physical as f32)
+    }
+}
+
+impl FromSignal for bool {
+    fn from_signal(signal: &DecodedSignal) -> Option<Self> {
+        Some(signal.value.physical != 0.0)
+    }
+}
+
+impl FromSignal for String {
+    fn from_signal(signal: &DecodedSignal) -> Option<Self> {
+        signal.value.enum_label.clone()
+    }
+}
+
+impl FromSignal for SignalValue {
+    fn from_signal(signal: &DecodedSignal) -> Option<Self> {
+        Some(signal.typed_value())
+    }
+}
+
+macro_rules! impl_from_signal_for_integers {
+    ($($ty:ty),*) => {
+        $(
+            impl FromSignal for $ty {
+                fn from_signal(signal: &DecodedSignal) -> Option<Self> {
+                    // NaN and infinities have a NaN fractional part
+                    let physical = signal.value.physical;
+                    if physical.fract() != 0.0 {
+                        return None;
+                    }
+                    <$ty>::try_from(physical as i128).ok()
+                }
+            }
+        )*
+    };
+}
+
+impl_from_signal_for_integers!(u8, u16, u32, u64, i8, i16, i32, i64);
+
+/// Construction of a type from a decoded message.
+///
+/// Usually derived with `#[derive(CanDecode)]` (requires the `derive`
+/// feature), which reads each field from the signal named by its
+/// `#[signal("...")]` attribute, or from the signal named like the field.
+/// Fields of type `Option<T>` are `None` when their signal is missing, e.g.
+/// an inactive multiplexed signal.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "derive")]
+/// # {
+/// use can_decode::{CanDecode, Parser, SignalEnum};
+///
+/// #[derive(Debug, PartialEq, SignalEnum)]
+/// enum Gear {
+///     Park,
+///     Drive,
+///     #[label("R")]
+///     Reverse,
+/// }
+///
+/// #[derive(Debug, CanDecode)]
+/// struct Motor {
+///     #[signal("MotorTemp")]
+///     temperature: f64,
+///     #[signal("Speed")]
+///     speed_rpm: u16,
+///     gear: Gear,
+///     #[signal("Boost")]
+///     boost: Option<f64>,
+/// }
+///
+/// let dbc = r#"
+/// VERSION ""
+///
+/// BU_: ECU
+///
+/// BO_ 256 Motor: 4 ECU
+///  SG_ MotorTemp : 0|8@1+ (0.5,-40) [-40|87.5] "degC" Vector__XXX
+///  SG_ Speed : 8|16@1+ (1,0) [0|8000] "rpm" Vector__XXX
+///  SG_ gear : 24|2@1+ (1,0) [0|3] "" Vector__XXX
+///
+/// VAL_ 256 gear 0 "Park" 1 "Drive" 2 "R";
+/// "#;
+/// let mut parser = Parser::new();
+/// parser.add_from_str(dbc).unwrap();
+///
+/// let decoded = parser.decode_msg(256, &[180, 0xE8, 0x03, 0x02]).unwrap();
+/// let motor = Motor::from_decoded(&decoded).unwrap();
+/// assert_eq!(motor.temperature, 50.0);
+/// assert_eq!(motor.speed_rpm, 1000);
+/// assert_eq!(motor.gear, Gear::Reverse);
+/// assert_eq!(motor.boost, None);
+///
+/// // A required signal beyond a short payload is reported by name
+/// let decoded = parser.decode_msg(256, &[180, 0xE8]).unwrap();
+/// let err = Motor::from_decoded(&decoded).unwrap_err();
+/// assert_eq!(err.to_string(), "signal 'Speed' is missing from message Motor");
+/// # }
+/// ```
+pub trait CanDecode: Sized {
+    /// Builds `Self` from the signals of a decoded message.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a required signal is missing or its value can't be
+    /// converted to the field's type.
+    fn from_decoded(decoded: &DecodedMessage) -> Result<Self, FromDecodedError>;
+}
+
+/// Errors from converting decoded signals with [`DecodedMessage::signal_as`]
+/// or [`CanDecode::from_decoded`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FromDecodedError {
+    /// The signal isn't in the decoded message
+    MissingSignal {
+        /// The message name
+        message: String,
+        /// The signal name
+        signal: String,
+    },
+    /// The signal's value can't be represented by the requested type, e.g. a
+    /// fractional value for an integer field or an unknown enum label
+    InvalidValue {
+        /// The signal name
+        signal: String,
+        /// The name of the requested type
+        type_name: &'static str,
+    },
+}
+
+impl std::fmt::Display for FromDecodedError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FromDecodedError::MissingSignal { message, signal } => {
+                write!(f, "signal '{signal}' is missing from message {message}")
+            }
+            FromDecodedError::InvalidValue { signal, type_name } => {
+                write!(f, "signal '{signal}' can't be converted to {type_name}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for FromDecodedError {}
+
+impl DecodedMessage {
+    /// Returns a signal's value converted to a Rust type.
+    ///
+    /// # Arguments
+    ///
+    /// * `signal` - The name of the signal as defined in the DBC file
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FromDecodedError::MissingSignal`] if the signal wasn't decoded,
+    /// or [`FromDecodedError::InvalidValue`] if its value doesn't convert.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use can_decode::{FromDecodedError, Parser};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut parser = Parser::new();
+    /// parser.add_from_str(
+    ///     r#"
+    /// BO_ 256 Motor: 2 ECU
+    ///  SG_ Speed : 0|8@1+ (10,0) [0|2550] "rpm" Vector__XXX
+    ///  SG_ Temp : 8|8@1+ (0.5,0) [0|127.5] "degC" Vector__XXX
+    /// "#,
+    /// )?;
+    ///
+    /// let decoded = parser.decode_msg(256, &[30, 81]).unwrap();
+    /// assert_eq!(decoded.signal_as::<u16>("Speed")?, 300);
+    /// assert_eq!(decoded.signal_as::<f64>("Temp")?, 40.5);
+    /// assert!(matches!(
+    ///     decoded.signal_as::<u8>("Temp"),
+    ///     Err(FromDecodedError::InvalidValue { .. })
+    /// ));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn signal_as<T: FromSignal>(&self, signal: &str) -> Result<T, FromDecodedError> {
+        let decoded_signal =
+            self.signals
+                .get(signal)
+                .ok_or_else(|| FromDecodedError::MissingSignal {
+                    message: self.name.clone(),
+                    signal: signal.to_string(),
+                })?;
+
+        T::from_signal(decoded_signal).ok_or_else(|| FromDecodedError::InvalidValue {
+            signal: signal.to_string(),
+            type_name: std::any::type_name::<T>(),
+        })
+    }
+}